@@ -5,7 +5,7 @@ pub mod spec;
 pub use fallback::OverlayModelCatalog;
 pub(crate) use resolver::is_gemini_api_model;
 pub use resolver::ModelResolver;
-pub use spec::{ModelFlavor, ModelSpec};
+pub use spec::{Endpoint, ModelFlavor, ModelSpec};
 
 use lingua::ProviderFormat;
 
@@ -21,6 +21,7 @@ use crate::error::Result;
 pub struct ModelCatalog {
     models: HashMap<String, Arc<ModelSpec>>,
     by_format: HashMap<ProviderFormat, Vec<String>>,
+    by_provider: HashMap<String, Vec<String>>,
     by_parent: HashMap<String, Vec<String>>,
     fallback_models: HashMap<String, Vec<String>>,
     equivalence_index: HashMap<String, Vec<String>>,
@@ -131,6 +132,15 @@ impl ModelCatalog {
         self.by_format.get(&format).map(Vec::as_slice)
     }
 
+    /// Model names whose `available_providers` includes `provider_id`.
+    ///
+    /// A model may list more than one provider (e.g. it's reachable through
+    /// both `OPENAI_API_KEY` and a custom provider), so unlike
+    /// `models_for_format` this indexes a many-to-many relationship.
+    pub fn models_for_provider(&self, provider_id: &str) -> Option<&[String]> {
+        self.by_provider.get(provider_id).map(Vec::as_slice)
+    }
+
     pub fn child_models<'a>(&'a self, parent: &str) -> impl Iterator<Item = &'a String> + 'a {
         self.by_parent
             .get(parent)
@@ -170,9 +180,16 @@ impl ModelCatalog {
         }
         let format = spec.format;
         let parent = spec.parent.clone();
+        let available_providers = spec.available_providers.clone();
         let spec = Arc::new(spec);
         self.models.insert(name.clone(), spec);
         self.by_format.entry(format).or_default().push(name.clone());
+        for provider_id in available_providers {
+            self.by_provider
+                .entry(provider_id)
+                .or_default()
+                .push(name.clone());
+        }
         if let Some(parent) = parent {
             self.by_parent.entry(parent).or_default().push(name);
         }
@@ -337,6 +354,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn models_for_provider_lists_models_by_available_provider() {
+        let catalog = ModelCatalog::from_json_str(
+            r#"{
+  "gpt-4o": {
+    "format": "openai",
+    "flavor": "chat",
+    "available_providers": ["OPENAI_API_KEY"]
+  },
+  "gpt-4o-mini": {
+    "format": "openai",
+    "flavor": "chat",
+    "available_providers": ["OPENAI_API_KEY", "custom-provider"]
+  },
+  "claude-sonnet-4-6": {
+    "format": "anthropic",
+    "flavor": "chat",
+    "available_providers": ["ANTHROPIC_API_KEY"]
+  }
+}"#,
+        )
+        .expect("catalog parses");
+
+        let mut openai_models = catalog
+            .models_for_provider("OPENAI_API_KEY")
+            .expect("OPENAI_API_KEY has models")
+            .to_vec();
+        openai_models.sort();
+        assert_eq!(openai_models, vec!["gpt-4o", "gpt-4o-mini"]);
+
+        assert_eq!(
+            catalog.models_for_provider("custom-provider"),
+            Some(&["gpt-4o-mini".to_string()][..])
+        );
+        assert_eq!(catalog.models_for_provider("missing-provider"), None);
+    }
+
     #[test]
     fn map_specs_preserves_equivalent_model_index() {
         let catalog = ModelCatalog::from_json_str(
@@ -389,6 +443,7 @@ mod tests {
             "custom-a".to_string(),
             ModelSpec {
                 model: "custom-a".to_string(),
+                provider_model_id: None,
                 format: ProviderFormat::Anthropic,
                 flavor: ModelFlavor::Chat,
                 display_name: None,
@@ -403,6 +458,7 @@ mod tests {
                 supports_streaming: true,
                 extra: Default::default(),
                 available_providers: vec!["custom-provider".to_string()],
+                endpoints: vec![],
             },
         );
         custom
@@ -460,6 +516,7 @@ mod tests {
             "model-b".to_string(),
             ModelSpec {
                 model: "custom-model-b".to_string(),
+                provider_model_id: None,
                 format: ProviderFormat::Anthropic,
                 flavor: ModelFlavor::Chat,
                 display_name: None,
@@ -474,6 +531,7 @@ mod tests {
                 supports_streaming: true,
                 extra: Default::default(),
                 available_providers: vec!["custom-provider".to_string()],
+                endpoints: vec![],
             },
         );
 
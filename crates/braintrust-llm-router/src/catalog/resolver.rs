@@ -124,6 +124,7 @@ mod tests {
     fn spec(model: &str, format: ProviderFormat) -> ModelSpec {
         ModelSpec {
             model: model.to_string(),
+            provider_model_id: None,
             format,
             flavor: ModelFlavor::Chat,
             display_name: None,
@@ -138,6 +139,7 @@ mod tests {
             supports_streaming: true,
             extra: Default::default(),
             available_providers: Default::default(),
+            endpoints: vec![],
         }
     }
 
@@ -381,6 +383,50 @@ mod tests {
         assert_eq!(aliases, vec!["openai".to_string(), "azure".to_string()]);
     }
 
+    #[test]
+    fn resolve_openrouter_model_preserves_vendor_prefix() {
+        let model = "anthropic/claude-3.5-sonnet";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(
+            model.into(),
+            spec_with_available_providers(
+                model,
+                ProviderFormat::ChatCompletions,
+                vec!["openrouter".to_string()],
+            ),
+        );
+        let resolver = ModelResolver::new(Arc::new(catalog));
+
+        let (spec, format, aliases) = resolver.resolve(model).expect("resolves");
+        assert_eq!(format, ProviderFormat::ChatCompletions);
+        assert_eq!(aliases, vec!["openrouter".to_string()]);
+        assert_eq!(spec.model, model);
+    }
+
+    #[test]
+    fn resolve_together_hosted_open_models_use_together_alias() {
+        for model in [
+            "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+            "Qwen/Qwen2.5-72B-Instruct-Turbo",
+        ] {
+            let mut catalog = ModelCatalog::empty();
+            catalog.insert(
+                model.into(),
+                spec_with_available_providers(
+                    model,
+                    ProviderFormat::ChatCompletions,
+                    vec!["together".to_string()],
+                ),
+            );
+            let resolver = ModelResolver::new(Arc::new(catalog));
+
+            let (spec, format, aliases) = resolver.resolve(model).expect("resolves");
+            assert_eq!(format, ProviderFormat::ChatCompletions);
+            assert_eq!(aliases, vec!["together".to_string()]);
+            assert_eq!(spec.model, model);
+        }
+    }
+
     #[test]
     fn resolve_custom_alias_overrides_available_providers() {
         let model = "gpt-4o";
@@ -16,10 +16,48 @@ pub enum ModelFlavor {
     Responses,
 }
 
+/// A caller-facing API surface a model may be routed to.
+///
+/// Distinct from [`ModelFlavor`], which describes the wire format a model's
+/// provider speaks, `Endpoint` describes which surface the *incoming*
+/// request came in on. Used to reject a request routed to an endpoint the
+/// model doesn't support (e.g. a Responses-format request for a chat-only
+/// model) with a clear error instead of a confusing upstream 404.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Endpoint {
+    Chat,
+    Responses,
+    Embeddings,
+}
+
+impl Endpoint {
+    /// The endpoint an outgoing request in `format` is targeting.
+    ///
+    /// Only `ProviderFormat::Responses` is a distinct endpoint from `Chat`;
+    /// every other provider wire format (Anthropic, Google, Bedrock, ...) is
+    /// a conversational chat surface.
+    pub fn for_output_format(format: ProviderFormat) -> Self {
+        match format {
+            ProviderFormat::Responses => Endpoint::Responses,
+            _ => Endpoint::Chat,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelSpec {
     #[serde(default)]
     pub model: String,
+    /// The id to send to the upstream provider, when it differs from `model`.
+    ///
+    /// Catalog entries are keyed by a stable, human-friendly name (e.g. `claude-sonnet`)
+    /// used for routing and billing, but some providers expect a different id in the
+    /// wire payload (e.g. `claude-3-5-sonnet-20241022`). When set, the Router substitutes
+    /// this id into the outgoing request instead of `model`; `model` keeps flowing through
+    /// routing, logging, and billing unchanged.
+    #[serde(default)]
+    pub provider_model_id: Option<String>,
     pub format: ProviderFormat,
     pub flavor: ModelFlavor,
     #[serde(rename = "displayName", default)]
@@ -46,6 +84,11 @@ pub struct ModelSpec {
     pub extra: serde_json::Map<String, serde_json::Value>,
     #[serde(default)]
     pub available_providers: Vec<String>,
+    /// Caller-facing endpoints this model may be routed to. Empty means
+    /// unrestricted, so existing catalog entries that don't set this keep
+    /// routing to any endpoint.
+    #[serde(default)]
+    pub endpoints: Vec<Endpoint>,
 }
 
 fn default_true() -> bool {
@@ -72,6 +115,18 @@ impl ModelSpec {
     pub fn requires_responses_api(&self) -> bool {
         self.flavor == ModelFlavor::Responses || model_requires_responses_api(&self.model)
     }
+
+    /// The model id to send in the outgoing request body, preferring
+    /// `provider_model_id` when the catalog defines one.
+    pub fn outgoing_model_id(&self) -> &str {
+        self.provider_model_id.as_deref().unwrap_or(&self.model)
+    }
+
+    /// Whether this model may be routed to `endpoint`. An empty `endpoints`
+    /// list means the catalog entry doesn't restrict routing.
+    pub fn supports_endpoint(&self, endpoint: Endpoint) -> bool {
+        self.endpoints.is_empty() || self.endpoints.contains(&endpoint)
+    }
 }
 
 #[cfg(test)]
@@ -134,6 +189,7 @@ mod tests {
     fn model_spec_requires_responses_api_allows_flavor_override() {
         let spec = ModelSpec {
             model: "custom-model".to_string(),
+            provider_model_id: None,
             format: ProviderFormat::ChatCompletions,
             flavor: ModelFlavor::Responses,
             display_name: None,
@@ -148,7 +204,59 @@ mod tests {
             supports_streaming: true,
             extra: serde_json::Map::new(),
             available_providers: vec![],
+            endpoints: vec![],
         };
         assert!(spec.requires_responses_api());
     }
+
+    #[test]
+    fn model_spec_supports_endpoint_is_unrestricted_by_default() {
+        let spec = ModelSpec {
+            model: "custom-model".to_string(),
+            provider_model_id: None,
+            format: ProviderFormat::ChatCompletions,
+            flavor: ModelFlavor::Chat,
+            display_name: None,
+            parent: None,
+            input_cost_per_mil_tokens: None,
+            output_cost_per_mil_tokens: None,
+            input_cache_read_cost_per_mil_tokens: None,
+            multimodal: None,
+            reasoning: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            supports_streaming: true,
+            extra: serde_json::Map::new(),
+            available_providers: vec![],
+            endpoints: vec![],
+        };
+        assert!(spec.supports_endpoint(Endpoint::Chat));
+        assert!(spec.supports_endpoint(Endpoint::Responses));
+    }
+
+    #[test]
+    fn model_spec_supports_endpoint_honors_explicit_restriction() {
+        let mut spec = ModelSpec {
+            model: "chat-only-model".to_string(),
+            provider_model_id: None,
+            format: ProviderFormat::ChatCompletions,
+            flavor: ModelFlavor::Chat,
+            display_name: None,
+            parent: None,
+            input_cost_per_mil_tokens: None,
+            output_cost_per_mil_tokens: None,
+            input_cache_read_cost_per_mil_tokens: None,
+            multimodal: None,
+            reasoning: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            supports_streaming: true,
+            extra: serde_json::Map::new(),
+            available_providers: vec![],
+            endpoints: vec![],
+        };
+        spec.endpoints = vec![Endpoint::Chat];
+        assert!(spec.supports_endpoint(Endpoint::Chat));
+        assert!(!spec.supports_endpoint(Endpoint::Responses));
+    }
 }
@@ -64,6 +64,19 @@ pub fn build_client(settings: &ClientSettings) -> Result<Client> {
         .pool_max_idle_per_host(settings.pool_max_idle_per_host)
         .user_agent(&settings.user_agent);
 
+    // Pick the TLS backend explicitly rather than relying on reqwest's own
+    // default, since a dependent crate elsewhere in the build graph could
+    // otherwise pull in both backends via feature unification. rustls wins
+    // when both are enabled, matching the `rustls-tls` default feature.
+    #[cfg(feature = "rustls-tls")]
+    {
+        builder = builder.use_rustls_tls();
+    }
+    #[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+    {
+        builder = builder.use_native_tls();
+    }
+
     if settings.http1_only {
         builder = builder.http1_only();
     }
@@ -302,6 +315,15 @@ mod tests {
         Mock, MockServer, ResponseTemplate,
     };
 
+    #[test]
+    fn build_client_succeeds_with_active_tls_backend() {
+        // Guards against the `rustls-tls`/`native-tls` features drifting out
+        // of sync with reqwest's own TLS features, which would otherwise only
+        // surface as a runtime "no TLS backend" error.
+        let client = build_client(&ClientSettings::default());
+        assert!(client.is_ok());
+    }
+
     #[test]
     #[serial]
     fn build_middleware_client_with_no_override() {
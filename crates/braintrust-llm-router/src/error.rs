@@ -6,6 +6,8 @@ use thiserror::Error;
 
 use lingua::ProviderFormat;
 
+use crate::catalog::Endpoint;
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, Clone)]
@@ -13,6 +15,11 @@ pub struct UpstreamHttpError {
     pub status: u16,
     pub headers: Vec<(String, String)>,
     pub body: String,
+    /// The request-id this call was correlated with (see
+    /// `RouterBuilder::with_request_id_header`), attached once the router
+    /// knows it - `None` for an `UpstreamHttpError` built directly by a
+    /// `Provider` before the router has had a chance to correlate it.
+    pub request_id: Option<String>,
 }
 
 impl UpstreamHttpError {
@@ -30,9 +37,16 @@ impl UpstreamHttpError {
             status,
             headers,
             body,
+            request_id: None,
         }
     }
 
+    /// Attach the request-id that correlates this upstream call.
+    pub(crate) fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
     pub fn status(&self) -> u16 {
         self.status
     }
@@ -110,6 +124,12 @@ pub enum Error {
 
     #[error("operation timed out")]
     Timeout,
+
+    #[error("model '{model}' does not support the {endpoint:?} endpoint")]
+    UnsupportedEndpoint { model: String, endpoint: Endpoint },
+
+    #[error("request rejected by hook: {0}")]
+    RejectedByHook(String),
 }
 
 impl Error {
@@ -136,7 +156,10 @@ impl Error {
     pub fn is_client_error(&self) -> bool {
         matches!(
             self,
-            Error::UnknownModel(_) | Error::NoProvider(_) | Error::InvalidRequest(_)
+            Error::UnknownModel(_)
+                | Error::NoProvider(_)
+                | Error::InvalidRequest(_)
+                | Error::RejectedByHook(_)
         ) || matches!(self, Error::Lingua(e) if e.is_client_error())
     }
 
@@ -161,6 +184,46 @@ impl Error {
             _ => None,
         }
     }
+
+    /// Returns true if retrying this error - on the same provider or a
+    /// fallback one - stands a reasonable chance of succeeding: timeouts,
+    /// connection failures, HTTP 429 (rate limited), and HTTP 5xx upstream
+    /// errors (via [`UpstreamHttpError::status`]).
+    ///
+    /// Unlike [`Error::is_retryable`], which deliberately excludes timeouts
+    /// already handled by the provider's HTTP wrapper, `is_retriable`
+    /// classifies errors purely by whether they're transient. The router's
+    /// own backoff loop ([`RetryStrategy::next_delay`](crate::RetryStrategy::next_delay))
+    /// uses it, and so can callers building a retry policy or fallback
+    /// chain on top of this crate.
+    ///
+    /// Returns false for 4xx request errors and authentication failures,
+    /// which won't succeed no matter how many times they're retried.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            Error::Timeout | Error::UpstreamUnavailable { .. } => true,
+            Error::Http(err) => {
+                err.is_timeout()
+                    || err.is_connect()
+                    || err
+                        .status()
+                        .map(|status| status.is_server_error() || status.as_u16() == 429)
+                        .unwrap_or(false)
+            }
+            Error::Middleware(err) => {
+                err.is_timeout()
+                    || middleware_is_connect(err)
+                    || err
+                        .status()
+                        .map(|status| status.is_server_error() || status.as_u16() == 429)
+                        .unwrap_or(false)
+            }
+            Error::Provider {
+                http: Some(http), ..
+            } => http.status == 429 || (500..600).contains(&http.status),
+            _ => false,
+        }
+    }
 }
 
 fn is_reqwest_retryable(err: &reqwest::Error) -> bool {
@@ -179,19 +242,22 @@ fn is_middleware_retryable(err: &reqwest_middleware::Error) -> bool {
     }
 
     err.is_request()
-        || {
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                err.is_connect()
-            }
-            #[cfg(target_arch = "wasm32")]
-            {
-                false
-            }
-        }
+        || middleware_is_connect(err)
         || err.status().map(|c| c.is_server_error()).unwrap_or(false)
 }
 
+fn middleware_is_connect(err: &reqwest_middleware::Error) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        err.is_connect()
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = err;
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,6 +351,7 @@ mod tests {
                 status: 404,
                 headers: vec![],
                 body: "not found".into(),
+                request_id: None,
             }),
         };
         assert!(upstream_err.is_upstream_error());
@@ -302,4 +369,57 @@ mod tests {
     fn timeout_error_is_not_retryable() {
         assert!(!Error::Timeout.is_retryable());
     }
+
+    #[test]
+    fn error_is_retriable_classification() {
+        // Retriable: timeouts, connection failures, 429, 5xx.
+        assert!(Error::Timeout.is_retriable());
+        assert!(Error::UpstreamUnavailable {
+            provider: "openai".into(),
+            source: anyhow::anyhow!("connection reset"),
+        }
+        .is_retriable());
+        assert!(Error::Provider {
+            provider: "openai".into(),
+            source: anyhow::anyhow!("rate limited"),
+            retry_after: None,
+            http: Some(UpstreamHttpError {
+                status: 429,
+                headers: vec![],
+                body: "rate limited".into(),
+                request_id: None,
+            }),
+        }
+        .is_retriable());
+        assert!(Error::Provider {
+            provider: "openai".into(),
+            source: anyhow::anyhow!("server error"),
+            retry_after: None,
+            http: Some(UpstreamHttpError {
+                status: 503,
+                headers: vec![],
+                body: "unavailable".into(),
+                request_id: None,
+            }),
+        }
+        .is_retriable());
+
+        // Not retriable: 4xx request errors, auth failures.
+        assert!(!Error::InvalidRequest("bad".into()).is_retriable());
+        assert!(!Error::UnknownModel("gpt-5".into()).is_retriable());
+        assert!(!Error::NoAuth("openai".into()).is_retriable());
+        assert!(!Error::Auth("invalid key".into()).is_retriable());
+        assert!(!Error::Provider {
+            provider: "openai".into(),
+            source: anyhow::anyhow!("bad request"),
+            retry_after: None,
+            http: Some(UpstreamHttpError {
+                status: 400,
+                headers: vec![],
+                body: "bad request".into(),
+                request_id: None,
+            }),
+        }
+        .is_retriable());
+    }
 }
@@ -1,6 +1,7 @@
 mod auth;
 mod catalog;
 mod client;
+mod compression;
 pub use client::{
     build_middleware_client, clear_override_client, set_override_client, ClientSettings,
     DnsOverride,
@@ -8,6 +9,7 @@ pub use client::{
 pub use reqwest_middleware::ClientWithMiddleware;
 mod error;
 mod providers;
+mod response_cache;
 mod retry;
 mod router;
 mod streaming;
@@ -23,7 +25,7 @@ pub use auth::{
     google::{GoogleServiceAccountConfig, GoogleTokenManager, ServiceAccountKey},
     AuthConfig, AuthType,
 };
-pub use catalog::{ModelCatalog, ModelFlavor, ModelResolver, ModelSpec};
+pub use catalog::{Endpoint, ModelCatalog, ModelFlavor, ModelResolver, ModelSpec};
 pub use error::{Error, Result, UpstreamHttpError};
 pub use lingua::ProviderFormat;
 pub use lingua::{FinishReason, UniversalStreamChoice, UniversalStreamChunk};
@@ -35,8 +37,10 @@ pub use providers::{
 };
 pub use retry::{RetryPolicy, RetryStrategy};
 pub use router::{
-    create_provider, extract_request_hints, CompleteResponseWithRaw, PreparedRequest,
-    PreparedStreamRequest, ProviderRoute, RequestHints, Router, RouterBuilder, RouterMetadata,
+    create_provider, extract_request_hints, CatalogRoutingStrategy, CompleteResponseWithRaw,
+    ModelInfo, ModelList, PreparedRequest, PreparedStreamRequest, ProviderRoute, RequestHints,
+    RequestKind, ResolvedRoute, Router, RouterBuilder, RouterHook, RouterMetadata, RoutingStrategy,
+    UnknownModelPolicy, DEFAULT_REQUEST_ID_HEADER,
 };
 pub use streaming::{RawResponseStream, RawStreamChunkCapture, ResponseStream, StreamChunk};
 
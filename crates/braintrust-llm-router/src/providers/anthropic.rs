@@ -8,6 +8,7 @@ use crate::providers::ClientHeaders;
 use crate::streaming::{sse_stream, RawResponseStream};
 use async_trait::async_trait;
 use bytes::Bytes;
+use lingua::providers::anthropic::capabilities as anthropic_capabilities;
 use lingua::ProviderFormat;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
 use reqwest::Url;
@@ -98,7 +99,7 @@ impl AnthropicProvider {
             .expect("join chat/completions path")
     }
 
-    fn build_headers(&self, client_headers: &ClientHeaders) -> HeaderMap {
+    fn build_headers(&self, client_headers: &ClientHeaders, model: &str) -> HeaderMap {
         let mut headers = client_headers.to_json_headers();
 
         headers.insert(
@@ -106,11 +107,15 @@ impl AnthropicProvider {
             HeaderValue::from_str(&self.config.version).expect("version header"),
         );
 
-        // Respect caller override: only set default if missing.
+        // Respect caller override: only set defaults if missing.
         if !headers.contains_key(ANTHROPIC_BETA) {
+            let mut betas = vec![STRUCTURED_OUTPUTS_BETA];
+            if let Some(context_beta) = anthropic_capabilities::context_beta_header(model) {
+                betas.push(context_beta);
+            }
             headers.insert(
                 ANTHROPIC_BETA,
-                HeaderValue::from_static(STRUCTURED_OUTPUTS_BETA),
+                HeaderValue::from_str(&betas.join(",")).expect("beta header"),
             );
         }
 
@@ -128,11 +133,15 @@ impl crate::providers::Provider for AnthropicProvider {
         vec![ProviderFormat::Anthropic, ProviderFormat::ChatCompletions]
     }
 
+    fn supports_request_compression(&self) -> bool {
+        true
+    }
+
     async fn complete(
         &self,
         payload: Bytes,
         auth: &AuthConfig,
-        _spec: &ModelSpec,
+        spec: &ModelSpec,
         format: ProviderFormat,
         client_headers: &ClientHeaders,
     ) -> Result<Bytes> {
@@ -148,7 +157,7 @@ impl crate::providers::Provider for AnthropicProvider {
             );
             (self.chat_completions_url(), h)
         } else {
-            let mut h = self.build_headers(client_headers);
+            let mut h = self.build_headers(client_headers, spec.outgoing_model_id());
             auth.apply_headers(&mut h)?;
             (self.messages_url(), h)
         };
@@ -214,7 +223,7 @@ impl crate::providers::Provider for AnthropicProvider {
             );
             (self.chat_completions_url(), h)
         } else {
-            let mut h = self.build_headers(client_headers);
+            let mut h = self.build_headers(client_headers, spec.outgoing_model_id());
             auth.apply_headers(&mut h)?;
             (self.messages_url(), h)
         };
@@ -259,7 +268,7 @@ impl crate::providers::Provider for AnthropicProvider {
             .endpoint
             .join("models")
             .expect("join models path");
-        let mut headers = self.build_headers(&ClientHeaders::default());
+        let mut headers = self.build_headers(&ClientHeaders::default(), "");
         auth.apply_headers(&mut headers)?;
 
         let response = self.client.get(url).headers(headers).send().await?;
@@ -234,6 +234,10 @@ impl crate::providers::Provider for AzureProvider {
         vec![ProviderFormat::ChatCompletions, ProviderFormat::Responses]
     }
 
+    fn supports_request_compression(&self) -> bool {
+        true
+    }
+
     async fn complete(
         &self,
         payload: Bytes,
@@ -128,7 +128,7 @@ where
 
     if source_adapter.format() == format {
         return Ok(PreparedBedrockRequest {
-            bytes: rewrite_body_model_if_required(body, format, &spec.model),
+            bytes: rewrite_body_model_if_required(body, format, spec.outgoing_model_id()),
             requires_json_response,
         });
     }
@@ -139,7 +139,7 @@ where
     };
 
     inline_remote_image_urls_with_fetch(&mut request, fetch).await?;
-    request.model = Some(spec.model.clone());
+    request.model = Some(spec.outgoing_model_id().to_string());
 
     let target_adapter =
         adapter_for_format(format).ok_or(TransformError::UnsupportedTargetFormat(format))?;
@@ -166,7 +166,7 @@ where
         let content = match message {
             Message::System { content }
             | Message::Developer { content }
-            | Message::User { content } => content,
+            | Message::User { content, .. } => content,
             Message::Assistant { .. } | Message::Tool { .. } | Message::AdditionalTools { .. } => {
                 continue;
             }
@@ -657,6 +657,7 @@ mod tests {
     fn bedrock_spec(model: &str, format: ProviderFormat) -> ModelSpec {
         ModelSpec {
             model: model.to_string(),
+            provider_model_id: None,
             format,
             flavor: ModelFlavor::Chat,
             display_name: None,
@@ -671,6 +672,7 @@ mod tests {
             supports_streaming: true,
             extra: Default::default(),
             available_providers: Default::default(),
+            endpoints: vec![],
         }
     }
 
@@ -293,6 +293,7 @@ mod tests {
     fn spec(model: &str) -> ModelSpec {
         ModelSpec {
             model: model.to_string(),
+            provider_model_id: None,
             format: ProviderFormat::Google,
             flavor: ModelFlavor::Chat,
             display_name: None,
@@ -307,6 +308,7 @@ mod tests {
             supports_streaming: true,
             extra: Default::default(),
             available_providers: Default::default(),
+            endpoints: vec![],
         }
     }
 
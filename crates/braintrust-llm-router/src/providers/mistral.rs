@@ -94,6 +94,10 @@ impl crate::providers::Provider for MistralProvider {
         vec![ProviderFormat::Mistral, ProviderFormat::ChatCompletions]
     }
 
+    fn supports_request_compression(&self) -> bool {
+        true
+    }
+
     async fn complete(
         &self,
         payload: Bytes,
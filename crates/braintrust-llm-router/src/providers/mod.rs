@@ -136,6 +136,36 @@ impl ClientHeaders {
         self.apply_user_configured(headers);
     }
 
+    /// Look up a header by name (case-insensitive), preferring an explicit
+    /// per-request override over one forwarded from the client.
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        let name = name.to_lowercase();
+        self.user_configured
+            .get(&name)
+            .or_else(|| self.inner.get(&name))
+            .map(String::as_str)
+    }
+
+    /// Layer operator-configured default headers underneath this request's own headers.
+    ///
+    /// `defaults` (e.g. from `RouterBuilder::with_default_headers`) fill in headers this
+    /// request didn't already set; any header already present here - forwarded from the
+    /// client or explicitly configured for this request - wins on conflict.
+    pub fn with_defaults(&self, defaults: &HeaderMap) -> ClientHeaders {
+        let mut merged = ClientHeaders::new();
+        for (name, value) in defaults {
+            if self.inner.contains_key(name.as_str()) {
+                continue;
+            }
+            if let Ok(value) = value.to_str() {
+                let _ = merged.insert_user_configured(name.as_str(), value);
+            }
+        }
+        merged.inner.extend(self.inner.clone());
+        merged.user_configured.extend(self.user_configured.clone());
+        merged
+    }
+
     pub(crate) fn to_json_headers(&self) -> HeaderMap {
         let mut headers = HeaderMap::new();
         self.apply_inner(&mut headers);
@@ -299,6 +329,18 @@ pub trait Provider: Send + Sync {
     fn build_headers(&self, client_headers: &ClientHeaders) -> HeaderMap {
         client_headers.to_json_headers()
     }
+
+    /// Whether this provider's API accepts a gzip-compressed request body
+    /// (`Content-Encoding: gzip`).
+    ///
+    /// Defaults to `false`; only override for a provider whose upstream is
+    /// known to decompress request bodies, since sending a compressed body
+    /// to an endpoint that doesn't expect one fails the request outright.
+    /// Only takes effect when [`RouterBuilder::with_request_compression`](crate::RouterBuilder::with_request_compression)
+    /// is also enabled - this is the per-provider half of that gate.
+    fn supports_request_compression(&self) -> bool {
+        false
+    }
 }
 
 impl dyn Provider {
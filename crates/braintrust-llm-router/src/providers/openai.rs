@@ -3,6 +3,7 @@ use std::time::Duration;
 use async_trait::async_trait;
 use bytes::Bytes;
 use lingua::serde_json::Value;
+use lingua::universal::message::ProviderOptions;
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::Url;
 
@@ -197,6 +198,10 @@ impl crate::providers::Provider for OpenAIProvider {
         vec![ProviderFormat::ChatCompletions, ProviderFormat::Responses]
     }
 
+    fn supports_request_compression(&self) -> bool {
+        true
+    }
+
     async fn complete(
         &self,
         payload: Bytes,
@@ -331,6 +336,48 @@ pub struct OpenAICompatibleEndpoint {
     pub url: &'static str,
     /// Whether the URL is a template containing `<model>` placeholder.
     pub is_template: bool,
+    /// Extra top-level request/response fields this vendor supports that have
+    /// no canonical `UniversalParams` mapping (e.g. Together's
+    /// `repetition_penalty`, some vendors' `reasoning_effort`).
+    ///
+    /// Callers configuring a vendor not in [`openai_compatible_endpoint`] can
+    /// set this directly rather than adding a new match arm - see
+    /// [`OpenAICompatibleEndpoint::apply_extra_passthrough_fields`].
+    pub extra_passthrough_fields: &'static [&'static str],
+}
+
+impl OpenAICompatibleEndpoint {
+    /// Copies `extra_passthrough_fields` present in `source` into `target` untouched,
+    /// and returns them as [`ProviderOptions`] keyed by `endpoint_id` so a Universal
+    /// response round trip can restore them without a dedicated adapter for this vendor.
+    ///
+    /// Returns `None` if this endpoint declares no passthrough fields, or none of them
+    /// are present in `source`.
+    pub fn apply_extra_passthrough_fields(
+        &self,
+        endpoint_id: &str,
+        source: &Value,
+        target: &mut Value,
+    ) -> Option<ProviderOptions> {
+        let source_obj = source.as_object()?;
+        let target_obj = target.as_object_mut()?;
+
+        let mut copied = lingua::serde_json::Map::new();
+        for &field in self.extra_passthrough_fields {
+            if let Some(value) = source_obj.get(field) {
+                target_obj.insert(field.to_string(), value.clone());
+                copied.insert(field.to_string(), value.clone());
+            }
+        }
+
+        if copied.is_empty() {
+            return None;
+        }
+
+        let mut options = lingua::serde_json::Map::new();
+        options.insert(endpoint_id.to_string(), Value::Object(copied));
+        Some(ProviderOptions { options })
+    }
 }
 
 /// Returns true if the provider kind uses the OpenAI API format.
@@ -366,50 +413,62 @@ pub fn openai_compatible_endpoint(kind: &str) -> Option<OpenAICompatibleEndpoint
         "groq" => Some(OpenAICompatibleEndpoint {
             url: "https://api.groq.com/openai/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "fireworks" => Some(OpenAICompatibleEndpoint {
             url: "https://api.fireworks.ai/inference/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "perplexity" => Some(OpenAICompatibleEndpoint {
             url: "https://api.perplexity.ai",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "together" => Some(OpenAICompatibleEndpoint {
             url: "https://api.together.xyz/v1",
             is_template: false,
+            extra_passthrough_fields: &["repetition_penalty", "min_p"],
         }),
         "replicate" => Some(OpenAICompatibleEndpoint {
             url: "https://openai-proxy.replicate.com/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "lepton" => Some(OpenAICompatibleEndpoint {
             url: "https://<model>.lepton.run/api/v1/",
             is_template: true,
+            extra_passthrough_fields: &[],
         }),
         "baseten" => Some(OpenAICompatibleEndpoint {
             url: "https://inference.baseten.co/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "cerebras" => Some(OpenAICompatibleEndpoint {
             url: "https://api.cerebras.ai/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "xai" | "xAI" => Some(OpenAICompatibleEndpoint {
             url: "https://api.x.ai/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "ollama" => Some(OpenAICompatibleEndpoint {
             url: "http://127.0.0.1:11434/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "cohere" => Some(OpenAICompatibleEndpoint {
             url: "https://api.cohere.com/compatibility/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         "openrouter" => Some(OpenAICompatibleEndpoint {
             url: "https://openrouter.ai/api/v1",
             is_template: false,
+            extra_passthrough_fields: &[],
         }),
         _ => None,
     }
@@ -419,6 +478,60 @@ pub fn openai_compatible_endpoint(kind: &str) -> Option<OpenAICompatibleEndpoint
 mod tests {
     use super::*;
 
+    #[test]
+    fn openrouter_is_openai_compatible_with_default_endpoint() {
+        assert!(is_openai_compatible("openrouter"));
+        let endpoint = openai_compatible_endpoint("openrouter").expect("known endpoint");
+        assert_eq!(endpoint.url, "https://openrouter.ai/api/v1");
+        assert!(!endpoint.is_template);
+    }
+
+    #[test]
+    fn together_endpoint_passes_through_repetition_penalty_and_min_p() {
+        let endpoint = openai_compatible_endpoint("together").expect("known endpoint");
+
+        let source = lingua::serde_json::json!({
+            "model": "meta-llama/Llama-3-70b",
+            "messages": [{"role": "user", "content": "hi"}],
+            "repetition_penalty": 1.2,
+            "min_p": 0.05,
+        });
+        let mut target = lingua::serde_json::json!({
+            "model": "meta-llama/Llama-3-70b",
+            "messages": [{"role": "user", "content": "hi"}],
+        });
+
+        let options = endpoint
+            .apply_extra_passthrough_fields("together", &source, &mut target)
+            .expect("repetition_penalty and min_p should be copied");
+
+        assert_eq!(
+            target.get("repetition_penalty"),
+            Some(&Value::from(1.2)),
+            "field should be forwarded to the outgoing body untouched"
+        );
+        assert_eq!(target.get("min_p"), Some(&Value::from(0.05)));
+        assert_eq!(
+            options.options.get("together"),
+            Some(&lingua::serde_json::json!({
+                "repetition_penalty": 1.2,
+                "min_p": 0.05,
+            }))
+        );
+    }
+
+    #[test]
+    fn endpoint_with_no_passthrough_fields_configured_returns_none() {
+        let endpoint = openai_compatible_endpoint("groq").expect("known endpoint");
+        let source = lingua::serde_json::json!({"repetition_penalty": 1.2});
+        let mut target = lingua::serde_json::json!({});
+
+        assert!(endpoint
+            .apply_extra_passthrough_fields("groq", &source, &mut target)
+            .is_none());
+        assert!(target.get("repetition_penalty").is_none());
+    }
+
     #[test]
     fn resolves_template_endpoint() {
         let config = OpenAIConfig {
@@ -599,6 +599,7 @@ mod tests {
         let provider = provider();
         let spec = ModelSpec {
             model: "publishers/google/models/gemini-3.1-pro-preview".to_string(),
+            provider_model_id: None,
             format: ProviderFormat::Google,
             flavor: crate::catalog::ModelFlavor::Chat,
             display_name: None,
@@ -622,6 +623,7 @@ mod tests {
                 map
             },
             available_providers: vec![],
+            endpoints: vec![],
         };
         assert_eq!(provider.resolve_location(&spec), "europe-west4");
     }
@@ -631,6 +633,7 @@ mod tests {
         let provider = provider();
         let spec = ModelSpec {
             model: "publishers/google/models/gemini-pro".to_string(),
+            provider_model_id: None,
             format: ProviderFormat::Google,
             flavor: crate::catalog::ModelFlavor::Chat,
             display_name: None,
@@ -645,6 +648,7 @@ mod tests {
             supports_streaming: true,
             extra: ::serde_json::Map::new(),
             available_providers: vec![],
+            endpoints: vec![],
         };
         assert_eq!(provider.resolve_location(&spec), "us-central1");
     }
@@ -0,0 +1,236 @@
+//! In-memory cache of non-streaming completions, keyed by target model plus
+//! a canonical hash of the outgoing request body.
+
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use bytes::Bytes;
+use lingua::canonicalize_payload;
+use lingua::serde_json::Value;
+use lingua::ProviderFormat;
+use lru::LruCache;
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
+use tokio::time::Instant;
+
+use crate::router::CompleteResponseWithRaw;
+
+/// Configuration for [`ResponseCache`], set via
+/// [`RouterBuilder::with_response_cache`](crate::RouterBuilder::with_response_cache).
+#[derive(Debug, Clone)]
+pub(crate) struct ResponseCacheConfig {
+    /// Maximum number of distinct (model, canonical request) entries to retain.
+    pub(crate) capacity: usize,
+    /// How long a cached response stays eligible to be served.
+    pub(crate) ttl: Duration,
+    /// When `false` (the default), a request carrying `temperature > 0` and no
+    /// `seed` is treated as non-deterministic and is neither served from nor
+    /// written to the cache, since replaying a stale sample would silently
+    /// change the caller's output distribution. Toggle with
+    /// [`RouterBuilder::with_response_cache_nondeterministic`](crate::RouterBuilder::with_response_cache_nondeterministic).
+    pub(crate) cache_nondeterministic: bool,
+}
+
+struct CachedResponse {
+    value: CompleteResponseWithRaw,
+    inserted_at: Instant,
+}
+
+/// A size-bounded, TTL-expiring cache of non-streaming completions.
+///
+/// Entries are evicted least-recently-used once `capacity` is exceeded, and
+/// treated as absent once `ttl` has elapsed since insertion (expired entries
+/// are dropped lazily, on the next lookup that finds them).
+pub(crate) struct ResponseCache {
+    entries: Mutex<LruCache<String, CachedResponse>>,
+    ttl: Duration,
+    cache_nondeterministic: bool,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(config: ResponseCacheConfig) -> Self {
+        let capacity = NonZeroUsize::new(config.capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            ttl: config.ttl,
+            cache_nondeterministic: config.cache_nondeterministic,
+        }
+    }
+
+    /// Compute a cache key for `payload` (in `format`'s wire shape) bound for
+    /// `model`, or `None` if the request isn't eligible for caching (the
+    /// payload isn't valid JSON, or it looks non-deterministic and
+    /// `cache_nondeterministic` is off).
+    pub(crate) fn key_for(
+        &self,
+        model: &str,
+        format: ProviderFormat,
+        payload: &Bytes,
+    ) -> Option<String> {
+        let value: Value = lingua::serde_json::from_slice(payload).ok()?;
+        if !self.cache_nondeterministic && Self::is_nondeterministic(format, &value) {
+            return None;
+        }
+        let canonical = canonicalize_payload(payload.clone()).ok()?;
+        let mut hasher = Sha256::new();
+        hasher.update(model.as_bytes());
+        hasher.update(b"|");
+        hasher.update(&canonical);
+        Some(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Google's `GenerateContentRequest` nests sampling params under
+    /// `generationConfig`, unlike OpenAI/Anthropic's flat top-level fields,
+    /// so the lookup location has to follow the wire format.
+    fn is_nondeterministic(format: ProviderFormat, payload: &Value) -> bool {
+        let sampling_params = match format {
+            ProviderFormat::Google => payload.get("generationConfig").unwrap_or(payload),
+            _ => payload,
+        };
+        let temperature = sampling_params.get("temperature").and_then(Value::as_f64);
+        let has_seed = sampling_params.get("seed").is_some();
+        matches!(temperature, Some(t) if t > 0.0) && !has_seed
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<CompleteResponseWithRaw> {
+        let mut entries = self.entries.lock();
+        let hit = entries.get(key)?;
+        if hit.inserted_at.elapsed() > self.ttl {
+            entries.pop(key);
+            return None;
+        }
+        Some(hit.value.clone())
+    }
+
+    pub(crate) fn insert(&self, key: String, value: CompleteResponseWithRaw) {
+        self.entries.lock().put(
+            key,
+            CachedResponse {
+                value,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lingua::ParsableResponseInfo;
+
+    fn config(capacity: usize, ttl: Duration) -> ResponseCacheConfig {
+        ResponseCacheConfig {
+            capacity,
+            ttl,
+            cache_nondeterministic: false,
+        }
+    }
+
+    fn response(body: &str) -> CompleteResponseWithRaw {
+        CompleteResponseWithRaw {
+            response: Bytes::from(body.to_string()),
+            raw_response: Bytes::from(body.to_string()),
+            parsable_info: ParsableResponseInfo::valid(),
+            requires_json_response: false,
+            request_id: "req_test".to_string(),
+        }
+    }
+
+    #[test]
+    fn key_for_is_stable_across_key_order_but_distinguishes_model() {
+        let cache = ResponseCache::new(config(10, Duration::from_secs(60)));
+        let a = Bytes::from_static(br#"{"model":"gpt-5-mini","messages":[]}"#);
+        let b = Bytes::from_static(br#"{"messages":[],"model":"gpt-5-mini"}"#);
+
+        let key_a = cache
+            .key_for("gpt-5-mini", ProviderFormat::ChatCompletions, &a)
+            .expect("cacheable");
+        let key_b = cache
+            .key_for("gpt-5-mini", ProviderFormat::ChatCompletions, &b)
+            .expect("cacheable");
+        assert_eq!(key_a, key_b);
+
+        let key_other_model = cache
+            .key_for("gpt-5", ProviderFormat::ChatCompletions, &a)
+            .expect("cacheable");
+        assert_ne!(key_a, key_other_model);
+    }
+
+    #[test]
+    fn key_for_skips_nondeterministic_requests_by_default() {
+        let cache = ResponseCache::new(config(10, Duration::from_secs(60)));
+        let hot = Bytes::from_static(br#"{"model":"gpt-5-mini","temperature":0.7}"#);
+        let seeded = Bytes::from_static(br#"{"model":"gpt-5-mini","temperature":0.7,"seed":1}"#);
+        let cold = Bytes::from_static(br#"{"model":"gpt-5-mini","temperature":0}"#);
+
+        assert!(cache
+            .key_for("gpt-5-mini", ProviderFormat::ChatCompletions, &hot)
+            .is_none());
+        assert!(cache
+            .key_for("gpt-5-mini", ProviderFormat::ChatCompletions, &seeded)
+            .is_some());
+        assert!(cache
+            .key_for("gpt-5-mini", ProviderFormat::ChatCompletions, &cold)
+            .is_some());
+    }
+
+    #[test]
+    fn key_for_allows_nondeterministic_requests_when_configured() {
+        let mut cfg = config(10, Duration::from_secs(60));
+        cfg.cache_nondeterministic = true;
+        let cache = ResponseCache::new(cfg);
+        let hot = Bytes::from_static(br#"{"model":"gpt-5-mini","temperature":0.7}"#);
+
+        assert!(cache
+            .key_for("gpt-5-mini", ProviderFormat::ChatCompletions, &hot)
+            .is_some());
+    }
+
+    #[test]
+    fn key_for_skips_nondeterministic_google_requests_with_nested_generation_config() {
+        let cache = ResponseCache::new(config(10, Duration::from_secs(60)));
+        let hot = Bytes::from_static(br#"{"contents":[],"generationConfig":{"temperature":0.7}}"#);
+        let seeded = Bytes::from_static(
+            br#"{"contents":[],"generationConfig":{"temperature":0.7,"seed":1}}"#,
+        );
+
+        assert!(cache
+            .key_for("gemini-2.5-pro", ProviderFormat::Google, &hot)
+            .is_none());
+        assert!(cache
+            .key_for("gemini-2.5-pro", ProviderFormat::Google, &seeded)
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn get_returns_none_on_miss_and_hit_after_insert() {
+        let cache = ResponseCache::new(config(10, Duration::from_secs(60)));
+        assert!(cache.get("missing").is_none());
+
+        cache.insert("key".to_string(), response("hello"));
+        let hit = cache.get("key").expect("should be a hit");
+        assert_eq!(hit.response, Bytes::from_static(b"hello"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn entries_expire_after_ttl() {
+        let cache = ResponseCache::new(config(10, Duration::from_secs(30)));
+        cache.insert("key".to_string(), response("hello"));
+
+        tokio::time::advance(Duration::from_secs(29)).await;
+        assert!(cache.get("key").is_some());
+
+        tokio::time::advance(Duration::from_secs(2)).await;
+        assert!(cache.get("key").is_none());
+    }
+
+    #[test]
+    fn capacity_evicts_least_recently_used_entry() {
+        let cache = ResponseCache::new(config(1, Duration::from_secs(60)));
+        cache.insert("first".to_string(), response("a"));
+        cache.insert("second".to_string(), response("b"));
+
+        assert!(cache.get("first").is_none());
+        assert!(cache.get("second").is_some());
+    }
+}
@@ -51,7 +51,10 @@ impl RetryStrategy {
     }
 
     pub fn next_delay(&mut self, error: &Error) -> Option<Duration> {
-        if self.attempts >= self.policy.max_attempts || !error.is_retryable() {
+        if self.attempts >= self.policy.max_attempts {
+            return None;
+        }
+        if !error.is_retriable() && error.retry_after().is_none() {
             return None;
         }
 
@@ -82,7 +85,7 @@ impl RetryStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::error::Error;
+    use crate::error::{Error, UpstreamHttpError};
     use anyhow::anyhow;
 
     fn base_policy() -> RetryPolicy {
@@ -150,6 +153,47 @@ mod tests {
         assert_eq!(delay, Duration::from_secs(1));
     }
 
+    #[test]
+    fn provider_error_with_429_status_is_retried_without_explicit_retry_after() {
+        let policy = base_policy();
+        let mut strategy = policy.strategy();
+
+        let err = Error::Provider {
+            provider: "stub".into(),
+            source: anyhow!("rate limited"),
+            retry_after: None,
+            http: Some(UpstreamHttpError {
+                status: 429,
+                headers: vec![],
+                body: "rate limited".into(),
+                request_id: None,
+            }),
+        };
+
+        let delay = strategy.next_delay(&err).expect("429 should be retried");
+        assert_eq!(delay, Duration::from_millis(200));
+    }
+
+    #[test]
+    fn provider_error_with_400_status_is_not_retried() {
+        let policy = base_policy();
+        let mut strategy = policy.strategy();
+
+        let err = Error::Provider {
+            provider: "stub".into(),
+            source: anyhow!("bad request"),
+            retry_after: None,
+            http: Some(UpstreamHttpError {
+                status: 400,
+                headers: vec![],
+                body: "bad request".into(),
+                request_id: None,
+            }),
+        };
+
+        assert!(strategy.next_delay(&err).is_none());
+    }
+
     #[tokio::test]
     async fn jitter_stays_within_expected_bounds() {
         let mut policy = base_policy();
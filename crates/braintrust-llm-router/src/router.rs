@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Semaphore;
 use tokio::time::sleep;
 
 #[cfg(feature = "tracing")]
@@ -10,25 +11,36 @@ use bytes::Bytes;
 
 use crate::auth::AuthConfig;
 use crate::catalog::{
-    is_gemini_api_model, load_catalog_from_disk, ModelCatalog, ModelResolver, ModelSpec,
+    is_gemini_api_model, load_catalog_from_disk, Endpoint, ModelCatalog, ModelFlavor,
+    ModelResolver, ModelSpec,
 };
 use crate::client::ClientSettings;
+use crate::compression;
 use crate::error::{Error, Result};
 use crate::providers::{
     enable_streaming_payload, prepare_bedrock_request, requires_bedrock_request_preparation,
     rewrite_body_model_if_required, ClientHeaders, Provider,
 };
+use crate::response_cache::{ResponseCache, ResponseCacheConfig};
 use crate::retry::{RetryPolicy, RetryStrategy};
 use crate::streaming::{
-    transform_stream, transform_stream_with_capture, RawStreamChunkCapture, ResponseStream,
+    transform_stream_with_instrumentation, FirstTokenCallback, RawStreamChunkCapture,
+    ResponseStream,
 };
+use lingua::processing::adapters::adapter_for_format;
 use lingua::serde_json::Value;
 use lingua::ProviderFormat;
-use lingua::{ParsableResponseInfo, TransformError, TransformResult};
-use serde::Deserialize;
+use lingua::{
+    Message, ParsableResponseInfo, TransformError, TransformResult, UniversalRequest,
+    UniversalResponse,
+};
+use serde::{Deserialize, Serialize};
 
 // Re-export for convenience in dependent crates
-pub use lingua::{extract_request_hints, RequestHints};
+pub use lingua::{
+    extract_request_hints, extract_request_hints_with_metadata_key, RequestHints, RequestKind,
+};
+use reqwest::header::HeaderMap;
 use reqwest::Url;
 
 #[derive(Debug, Clone)]
@@ -37,6 +49,9 @@ pub struct CompleteResponseWithRaw {
     pub raw_response: Bytes,
     pub parsable_info: ParsableResponseInfo,
     pub requires_json_response: bool,
+    /// The request-id this call was correlated under (see
+    /// [`RouterBuilder::with_request_id_header`]).
+    pub request_id: String,
 }
 
 use crate::providers::{
@@ -163,6 +178,77 @@ impl ProviderRoute {
     }
 }
 
+/// Resolved routing information for a model, without dispatching a request.
+///
+/// Returned by [`Router::resolve`] for pre-flight decisions (cost estimation, admin
+/// UIs) that need to know how a model would be routed without executing a completion.
+#[derive(Debug, Clone)]
+pub struct ResolvedRoute {
+    /// The alias of the provider that would serve this model.
+    pub provider_id: String,
+    /// The wire format that would be used for the request.
+    pub format: ProviderFormat,
+    /// The catalog entry backing this model.
+    pub spec: Arc<ModelSpec>,
+}
+
+/// Pluggable strategy for choosing a route for a model.
+///
+/// The catalog-only signature (no access to registered providers or auth)
+/// keeps strategies easy to unit test and free of I/O; they decide *which*
+/// catalog entry to serve a request with, not how to reach it. Install a
+/// custom strategy with [`RouterBuilder::with_strategy`] to implement
+/// weighted, least-loaded, or cost-based routing across a model family.
+///
+/// The default, [`CatalogRoutingStrategy`], resolves `hints.model` directly
+/// from the catalog, matching the router's behavior prior to this trait's
+/// introduction.
+pub trait RoutingStrategy: Send + Sync {
+    fn route(&self, hints: &RequestHints, catalog: &ModelCatalog) -> Option<ResolvedRoute>;
+}
+
+/// Default [`RoutingStrategy`]: looks `hints.model` up in the catalog as-is
+/// and uses its first `available_providers` entry.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CatalogRoutingStrategy;
+
+impl RoutingStrategy for CatalogRoutingStrategy {
+    fn route(&self, hints: &RequestHints, catalog: &ModelCatalog) -> Option<ResolvedRoute> {
+        let model = hints.model.as_deref()?;
+        let spec = catalog.get(model)?;
+        let provider_id = spec.available_providers.first()?.clone();
+        Some(ResolvedRoute {
+            provider_id,
+            format: spec.format,
+            spec,
+        })
+    }
+}
+
+/// Pluggable hook for inspecting or mutating a [`UniversalRequest`]/[`UniversalResponse`]
+/// as it passes through [`Router::complete_universal`], e.g. for PII redaction or
+/// prompt-injection defense.
+///
+/// Hooks are registered with [`RouterBuilder::with_hook`] and run in registration
+/// order. A `before_request` hook can short-circuit the request by returning an
+/// error, which becomes [`Error::RejectedByHook`]. Both methods default to a
+/// no-op so a hook only needs to override the one it cares about.
+pub trait RouterHook: Send + Sync {
+    /// Inspect or mutate the outgoing request before it's converted to the
+    /// target provider's format. Returning `Err` aborts the request before
+    /// any provider is contacted.
+    fn before_request(&self, req: &mut UniversalRequest) -> Result<()> {
+        let _ = req;
+        Ok(())
+    }
+
+    /// Inspect or mutate the parsed response before it's returned to the caller.
+    fn after_response(&self, resp: &mut UniversalResponse) -> Result<()> {
+        let _ = resp;
+        Ok(())
+    }
+}
+
 /// Metadata about how an incoming request was interpreted, mainly to be used
 /// for observability.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -185,6 +271,29 @@ pub struct RouterMetadata {
     pub provider_format: ProviderFormat,
 }
 
+/// A single catalog entry, shaped like an OpenAI `/v1/models` list item.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelInfo {
+    /// The model name, as it appears in the catalog and in request bodies.
+    pub id: String,
+    /// The object type, always `"model"`.
+    pub object: &'static str,
+    /// Placeholder creation timestamp - the catalog doesn't track when a model
+    /// was added, so this is always `0` rather than fabricating a real one.
+    pub created: i64,
+    /// The provider that serves this model (e.g. `"openai"`, or the first
+    /// entry of `available_providers` when the catalog specifies one).
+    pub owned_by: String,
+}
+
+/// A list of models, shaped like an OpenAI `/v1/models` response.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelList {
+    /// The object type, always `"list"`.
+    pub object: &'static str,
+    pub data: Vec<ModelInfo>,
+}
+
 #[cfg(test)]
 type ResolvedProviderForTest = (
     String,
@@ -246,7 +355,9 @@ async fn prepare_provider_request(
         ));
     }
 
-    let model_override = options.rewrite_body_model.then_some(spec.model.as_str());
+    let model_override = options
+        .rewrite_body_model
+        .then_some(spec.outgoing_model_id());
     let (
         transformed,
         detected_format,
@@ -265,6 +376,7 @@ async fn prepare_provider_request(
                     bytes,
                     source_format,
                     actual_target_format,
+                    ..
                 } => (
                     bytes,
                     Some(source_format),
@@ -280,7 +392,7 @@ async fn prepare_provider_request(
     };
 
     let transformed = if options.rewrite_body_model && maybe_rewrite_model {
-        rewrite_body_model_if_required(transformed, actual_format, &spec.model)
+        rewrite_body_model_if_required(transformed, actual_format, spec.outgoing_model_id())
     } else {
         transformed
     };
@@ -306,6 +418,36 @@ async fn prepare_provider_request(
     }
 }
 
+/// Default cap on concurrent provider calls for batch operations like
+/// [`Router::health_check_all`], used when `RouterBuilder::with_concurrency_limit`
+/// isn't called.
+const DEFAULT_CONCURRENCY_LIMIT: usize = 16;
+
+/// Header used to correlate a request with upstream provider calls when
+/// [`RouterBuilder::with_request_id_header`] hasn't overridden it.
+pub const DEFAULT_REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Generate a fresh request-id for correlating one call across tracing spans,
+/// the outgoing provider request, and any resulting error.
+fn generate_request_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Reject `output_format` up front if `spec` restricts which [`Endpoint`]s it
+/// may be driven through. Shared by both the non-streaming and streaming
+/// completion paths so a model's `endpoints` restriction can't be bypassed by
+/// going through one and not the other.
+fn check_endpoint_supported(spec: &ModelSpec, output_format: ProviderFormat) -> Result<()> {
+    let endpoint = Endpoint::for_output_format(output_format);
+    if !spec.supports_endpoint(endpoint) {
+        return Err(Error::UnsupportedEndpoint {
+            model: spec.model.clone(),
+            endpoint,
+        });
+    }
+    Ok(())
+}
+
 pub struct Router {
     catalog: Arc<ModelCatalog>,
     resolver: ModelResolver,
@@ -313,6 +455,14 @@ pub struct Router {
     auth_configs: HashMap<String, AuthConfig>,     // alias -> auth
     formats: HashMap<ProviderFormat, String>,      // format -> default alias
     retry_policy: RetryPolicy,
+    default_headers: HeaderMap,
+    concurrency_limit: usize,
+    unknown_model_policy: UnknownModelPolicy,
+    response_cache: Option<Arc<ResponseCache>>,
+    strategy: Box<dyn RoutingStrategy>,
+    hooks: Vec<Box<dyn RouterHook>>,
+    request_compression: bool,
+    request_id_header: Option<String>,
 }
 
 impl Router {
@@ -324,6 +474,71 @@ impl Router {
         Arc::clone(&self.catalog)
     }
 
+    /// Resolve routing hints against the router's configured
+    /// [`RoutingStrategy`] (see [`RouterBuilder::with_strategy`]), without
+    /// dispatching a request.
+    ///
+    /// Unlike [`Router::resolve`], this doesn't require registered providers
+    /// or auth to succeed - it only consults the catalog through the
+    /// strategy.
+    pub fn route(&self, hints: &RequestHints) -> Option<ResolvedRoute> {
+        self.strategy.route(hints, &self.catalog)
+    }
+
+    /// List known models from the catalog, shaped like an OpenAI `/v1/models` response.
+    ///
+    /// Intended to back an OpenAI-compatible `/v1/models` passthrough endpoint.
+    pub fn models(&self) -> ModelList {
+        let data = self
+            .catalog
+            .iter()
+            .map(|(name, spec)| ModelInfo {
+                id: name.clone(),
+                object: "model",
+                created: 0,
+                owned_by: spec
+                    .available_providers
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| spec.format.to_string()),
+            })
+            .collect();
+        ModelList {
+            object: "list",
+            data,
+        }
+    }
+
+    /// Run a health check against every registered provider.
+    ///
+    /// Concurrency is bounded by the router's configured concurrency limit (see
+    /// [`RouterBuilder::with_concurrency_limit`]) so checking many providers at once
+    /// doesn't overwhelm the host. Returns one result per provider alias.
+    pub async fn health_check_all(&self) -> HashMap<String, Result<()>> {
+        let semaphore = Arc::new(Semaphore::new(self.concurrency_limit.max(1)));
+        let checks = self.providers.iter().map(|(alias, provider)| {
+            let semaphore = Arc::clone(&semaphore);
+            let provider = Arc::clone(provider);
+            let auth = self.auth_configs.get(alias).cloned();
+            let alias = alias.clone();
+            async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("semaphore is never closed");
+                let result = match auth {
+                    Some(auth) => provider.health_check(&auth).await,
+                    None => Err(Error::NoAuth(alias.clone())),
+                };
+                (alias, result)
+            }
+        });
+        futures::future::join_all(checks)
+            .await
+            .into_iter()
+            .collect()
+    }
+
     // Internal method to create a prepared request, handles streaming and non-streaming requests.
     async fn create_prepared_request_internal(
         &self,
@@ -431,6 +646,78 @@ impl Router {
         self.complete_internal(request, client_headers).await
     }
 
+    /// Send a [`UniversalRequest`] straight to the resolved provider and parse
+    /// its response back into a [`UniversalResponse`].
+    ///
+    /// [`Router::complete`] and friends take and return provider-format bytes,
+    /// which is right for callers proxying an existing HTTP request. Callers
+    /// that already hold a `UniversalRequest` (e.g. an agent framework built
+    /// on lingua's universal types) would otherwise have to serialize it to
+    /// some provider format just so the router could detect and re-parse it;
+    /// `complete_universal` skips that redundant JSON hop by resolving the
+    /// model's own format and converting directly with that format's adapter.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "bt.router.complete_universal",
+            skip(self, req, client_headers),
+            fields(llm.model = %target_model)
+        )
+    )]
+    pub async fn complete_universal(
+        &self,
+        mut req: UniversalRequest,
+        target_model: &str,
+        client_headers: &ClientHeaders,
+    ) -> Result<UniversalResponse> {
+        let (_, catalog_format, _) = self.resolve_model(target_model)?;
+        let route = self
+            .resolve_provider_routes(target_model, catalog_format, &[])?
+            .into_iter()
+            .next()
+            .ok_or(Error::NoProvider(catalog_format))?;
+
+        for hook in &self.hooks {
+            hook.before_request(&mut req)?;
+        }
+
+        req.model = Some(route.spec.outgoing_model_id().to_string());
+
+        let adapter = adapter_for_format(route.format).ok_or(Error::NoProvider(route.format))?;
+        adapter.apply_defaults(&mut req);
+        // `req` was built by the caller/hooks above, not parsed from a wire
+        // payload, so it's already trusted; skip the extra validation that
+        // exists to catch a hand-written client's mistakes.
+        let payload = adapter
+            .request_from_universal_unchecked(&req)
+            .map_err(Error::Lingua)?;
+        let body = Bytes::from(lingua::serde_json::to_vec(&payload)?);
+
+        let client_headers = client_headers.with_defaults(&self.default_headers);
+        let (response_bytes, _request_id) = self
+            .execute_with_retry(
+                route.provider,
+                &route.auth,
+                route.spec,
+                route.format,
+                body,
+                self.retry_policy.strategy(),
+                &client_headers,
+            )
+            .await?;
+
+        let response_payload = lingua::serde_json::from_slice(&response_bytes)?;
+        let mut response = adapter
+            .response_to_universal(response_payload)
+            .map_err(Error::Lingua)?;
+
+        for hook in &self.hooks {
+            hook.after_response(&mut response)?;
+        }
+
+        Ok(response)
+    }
+
     async fn complete_internal(
         &self,
         request: PreparedRequest,
@@ -446,8 +733,24 @@ impl Router {
             requires_json_response,
             strategy,
         } = request.inner;
+        check_endpoint_supported(&spec, output_format)?;
+
+        let cache_entry = self
+            .response_cache
+            .as_ref()
+            .and_then(|cache| Some((cache, cache.key_for(&spec.model, format, &payload)?)));
+        let client_headers = client_headers.with_defaults(&self.default_headers);
+        if let Some((cache, key)) = &cache_entry {
+            if let Some(cached) = cache.get(key) {
+                return Ok(CompleteResponseWithRaw {
+                    request_id: self.request_id_for(&client_headers),
+                    ..cached
+                });
+            }
+        }
+
         let fallback_response_model = spec.model.clone();
-        let response_bytes = self
+        let (response_bytes, request_id) = self
             .execute_with_retry(
                 provider,
                 &auth,
@@ -455,7 +758,7 @@ impl Router {
                 format,
                 payload,
                 strategy,
-                client_headers,
+                &client_headers,
             )
             .await?;
         let result = lingua::transform_response(response_bytes.clone(), output_format).map_err(
@@ -470,12 +773,17 @@ impl Router {
                 replace_transformed_response_model(bytes, &fallback_response_model)?
             }
         };
-        Ok(CompleteResponseWithRaw {
+        let result = CompleteResponseWithRaw {
             response,
             raw_response: response_bytes,
             parsable_info: result.parsable_info,
             requires_json_response,
-        })
+            request_id,
+        };
+        if let Some((cache, key)) = cache_entry {
+            cache.insert(key, result.clone());
+        }
+        Ok(result)
     }
 
     /// Create a prepared streaming request from raw body bytes.
@@ -532,7 +840,7 @@ impl Router {
         client_headers: &ClientHeaders,
         gateway_request_id: Option<String>,
     ) -> Result<ResponseStream> {
-        self.complete_stream_internal(request, client_headers, gateway_request_id, None)
+        self.complete_stream_internal(request, client_headers, gateway_request_id, None, None)
             .await
     }
 
@@ -556,6 +864,38 @@ impl Router {
             client_headers,
             gateway_request_id,
             Some(raw_chunk_capture),
+            None,
+        )
+        .await
+    }
+
+    /// Execute a prepared streaming request, invoking `first_token_callback`
+    /// once with the time elapsed between request dispatch and the first
+    /// content-bearing chunk (see [`crate::streaming::FirstTokenCallback`]).
+    ///
+    /// Useful for SLO monitoring of time-to-first-token without the caller
+    /// having to inspect chunk contents itself.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "bt.router.complete_stream",
+            skip(self, request, client_headers, gateway_request_id, first_token_callback),
+            fields(llm.model = %request.inner.spec.model)
+        )
+    )]
+    pub async fn complete_stream_with_first_token_callback(
+        &self,
+        request: PreparedStreamRequest,
+        client_headers: &ClientHeaders,
+        gateway_request_id: Option<String>,
+        first_token_callback: FirstTokenCallback,
+    ) -> Result<ResponseStream> {
+        self.complete_stream_internal(
+            request,
+            client_headers,
+            gateway_request_id,
+            None,
+            Some(first_token_callback),
         )
         .await
     }
@@ -566,6 +906,7 @@ impl Router {
         client_headers: &ClientHeaders,
         gateway_request_id: Option<String>,
         raw_chunk_capture: Option<RawStreamChunkCapture>,
+        first_token_callback: Option<FirstTokenCallback>,
     ) -> Result<ResponseStream> {
         let PreparedRequestInner {
             provider,
@@ -577,25 +918,87 @@ impl Router {
             requires_json_response: _,
             strategy: _,
         } = request.inner;
+        check_endpoint_supported(&spec, output_format)?;
         let allow_full_response_fallback = spec.supports_streaming;
+        let client_headers = client_headers.with_defaults(&self.default_headers);
+        let dispatch_start = std::time::Instant::now();
         let raw_stream = provider
             .clone()
-            .complete_stream(payload, &auth, spec.as_ref(), format, client_headers)
+            .complete_stream(payload, &auth, spec.as_ref(), format, &client_headers)
             .await?;
-        Ok(match raw_chunk_capture {
-            Some(capture) => transform_stream_with_capture(
-                raw_stream,
-                output_format,
-                allow_full_response_fallback,
-                gateway_request_id,
-                Some(capture),
-            ),
-            None => transform_stream(
-                raw_stream,
-                output_format,
-                allow_full_response_fallback,
-                gateway_request_id,
-            ),
+        Ok(transform_stream_with_instrumentation(
+            raw_stream,
+            output_format,
+            allow_full_response_fallback,
+            gateway_request_id,
+            raw_chunk_capture,
+            first_token_callback.map(|callback| (dispatch_start, callback)),
+        ))
+    }
+
+    /// Resolve `model` via the catalog, applying [`UnknownModelPolicy`] when
+    /// the catalog doesn't recognize it.
+    fn resolve_model(&self, model: &str) -> Result<(Arc<ModelSpec>, ProviderFormat, Vec<String>)> {
+        match self.resolver.resolve(model) {
+            Err(Error::UnknownModel(_)) => self.unknown_model_fallback(model),
+            other => other,
+        }
+    }
+
+    /// Synthesize a routing decision for a model the catalog doesn't
+    /// recognize, per the configured [`UnknownModelPolicy`].
+    fn unknown_model_fallback(
+        &self,
+        model: &str,
+    ) -> Result<(Arc<ModelSpec>, ProviderFormat, Vec<String>)> {
+        let alias = match &self.unknown_model_policy {
+            UnknownModelPolicy::Error => return Err(Error::UnknownModel(model.to_string())),
+            UnknownModelPolicy::AssumeChatCompletions => self
+                .formats
+                .get(&ProviderFormat::ChatCompletions)
+                .cloned()
+                .ok_or(Error::NoProvider(ProviderFormat::ChatCompletions))?,
+            UnknownModelPolicy::UseProvider(alias) => alias.clone(),
+        };
+        let spec = Arc::new(ModelSpec {
+            model: model.to_string(),
+            provider_model_id: None,
+            format: ProviderFormat::ChatCompletions,
+            flavor: ModelFlavor::Chat,
+            display_name: None,
+            parent: None,
+            input_cost_per_mil_tokens: None,
+            output_cost_per_mil_tokens: None,
+            input_cache_read_cost_per_mil_tokens: None,
+            multimodal: None,
+            reasoning: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            supports_streaming: true,
+            extra: Default::default(),
+            available_providers: vec![alias.clone()],
+            endpoints: Vec::new(),
+        });
+        Ok((spec, ProviderFormat::ChatCompletions, vec![alias]))
+    }
+
+    /// Resolve how a model would be routed, without dispatching a request.
+    ///
+    /// Applies the same catalog lookup and provider-format fallback logic as
+    /// [`Router::create_request`]/[`Router::complete`], returning the route that
+    /// would be used. Returns `None` if the model is unknown or has no usable
+    /// provider registered.
+    pub fn resolve(&self, model: &str) -> Option<ResolvedRoute> {
+        let (_, catalog_format, _) = self.resolve_model(model).ok()?;
+        let route = self
+            .resolve_provider_routes(model, catalog_format, &[])
+            .ok()?
+            .into_iter()
+            .next()?;
+        Some(ResolvedRoute {
+            provider_id: route.provider_alias,
+            format: route.format,
+            spec: route.spec,
         })
     }
 
@@ -623,7 +1026,7 @@ impl Router {
             );
         }
 
-        let (spec, catalog_format, aliases) = self.resolver.resolve(model)?;
+        let (spec, catalog_format, aliases) = self.resolve_model(model)?;
         let routes: Vec<Result<ProviderRoute>> = aliases
             .iter()
             .map(|alias| {
@@ -937,6 +1340,25 @@ impl Router {
         })
     }
 
+    /// The request-id this call is correlated under: the caller-supplied
+    /// header value if present, otherwise a freshly generated one (see
+    /// [`RouterBuilder::with_request_id_header`]). Callers must consult this
+    /// for every call, including a [`ResponseCache`] hit, rather than
+    /// forwarding a request-id captured on some earlier call.
+    fn request_id_for(&self, client_headers: &ClientHeaders) -> String {
+        let request_id_header = self
+            .request_id_header
+            .as_deref()
+            .unwrap_or(DEFAULT_REQUEST_ID_HEADER);
+        client_headers
+            .get(request_id_header)
+            .map(str::to_string)
+            .unwrap_or_else(generate_request_id)
+    }
+
+    /// Dispatch `payload` to `provider`, retrying per `strategy`, and return
+    /// the response bytes together with the request-id this call was
+    /// correlated under (see [`RouterBuilder::with_request_id_header`]).
     #[allow(clippy::too_many_arguments)]
     async fn execute_with_retry(
         &self,
@@ -947,7 +1369,29 @@ impl Router {
         payload: Bytes,
         mut strategy: RetryStrategy,
         client_headers: &ClientHeaders,
-    ) -> Result<Bytes> {
+    ) -> Result<(Bytes, String)> {
+        let request_id_header = self
+            .request_id_header
+            .as_deref()
+            .unwrap_or(DEFAULT_REQUEST_ID_HEADER);
+        let request_id = self.request_id_for(client_headers);
+
+        let should_compress = self.request_compression
+            && provider.supports_request_compression()
+            && payload.len() >= compression::MIN_COMPRESSION_BYTES;
+
+        let mut owned_client_headers = client_headers.clone();
+        owned_client_headers.insert_if_allowed(request_id_header, &request_id);
+        if should_compress {
+            owned_client_headers.insert_if_allowed("content-encoding", "gzip");
+        }
+        let client_headers = &owned_client_headers;
+        let payload = if should_compress {
+            compression::gzip(&payload)?
+        } else {
+            payload
+        };
+
         #[cfg(feature = "tracing")]
         let mut attempt = 0u32;
 
@@ -962,6 +1406,7 @@ impl Router {
                 let span = tracing::info_span!(
                     "bt.router.provider.attempt",
                     llm.provider = %provider.id(),
+                    llm.request_id = %request_id,
                     attempt = attempt,
                     http.url = tracing::field::Empty,
                     http.status_code = tracing::field::Empty,
@@ -982,12 +1427,13 @@ impl Router {
                 .await;
 
             match result {
-                Ok(response) => return Ok(response),
+                Ok(response) => return Ok((response, request_id)),
                 Err(err) => {
                     if let Some(delay) = strategy.next_delay(&err) {
                         #[cfg(feature = "tracing")]
                         tracing::info!(
                             llm.provider = %provider.id(),
+                            llm.request_id = %request_id,
                             attempt = attempt,
                             delay_ms = delay.as_millis() as u64,
                             error = %err,
@@ -996,17 +1442,20 @@ impl Router {
                         sleep(delay).await;
                         continue;
                     } else {
-                        return Err(match err {
-                            Error::Http(source) => Error::UpstreamUnavailable {
-                                provider: provider.id().to_string(),
-                                source: source.into(),
-                            },
-                            Error::Middleware(source) => Error::UpstreamUnavailable {
-                                provider: provider.id().to_string(),
-                                source: source.into(),
+                        return Err(attach_request_id(
+                            match err {
+                                Error::Http(source) => Error::UpstreamUnavailable {
+                                    provider: provider.id().to_string(),
+                                    source: source.into(),
+                                },
+                                Error::Middleware(source) => Error::UpstreamUnavailable {
+                                    provider: provider.id().to_string(),
+                                    source: source.into(),
+                                },
+                                other => other,
                             },
-                            other => other,
-                        });
+                            &request_id,
+                        ));
                     }
                 }
             }
@@ -1014,6 +1463,26 @@ impl Router {
     }
 }
 
+/// Record `request_id` on an [`Error::Provider`]'s [`UpstreamHttpError`], if
+/// it has one, so a caller inspecting the returned error can still correlate
+/// it with the outgoing request and tracing spans.
+fn attach_request_id(err: Error, request_id: &str) -> Error {
+    match err {
+        Error::Provider {
+            provider,
+            source,
+            retry_after,
+            http: Some(http),
+        } => Error::Provider {
+            provider,
+            source,
+            retry_after,
+            http: Some(http.with_request_id(request_id)),
+        },
+        other => other,
+    }
+}
+
 fn replace_transformed_response_model(bytes: Bytes, model: &str) -> Result<Bytes> {
     if !bytes
         .windows(br#""model":"transformed""#.len())
@@ -1064,11 +1533,41 @@ struct ProviderEntry {
     default_for_formats: Vec<ProviderFormat>,
 }
 
+/// Governs how a [`Router`] handles a model name that isn't in the catalog.
+///
+/// Defaults to `Error`, matching the router's historical behavior: a model
+/// the catalog doesn't recognize fails the request outright. A gateway that
+/// would rather forward requests for models it hasn't caught up with yet
+/// (e.g. a provider ships a new model release before the catalog is updated)
+/// can opt into one of the other variants instead of hard-failing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum UnknownModelPolicy {
+    /// Fail with [`Error::UnknownModel`] (default).
+    #[default]
+    Error,
+    /// Treat the model as an OpenAI-compatible Chat Completions model,
+    /// routed to whichever provider is registered as the default for that
+    /// format (see [`RouterBuilder::add_provider`]'s `default_for_formats`).
+    AssumeChatCompletions,
+    /// Treat the model as an OpenAI-compatible Chat Completions model,
+    /// routed to a specific provider alias, bypassing the default-format
+    /// lookup `AssumeChatCompletions` uses.
+    UseProvider(String),
+}
+
 pub struct RouterBuilder {
     catalog: Option<Arc<ModelCatalog>>,
     custom_catalog: Option<ModelCatalog>,
     provider_entries: Vec<ProviderEntry>,
     retry_policy: RetryPolicy,
+    default_headers: HeaderMap,
+    concurrency_limit: usize,
+    unknown_model_policy: UnknownModelPolicy,
+    response_cache: Option<ResponseCacheConfig>,
+    strategy: Box<dyn RoutingStrategy>,
+    hooks: Vec<Box<dyn RouterHook>>,
+    request_compression: bool,
+    request_id_header: Option<String>,
 }
 
 impl Default for RouterBuilder {
@@ -1084,9 +1583,86 @@ impl RouterBuilder {
             custom_catalog: None,
             provider_entries: Vec::new(),
             retry_policy: RetryPolicy::default(),
+            default_headers: HeaderMap::new(),
+            concurrency_limit: DEFAULT_CONCURRENCY_LIMIT,
+            unknown_model_policy: UnknownModelPolicy::default(),
+            response_cache: None,
+            strategy: Box::new(CatalogRoutingStrategy),
+            hooks: Vec::new(),
+            request_compression: false,
+            request_id_header: None,
         }
     }
 
+    /// Install a custom [`RoutingStrategy`] for [`Router::route`], replacing
+    /// the default catalog-lookup behavior.
+    pub fn with_strategy(mut self, strategy: Box<dyn RoutingStrategy>) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Register a [`RouterHook`] to run against every [`Router::complete_universal`]
+    /// call, for policy enforcement like PII redaction or prompt-injection defense.
+    ///
+    /// Hooks run in registration order; a `before_request` hook that returns an
+    /// error short-circuits the request before any provider is contacted.
+    pub fn with_hook(mut self, hook: Box<dyn RouterHook>) -> Self {
+        self.hooks.push(hook);
+        self
+    }
+
+    /// Configure how the router handles a model name that isn't in the
+    /// catalog. Defaults to [`UnknownModelPolicy::Error`].
+    pub fn with_unknown_model_policy(mut self, policy: UnknownModelPolicy) -> Self {
+        self.unknown_model_policy = policy;
+        self
+    }
+
+    /// Cap concurrent provider calls for batch operations like [`Router::health_check_all`].
+    ///
+    /// Defaults to [`DEFAULT_CONCURRENCY_LIMIT`] when not set.
+    pub fn with_concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency_limit = limit;
+        self
+    }
+
+    /// Attach headers (e.g. `x-tenant-id`, a tracing header) to every upstream call across all
+    /// providers, regardless of which provider ends up serving the request.
+    ///
+    /// These sit at the bottom of the precedence stack: a per-request header (forwarded from
+    /// the client or set via `ClientHeaders::insert_user_configured`) overrides a default with
+    /// the same name, and provider-specific/auth headers always win over both.
+    pub fn with_default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Gzip-compress outgoing request bodies (`Content-Encoding: gzip`) for
+    /// providers that opt into it via [`Provider::supports_request_compression`].
+    ///
+    /// This is the router-wide half of the gate; a provider that doesn't
+    /// override `supports_request_compression` to `true` is never
+    /// compressed, regardless of this setting. Small bodies are sent
+    /// uncompressed even when both are enabled, since gzip's own overhead
+    /// outweighs the savings at that size. Defaults to `false`.
+    pub fn with_request_compression(mut self, enabled: bool) -> Self {
+        self.request_compression = enabled;
+        self
+    }
+
+    /// Correlate each provider call with a request-id carried in the header
+    /// named `name`, instead of the default [`DEFAULT_REQUEST_ID_HEADER`].
+    ///
+    /// A caller that already forwarded this header on `ClientHeaders` has its
+    /// value reused; otherwise the router generates a fresh UUID. Either way,
+    /// the id is attached to the tracing span for the provider attempt, sent
+    /// upstream on this header, and recorded on [`UpstreamHttpError`] if the
+    /// call fails.
+    pub fn with_request_id_header(mut self, name: impl Into<String>) -> Self {
+        self.request_id_header = Some(name.into());
+        self
+    }
+
     pub fn load_models(mut self, path: impl AsRef<std::path::Path>) -> Result<Self> {
         let catalog = load_catalog_from_disk(path)?;
         self.catalog = Some(catalog);
@@ -1115,6 +1691,39 @@ impl RouterBuilder {
         self
     }
 
+    /// Cache successful non-streaming completions in memory, keyed by the
+    /// target model plus a canonical hash of the outgoing request body, so
+    /// identical requests within `ttl` are served without hitting the
+    /// provider again. `capacity` bounds the cache to that many entries,
+    /// evicting least-recently-used entries once it's exceeded.
+    ///
+    /// By default, a request with `temperature > 0` and no `seed` is treated
+    /// as non-deterministic and is neither served from nor written to the
+    /// cache; use [`RouterBuilder::with_response_cache_nondeterministic`] to
+    /// cache those requests anyway.
+    ///
+    /// Only [`Router::complete`] and [`Router::complete_with_raw_response`]
+    /// consult this cache; streaming completions are never cached.
+    pub fn with_response_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.response_cache = Some(ResponseCacheConfig {
+            capacity,
+            ttl,
+            cache_nondeterministic: false,
+        });
+        self
+    }
+
+    /// Allow the response cache configured via
+    /// [`RouterBuilder::with_response_cache`] to serve and store responses
+    /// for requests that look non-deterministic (`temperature > 0` without a
+    /// `seed`). Has no effect if the response cache isn't enabled.
+    pub fn with_response_cache_nondeterministic(mut self, allow: bool) -> Self {
+        if let Some(config) = self.response_cache.as_mut() {
+            config.cache_nondeterministic = allow;
+        }
+        self
+    }
+
     pub fn add_provider<P>(
         mut self,
         alias: impl Into<String>,
@@ -1210,6 +1819,16 @@ impl RouterBuilder {
             formats,
             auth_configs,
             retry_policy: self.retry_policy,
+            default_headers: self.default_headers,
+            concurrency_limit: self.concurrency_limit,
+            unknown_model_policy: self.unknown_model_policy,
+            response_cache: self
+                .response_cache
+                .map(|config| Arc::new(ResponseCache::new(config))),
+            strategy: self.strategy,
+            hooks: self.hooks,
+            request_compression: self.request_compression,
+            request_id_header: self.request_id_header,
         })
     }
 }
@@ -1218,11 +1837,12 @@ impl RouterBuilder {
 mod tests {
     use super::*;
     use crate::catalog::{ModelCatalog, ModelFlavor, ModelSpec};
-    use crate::error::Error;
+    use crate::error::{Error, UpstreamHttpError};
     use crate::streaming::{RawResponseStream, StreamChunk};
     use async_trait::async_trait;
     use futures::{stream, StreamExt};
-    use reqwest::header::HeaderMap;
+    use lingua::universal::message::{AssistantContent, UserContent};
+    use reqwest::header::HeaderValue;
     use std::sync::Mutex;
 
     struct FakeProvider {
@@ -1318,18 +1938,19 @@ mod tests {
         }
     }
 
-    struct FakeOpenAICompatibleProvider {
-        alias: &'static str,
+    /// Fails with a retryable error on its first `failures` calls, then
+    /// succeeds. Used to drive [`RetryStrategy`] through real retry attempts
+    /// under a paused clock, without any real network I/O or wall-clock delay.
+    struct FlakyProvider {
+        failures: usize,
+        attempts: Arc<std::sync::atomic::AtomicUsize>,
+        response: Bytes,
     }
 
     #[async_trait]
-    impl Provider for FakeOpenAICompatibleProvider {
+    impl Provider for FlakyProvider {
         fn id(&self) -> &'static str {
-            "openai"
-        }
-
-        fn matches_provider_alias(&self, alias: &str) -> bool {
-            self.alias == alias
+            "flaky"
         }
 
         fn provider_formats(&self) -> Vec<ProviderFormat> {
@@ -1344,7 +1965,18 @@ mod tests {
             _format: ProviderFormat,
             _client_headers: &ClientHeaders,
         ) -> Result<Bytes> {
-            Ok(Bytes::from("{}"))
+            let attempt = self
+                .attempts
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if attempt < self.failures {
+                return Err(Error::Provider {
+                    provider: self.id().to_string(),
+                    source: anyhow::anyhow!("simulated upstream failure"),
+                    retry_after: Some(Duration::from_millis(50)),
+                    http: None,
+                });
+            }
+            Ok(self.response.clone())
         }
 
         async fn complete_stream(
@@ -1363,559 +1995,1812 @@ mod tests {
         }
     }
 
-    fn google_spec(model: &str) -> ModelSpec {
-        ModelSpec {
-            model: model.to_string(),
-            format: ProviderFormat::Google,
-            flavor: ModelFlavor::Chat,
-            display_name: None,
-            parent: None,
-            input_cost_per_mil_tokens: None,
-            output_cost_per_mil_tokens: None,
-            input_cache_read_cost_per_mil_tokens: None,
-            multimodal: None,
-            reasoning: None,
-            max_input_tokens: None,
-            max_output_tokens: None,
-            supports_streaming: true,
-            extra: Default::default(),
-            available_providers: Default::default(),
-        }
+    /// Always succeeds, and counts how many times `complete` was actually
+    /// invoked. Used to assert that [`Router`]'s response cache serves
+    /// repeated identical requests without calling the provider again.
+    struct CountingProvider {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+        response: Bytes,
     }
 
-    fn openai_spec(model: &str, flavor: ModelFlavor) -> ModelSpec {
-        ModelSpec {
-            model: model.to_string(),
-            format: ProviderFormat::ChatCompletions,
-            flavor,
-            display_name: None,
-            parent: None,
-            input_cost_per_mil_tokens: None,
-            output_cost_per_mil_tokens: None,
-            input_cache_read_cost_per_mil_tokens: None,
-            multimodal: None,
-            reasoning: None,
-            max_input_tokens: None,
-            max_output_tokens: None,
-            supports_streaming: true,
-            extra: Default::default(),
-            available_providers: Default::default(),
+    #[async_trait]
+    impl Provider for CountingProvider {
+        fn id(&self) -> &'static str {
+            "counting"
         }
-    }
 
-    fn openai_spec_with_available_providers(model: &str, flavor: ModelFlavor) -> ModelSpec {
-        let mut spec = openai_spec(model, flavor);
-        spec.available_providers = vec!["openai".into(), "azure".into(), "cerebras".into()];
-        spec
-    }
+        fn provider_formats(&self) -> Vec<ProviderFormat> {
+            vec![ProviderFormat::ChatCompletions]
+        }
 
-    fn router_with_static_provider(provider: StaticProvider) -> Router {
-        let model = "gpt-5-mini";
-        let mut catalog = ModelCatalog::empty();
-        catalog.insert(model.into(), openai_spec(model, ModelFlavor::Chat));
-        Router::builder()
-            .with_catalog(Arc::new(catalog))
-            .add_provider(
+        async fn complete(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<Bytes> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(self.response.clone())
+        }
+
+        async fn complete_stream(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<RawResponseStream> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self, _auth: &AuthConfig) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Always fails with a non-retryable [`Error::Provider`] carrying an
+    /// [`UpstreamHttpError`], so the terminal error path can be asserted on.
+    struct AlwaysFailingProvider;
+
+    #[async_trait]
+    impl Provider for AlwaysFailingProvider {
+        fn id(&self) -> &'static str {
+            "always-failing"
+        }
+
+        fn provider_formats(&self) -> Vec<ProviderFormat> {
+            vec![ProviderFormat::ChatCompletions]
+        }
+
+        async fn complete(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<Bytes> {
+            Err(Error::Provider {
+                provider: self.id().to_string(),
+                source: anyhow::anyhow!("simulated non-retryable failure"),
+                retry_after: None,
+                http: Some(UpstreamHttpError::new(
+                    400,
+                    HeaderMap::new(),
+                    "boom".to_string(),
+                )),
+            })
+        }
+
+        async fn complete_stream(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<RawResponseStream> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self, _auth: &AuthConfig) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    struct HeaderCapturingProvider {
+        captured: Arc<Mutex<Option<HeaderMap>>>,
+    }
+
+    #[async_trait]
+    impl Provider for HeaderCapturingProvider {
+        fn id(&self) -> &'static str {
+            "header-capturing"
+        }
+
+        fn provider_formats(&self) -> Vec<ProviderFormat> {
+            vec![ProviderFormat::ChatCompletions]
+        }
+
+        async fn complete(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            client_headers: &ClientHeaders,
+        ) -> Result<Bytes> {
+            *self.captured.lock().unwrap() = Some(self.build_headers(client_headers));
+            Ok(Bytes::from("{}"))
+        }
+
+        async fn complete_stream(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<RawResponseStream> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self, _auth: &AuthConfig) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Captures the exact bytes and headers it receives, and reports itself
+    /// as supporting request compression, for asserting the router actually
+    /// gzips the body and sets `Content-Encoding` when enabled.
+    struct CompressionCapturingProvider {
+        captured: Arc<Mutex<Option<(HeaderMap, Bytes)>>>,
+    }
+
+    #[async_trait]
+    impl Provider for CompressionCapturingProvider {
+        fn id(&self) -> &'static str {
+            "compression-capturing"
+        }
+
+        fn provider_formats(&self) -> Vec<ProviderFormat> {
+            vec![ProviderFormat::ChatCompletions]
+        }
+
+        fn supports_request_compression(&self) -> bool {
+            true
+        }
+
+        async fn complete(
+            &self,
+            payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            client_headers: &ClientHeaders,
+        ) -> Result<Bytes> {
+            *self.captured.lock().unwrap() = Some((self.build_headers(client_headers), payload));
+            Ok(Bytes::from("{}"))
+        }
+
+        async fn complete_stream(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<RawResponseStream> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self, _auth: &AuthConfig) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Records how many `health_check` calls are in flight at once, for asserting
+    /// that [`Router::health_check_all`] respects its concurrency limit.
+    struct ConcurrencyTrackingProvider {
+        current: Arc<std::sync::atomic::AtomicUsize>,
+        max_observed: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Provider for ConcurrencyTrackingProvider {
+        fn id(&self) -> &'static str {
+            "tracking"
+        }
+
+        fn provider_formats(&self) -> Vec<ProviderFormat> {
+            vec![ProviderFormat::ChatCompletions]
+        }
+
+        async fn complete(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<Bytes> {
+            unimplemented!()
+        }
+
+        async fn complete_stream(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<RawResponseStream> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self, _auth: &AuthConfig) -> Result<()> {
+            use std::sync::atomic::Ordering;
+
+            let in_flight = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_observed.fetch_max(in_flight, Ordering::SeqCst);
+            sleep(Duration::from_millis(20)).await;
+            self.current.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct FakeOpenAICompatibleProvider {
+        alias: &'static str,
+    }
+
+    #[async_trait]
+    impl Provider for FakeOpenAICompatibleProvider {
+        fn id(&self) -> &'static str {
+            "openai"
+        }
+
+        fn matches_provider_alias(&self, alias: &str) -> bool {
+            self.alias == alias
+        }
+
+        fn provider_formats(&self) -> Vec<ProviderFormat> {
+            vec![ProviderFormat::ChatCompletions]
+        }
+
+        async fn complete(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<Bytes> {
+            Ok(Bytes::from("{}"))
+        }
+
+        async fn complete_stream(
+            &self,
+            _payload: Bytes,
+            _auth: &AuthConfig,
+            _spec: &ModelSpec,
+            _format: ProviderFormat,
+            _client_headers: &ClientHeaders,
+        ) -> Result<RawResponseStream> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self, _auth: &AuthConfig) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn google_spec(model: &str) -> ModelSpec {
+        ModelSpec {
+            model: model.to_string(),
+            provider_model_id: None,
+            format: ProviderFormat::Google,
+            flavor: ModelFlavor::Chat,
+            display_name: None,
+            parent: None,
+            input_cost_per_mil_tokens: None,
+            output_cost_per_mil_tokens: None,
+            input_cache_read_cost_per_mil_tokens: None,
+            multimodal: None,
+            reasoning: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            supports_streaming: true,
+            extra: Default::default(),
+            available_providers: Default::default(),
+            endpoints: vec![],
+        }
+    }
+
+    fn openai_spec(model: &str, flavor: ModelFlavor) -> ModelSpec {
+        ModelSpec {
+            model: model.to_string(),
+            provider_model_id: None,
+            format: ProviderFormat::ChatCompletions,
+            flavor,
+            display_name: None,
+            parent: None,
+            input_cost_per_mil_tokens: None,
+            output_cost_per_mil_tokens: None,
+            input_cache_read_cost_per_mil_tokens: None,
+            multimodal: None,
+            reasoning: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            supports_streaming: true,
+            extra: Default::default(),
+            available_providers: Default::default(),
+            endpoints: vec![],
+        }
+    }
+
+    fn openai_spec_with_available_providers(model: &str, flavor: ModelFlavor) -> ModelSpec {
+        let mut spec = openai_spec(model, flavor);
+        spec.available_providers = vec!["openai".into(), "azure".into(), "cerebras".into()];
+        spec
+    }
+
+    /// Routing strategy that treats `hints.model` as a model family and
+    /// picks the cheapest child model by input cost, falling back to the
+    /// family model itself if it has no children.
+    struct CheapestChildStrategy;
+
+    impl RoutingStrategy for CheapestChildStrategy {
+        fn route(&self, hints: &RequestHints, catalog: &ModelCatalog) -> Option<ResolvedRoute> {
+            let family = hints.model.as_deref()?;
+            let cheapest = catalog
+                .child_models(family)
+                .filter_map(|name| catalog.get(name))
+                .min_by(|a, b| {
+                    a.input_cost_per_mil_tokens
+                        .partial_cmp(&b.input_cost_per_mil_tokens)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .or_else(|| catalog.get(family))?;
+            let provider_id = cheapest.available_providers.first()?.clone();
+            Some(ResolvedRoute {
+                provider_id,
+                format: cheapest.format,
+                spec: cheapest,
+            })
+        }
+    }
+
+    #[test]
+    fn test_custom_strategy_picks_cheapest_candidate() {
+        let mut catalog = ModelCatalog::empty();
+
+        let mut cheap = openai_spec_with_available_providers("gpt-5-nano", ModelFlavor::Chat);
+        cheap.parent = Some("gpt-5-family".into());
+        cheap.input_cost_per_mil_tokens = Some(0.1);
+        catalog.insert("gpt-5-nano".into(), cheap);
+
+        let mut pricey = openai_spec_with_available_providers("gpt-5-mega", ModelFlavor::Chat);
+        pricey.parent = Some("gpt-5-family".into());
+        pricey.input_cost_per_mil_tokens = Some(20.0);
+        catalog.insert("gpt-5-mega".into(), pricey);
+
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .with_strategy(Box::new(CheapestChildStrategy))
+            .build()
+            .expect("router builds");
+
+        let hints = RequestHints {
+            model: Some("gpt-5-family".to_string()),
+            ..Default::default()
+        };
+        let route = router.route(&hints).expect("cheapest candidate resolves");
+        assert_eq!(route.spec.model, "gpt-5-nano");
+    }
+
+    /// Routing strategy that sends `metadata.tier: "premium"` requests to a
+    /// dedicated model, falling back to `hints.model` for everyone else.
+    struct TieredMetadataStrategy {
+        premium_model: String,
+    }
+
+    impl RoutingStrategy for TieredMetadataStrategy {
+        fn route(&self, hints: &RequestHints, catalog: &ModelCatalog) -> Option<ResolvedRoute> {
+            let model = if hints.metadata_hint.as_deref() == Some("premium") {
+                self.premium_model.as_str()
+            } else {
+                hints.model.as_deref()?
+            };
+            let spec = catalog.get(model)?;
+            let provider_id = spec.available_providers.first()?.clone();
+            Some(ResolvedRoute {
+                provider_id,
+                format: spec.format,
+                spec,
+            })
+        }
+    }
+
+    #[test]
+    fn test_metadata_hint_routes_to_different_provider() {
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(
+            "gpt-5-mini".into(),
+            openai_spec_with_available_providers("gpt-5-mini", ModelFlavor::Chat),
+        );
+        let mut premium = openai_spec("gpt-5-mega", ModelFlavor::Chat);
+        premium.available_providers = vec!["azure".into()];
+        catalog.insert("gpt-5-mega".into(), premium);
+
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .with_strategy(Box::new(TieredMetadataStrategy {
+                premium_model: "gpt-5-mega".to_string(),
+            }))
+            .build()
+            .expect("router builds");
+
+        let body = br#"{"model": "gpt-5-mini", "messages": [], "metadata": {"tier": "premium"}}"#;
+        let hints = extract_request_hints_with_metadata_key(body, Some("tier")).unwrap();
+
+        let route = router.route(&hints).expect("premium tier resolves");
+        assert_eq!(route.spec.model, "gpt-5-mega");
+        assert_eq!(route.provider_id, "azure");
+
+        let default_hints = RequestHints {
+            model: Some("gpt-5-mini".to_string()),
+            ..Default::default()
+        };
+        let default_route = router.route(&default_hints).expect("default tier resolves");
+        assert_eq!(default_route.spec.model, "gpt-5-mini");
+        assert_eq!(default_route.provider_id, "openai");
+    }
+
+    #[test]
+    fn test_default_strategy_matches_catalog_resolve() {
+        let model = "gpt-5-mini";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(
+            model.into(),
+            openai_spec_with_available_providers(model, ModelFlavor::Chat),
+        );
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .build()
+            .expect("router builds");
+
+        let hints = RequestHints {
+            model: Some(model.to_string()),
+            ..Default::default()
+        };
+        let route = router.route(&hints).expect("default strategy resolves");
+        assert_eq!(route.spec.model, model);
+        assert_eq!(route.provider_id, "openai");
+    }
+
+    fn router_with_static_provider(provider: StaticProvider) -> Router {
+        let model = "gpt-5-mini";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), openai_spec(model, ModelFlavor::Chat));
+        Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "openai",
+                provider,
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds")
+    }
+
+    fn chat_request_body() -> Bytes {
+        Bytes::from_static(
+            br#"{"model":"gpt-5-mini","messages":[{"role":"user","content":"hello"}]}"#,
+        )
+    }
+
+    fn chat_response_body() -> Bytes {
+        Bytes::from_static(
+            br#"{"id":"chatcmpl-test","object":"chat.completion","created":0,"model":"gpt-5-mini","choices":[{"index":0,"message":{"role":"assistant","content":"hello"},"finish_reason":"stop"}]}"#,
+        )
+    }
+
+    fn chat_stream_chunk_body() -> Bytes {
+        Bytes::from_static(
+            br#"{"id":"chatcmpl-test","object":"chat.completion.chunk","created":0,"model":"gpt-5-mini","choices":[{"index":0,"delta":{"content":"hello"},"finish_reason":null}]}"#,
+        )
+    }
+
+    #[test]
+    fn replace_transformed_response_model_replaces_exact_placeholder() {
+        let response = Bytes::from_static(
+            br#"{"id":"msg_transformed","type":"message","model":"transformed","content":[]}"#,
+        );
+
+        let patched =
+            replace_transformed_response_model(response, "global.anthropic.claude-opus-4-8")
+                .expect("response model patches");
+        let parsed: Value = serde_json::from_slice(&patched).expect("valid response json");
+
+        assert_eq!(
+            parsed.get("model").and_then(Value::as_str),
+            Some("global.anthropic.claude-opus-4-8")
+        );
+    }
+
+    #[test]
+    fn replace_transformed_response_model_preserves_real_model() {
+        let response = Bytes::from_static(
+            br#"{"id":"msg_123","type":"message","model":"claude-sonnet-4-5","content":[]}"#,
+        );
+
+        let patched = replace_transformed_response_model(
+            response.clone(),
+            "global.anthropic.claude-opus-4-8",
+        )
+        .expect("response model patches");
+
+        assert_eq!(patched, response);
+    }
+
+    #[test]
+    fn replace_transformed_response_model_preserves_missing_model() {
+        let response = Bytes::from_static(br#"{"id":"msg_123","type":"message","content":[]}"#);
+
+        let patched = replace_transformed_response_model(
+            response.clone(),
+            "global.anthropic.claude-opus-4-8",
+        )
+        .expect("response model patches");
+
+        assert_eq!(patched, response);
+    }
+
+    fn resolved_aliases(
+        router: &Router,
+        model: &str,
+        output_format: ProviderFormat,
+    ) -> Result<Vec<String>> {
+        router
+            .resolve_provider_routes(model, output_format, &[])
+            .map(|routes| {
+                routes
+                    .into_iter()
+                    .map(|route| route.provider_alias)
+                    .collect()
+            })
+    }
+
+    fn explicit_route_aliases(
+        router: &Router,
+        model: &str,
+        output_format: ProviderFormat,
+        aliases: &[&str],
+    ) -> Result<Vec<String>> {
+        let aliases = aliases
+            .iter()
+            .map(|alias| alias.to_string())
+            .collect::<Vec<_>>();
+        router
+            .resolve_provider_routes(model, output_format, &aliases)
+            .map(|routes| {
+                routes
+                    .into_iter()
+                    .map(|route| route.provider_alias)
+                    .collect()
+            })
+    }
+
+    async fn create_test_request(
+        router: &Router,
+        body: Bytes,
+        model: &str,
+        output_format: ProviderFormat,
+    ) -> Result<(PreparedRequest, RouterMetadata)> {
+        let routes = router.resolve_provider_routes(model, output_format, &[])?;
+        let route = routes
+            .first()
+            .ok_or_else(|| Error::NoProvider(output_format))?;
+        router
+            .create_request(body, output_format, route, false)
+            .await
+    }
+
+    async fn create_test_stream_request(
+        router: &Router,
+        body: Bytes,
+        model: &str,
+        output_format: ProviderFormat,
+    ) -> Result<(PreparedStreamRequest, RouterMetadata)> {
+        let routes = router.resolve_provider_routes(model, output_format, &[])?;
+        let route = routes
+            .first()
+            .ok_or_else(|| Error::NoProvider(output_format))?;
+        router
+            .create_stream_request(body, output_format, route, false)
+            .await
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_enables_stream_for_google_to_chat_completions() {
+        let body = Bytes::from_static(
+            br#"{"model":"gpt-5-mini","contents":[{"role":"user","parts":[{"text":"hello"}]}]}"#,
+        );
+        let spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+
+        let (payload, _, _, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::ChatCompletions,
+            true,
+            RequestPreparationOptions::default(),
+        )
+        .await
+        .expect("request prepares");
+
+        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+        assert_eq!(parsed.get("stream"), Some(&Value::Bool(true)));
+        assert_eq!(parsed.get("stream_options"), None);
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_leaves_non_streaming_google_to_chat_completions_without_stream_flag(
+    ) {
+        let body = Bytes::from_static(
+            br#"{"model":"gpt-5-mini","contents":[{"role":"user","parts":[{"text":"hello"}]}]}"#,
+        );
+        let spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+
+        let (payload, _, _, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::ChatCompletions,
+            false,
+            RequestPreparationOptions::default(),
+        )
+        .await
+        .expect("request prepares");
+
+        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+        assert_eq!(parsed.get("stream"), None);
+        assert_eq!(parsed.get("stream_options"), None);
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_does_not_read_model_for_vertex_anthropic() {
+        let body = Bytes::from_static(
+            br#"{"model":"claude-sonnet-4-6","messages":[{"role":"user","content":"Ping"}]}"#,
+        );
+        let spec = ModelSpec {
+            model: "publishers/anthropic/models/claude-sonnet-4-6".to_string(),
+            provider_model_id: None,
+            format: ProviderFormat::Anthropic,
+            flavor: ModelFlavor::Chat,
+            display_name: None,
+            parent: None,
+            input_cost_per_mil_tokens: None,
+            output_cost_per_mil_tokens: None,
+            input_cache_read_cost_per_mil_tokens: None,
+            multimodal: None,
+            reasoning: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            supports_streaming: true,
+            extra: Default::default(),
+            available_providers: vec!["vertex".to_string()],
+            endpoints: vec![],
+        };
+
+        let (payload, _, actual_format, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::VertexAnthropic,
+            false,
+            RequestPreparationOptions::default(),
+        )
+        .await
+        .expect("request prepares");
+        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+
+        assert_eq!(actual_format, ProviderFormat::VertexAnthropic);
+        assert_eq!(parsed.get("model"), None);
+        assert!(parsed.get("anthropic_version").is_some());
+        assert!(parsed.get("messages").is_some());
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_does_not_rewrite_model_for_google_pass_through() {
+        let body = Bytes::from_static(
+            br#"{"model":"models/gemini-2.5-flash","contents":[{"role":"user","parts":[{"text":"Ping"}]}]}"#,
+        );
+        let spec = ModelSpec {
+            model: "models/gemini-2.5-pro".to_string(),
+            provider_model_id: None,
+            format: ProviderFormat::Google,
+            flavor: ModelFlavor::Chat,
+            display_name: None,
+            parent: None,
+            input_cost_per_mil_tokens: None,
+            output_cost_per_mil_tokens: None,
+            input_cache_read_cost_per_mil_tokens: None,
+            multimodal: None,
+            reasoning: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            supports_streaming: true,
+            extra: Default::default(),
+            available_providers: vec!["google".to_string()],
+            endpoints: vec![],
+        };
+
+        let (payload, _, actual_format, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::Google,
+            false,
+            RequestPreparationOptions::default(),
+        )
+        .await
+        .expect("request prepares");
+        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+
+        assert_eq!(actual_format, ProviderFormat::Google);
+        assert_eq!(
+            parsed.get("model").and_then(Value::as_str),
+            Some("models/gemini-2.5-flash")
+        );
+        assert!(parsed.get("contents").is_some());
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_rewrites_same_format_chat_model_without_losing_native_fields()
+    {
+        let body = Bytes::from_static(
+            br#"{"model":"gpt-4","messages":[{"role":"user","name":"example_user","content":"Ping"}]}"#,
+        );
+        let spec = openai_spec("gpt-4o", ModelFlavor::Chat);
+
+        let (payload, _, actual_format, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::ChatCompletions,
+            false,
+            RequestPreparationOptions::default(),
+        )
+        .await
+        .expect("request prepares");
+        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+
+        assert_eq!(actual_format, ProviderFormat::ChatCompletions);
+        assert_eq!(parsed.get("model").and_then(Value::as_str), Some("gpt-4o"));
+        assert_eq!(
+            parsed.pointer("/messages/0/name").and_then(Value::as_str),
+            Some("example_user")
+        );
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_substitutes_provider_model_id_in_outgoing_body() {
+        let body = Bytes::from_static(
+            br#"{"model":"claude-sonnet","max_tokens":1024,"messages":[{"role":"user","content":"Ping"}]}"#,
+        );
+        let spec = ModelSpec {
+            model: "claude-sonnet".to_string(),
+            provider_model_id: Some("claude-3-5-sonnet-20241022".to_string()),
+            format: ProviderFormat::Anthropic,
+            flavor: ModelFlavor::Chat,
+            display_name: None,
+            parent: None,
+            input_cost_per_mil_tokens: None,
+            output_cost_per_mil_tokens: None,
+            input_cache_read_cost_per_mil_tokens: None,
+            multimodal: None,
+            reasoning: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            supports_streaming: true,
+            extra: Default::default(),
+            available_providers: Default::default(),
+            endpoints: vec![],
+        };
+
+        let (payload, _, actual_format, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::Anthropic,
+            false,
+            RequestPreparationOptions::default(),
+        )
+        .await
+        .expect("request prepares");
+        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+
+        assert_eq!(actual_format, ProviderFormat::Anthropic);
+        assert_eq!(
+            parsed.get("model").and_then(Value::as_str),
+            Some("claude-3-5-sonnet-20241022")
+        );
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_can_preserve_same_format_body_model() {
+        let body = Bytes::from_static(
+            br#"{"model":"gpt-4","messages":[{"role":"user","name":"example_user","content":"Ping"}]}"#,
+        );
+        let spec = openai_spec("gpt-4o", ModelFlavor::Chat);
+
+        let (payload, _, actual_format, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::ChatCompletions,
+            false,
+            RequestPreparationOptions {
+                rewrite_body_model: false,
+            },
+        )
+        .await
+        .expect("request prepares");
+        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+
+        assert_eq!(actual_format, ProviderFormat::ChatCompletions);
+        assert_eq!(parsed.get("model").and_then(Value::as_str), Some("gpt-4"));
+        assert_eq!(
+            parsed.pointer("/messages/0/name").and_then(Value::as_str),
+            Some("example_user")
+        );
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_can_preserve_body_model_across_format_transform() {
+        let body = Bytes::from_static(
+            br#"{"model":"claude-3-5-haiku-20241022","max_tokens":128,"messages":[{"role":"user","content":"Ping"}]}"#,
+        );
+        let spec = openai_spec("gpt-4o", ModelFlavor::Chat);
+
+        let (payload, detected_format, actual_format, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::ChatCompletions,
+            false,
+            RequestPreparationOptions {
+                rewrite_body_model: false,
+            },
+        )
+        .await
+        .expect("request prepares");
+        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+
+        assert_eq!(detected_format, Some(ProviderFormat::Anthropic));
+        assert_eq!(actual_format, ProviderFormat::ChatCompletions);
+        assert_eq!(
+            parsed.get("model").and_then(Value::as_str),
+            Some("claude-3-5-haiku-20241022")
+        );
+    }
+
+    #[tokio::test]
+    async fn prepare_provider_request_upgrades_actual_format_to_responses_for_reasoning_plus_tools()
+    {
+        // A chat-completions request with reasoning_effort + tools should have its actual_format
+        // upgraded to Responses so the router sends it to the correct endpoint.
+        let body = Bytes::from(
+            serde_json::json!({
+                "model": "gpt-5.4-mini",
+                "messages": [{"role": "user", "content": "Tokyo weather?"}],
+                "reasoning_effort": "medium",
+                "tools": [{
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "description": "Get weather",
+                        "parameters": {
+                            "type": "object",
+                            "properties": {"location": {"type": "string"}},
+                            "required": ["location"]
+                        }
+                    }
+                }]
+            })
+            .to_string(),
+        );
+        let spec = openai_spec("gpt-5.4-mini", ModelFlavor::Chat);
+
+        let (_, _, actual_format, _, _) = prepare_provider_request(
+            body,
+            &spec,
+            ProviderFormat::ChatCompletions,
+            false,
+            RequestPreparationOptions::default(),
+        )
+        .await
+        .expect("request prepares");
+
+        assert_eq!(
+            actual_format,
+            ProviderFormat::Responses,
+            "actual_format must be Responses so the router uses the /v1/responses endpoint"
+        );
+    }
+
+    fn dummy_auth() -> AuthConfig {
+        AuthConfig::ApiKey {
+            key: "test".into(),
+            header: Some("authorization".into()),
+            prefix: Some("Bearer".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn complete_with_raw_response_returns_response_and_raw_response() {
+        let raw_response = chat_response_body();
+        let router = router_with_static_provider(StaticProvider {
+            response: raw_response.clone(),
+            stream_chunks: Vec::new(),
+        });
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            "gpt-5-mini",
+            ProviderFormat::ChatCompletions,
+        )
+        .await
+        .expect("request prepares");
+
+        let result = router
+            .complete_with_raw_response(prepared, &ClientHeaders::default())
+            .await
+            .expect("complete succeeds");
+
+        assert_eq!(result.response, raw_response);
+        assert_eq!(result.raw_response, raw_response);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn complete_retries_through_paused_clock_until_success() {
+        let attempts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let raw_response = chat_response_body();
+        let model = "gpt-5-mini";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), openai_spec(model, ModelFlavor::Chat));
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .with_retry_policy(RetryPolicy {
+                max_attempts: 5,
+                initial_delay: Duration::from_millis(50),
+                max_delay: Duration::from_secs(1),
+                exponential_base: 2.0,
+                jitter: false,
+            })
+            .add_provider(
                 "openai",
-                provider,
+                FlakyProvider {
+                    failures: 2,
+                    attempts: attempts.clone(),
+                    response: raw_response.clone(),
+                },
                 dummy_auth(),
                 vec![ProviderFormat::ChatCompletions],
             )
             .build()
-            .expect("router builds")
-    }
+            .expect("router builds");
 
-    fn chat_request_body() -> Bytes {
-        Bytes::from_static(
-            br#"{"model":"gpt-5-mini","messages":[{"role":"user","content":"hello"}]}"#,
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            model,
+            ProviderFormat::ChatCompletions,
         )
-    }
+        .await
+        .expect("request prepares");
 
-    fn chat_response_body() -> Bytes {
-        Bytes::from_static(
-            br#"{"id":"chatcmpl-test","object":"chat.completion","created":0,"model":"gpt-5-mini","choices":[{"index":0,"message":{"role":"assistant","content":"hello"},"finish_reason":"stop"}]}"#,
-        )
+        let result = router
+            .complete(prepared, &ClientHeaders::default())
+            .await
+            .expect("retries exhaust the simulated failures without real delay");
+
+        assert_eq!(result, raw_response);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
     }
 
-    fn chat_stream_chunk_body() -> Bytes {
-        Bytes::from_static(
-            br#"{"id":"chatcmpl-test","object":"chat.completion.chunk","created":0,"model":"gpt-5-mini","choices":[{"index":0,"delta":{"content":"hello"},"finish_reason":null}]}"#,
-        )
+    #[tokio::test]
+    async fn response_cache_serves_repeated_identical_requests_without_calling_provider_again() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let raw_response = chat_response_body();
+        let model = "gpt-5-mini";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), openai_spec(model, ModelFlavor::Chat));
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .with_response_cache(10, Duration::from_secs(60))
+            .add_provider(
+                "openai",
+                CountingProvider {
+                    calls: calls.clone(),
+                    response: raw_response.clone(),
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds");
+
+        for _ in 0..2 {
+            let (prepared, _) = create_test_request(
+                &router,
+                chat_request_body(),
+                model,
+                ProviderFormat::ChatCompletions,
+            )
+            .await
+            .expect("request prepares");
+
+            let result = router
+                .complete(prepared, &ClientHeaders::default())
+                .await
+                .expect("complete succeeds");
+            assert_eq!(result, raw_response);
+        }
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
     }
 
-    #[test]
-    fn replace_transformed_response_model_replaces_exact_placeholder() {
-        let response = Bytes::from_static(
-            br#"{"id":"msg_transformed","type":"message","model":"transformed","content":[]}"#,
-        );
+    #[tokio::test]
+    async fn response_cache_hit_still_gets_a_fresh_request_id() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let raw_response = chat_response_body();
+        let model = "gpt-5-mini";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), openai_spec(model, ModelFlavor::Chat));
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .with_response_cache(10, Duration::from_secs(60))
+            .add_provider(
+                "openai",
+                CountingProvider {
+                    calls: calls.clone(),
+                    response: raw_response.clone(),
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds");
 
-        let patched =
-            replace_transformed_response_model(response, "global.anthropic.claude-opus-4-8")
-                .expect("response model patches");
-        let parsed: Value = serde_json::from_slice(&patched).expect("valid response json");
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            model,
+            ProviderFormat::ChatCompletions,
+        )
+        .await
+        .expect("request prepares");
+        let first = router
+            .complete_with_raw_response(prepared, &ClientHeaders::default())
+            .await
+            .expect("complete succeeds");
 
-        assert_eq!(
-            parsed.get("model").and_then(Value::as_str),
-            Some("global.anthropic.claude-opus-4-8")
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            model,
+            ProviderFormat::ChatCompletions,
+        )
+        .await
+        .expect("request prepares");
+        let second = router
+            .complete_with_raw_response(prepared, &ClientHeaders::default())
+            .await
+            .expect("complete succeeds (served from cache)");
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_ne!(
+            first.request_id, second.request_id,
+            "a cache hit must not hand back a stale request-id"
         );
+        assert!(!second.request_id.is_empty());
     }
 
-    #[test]
-    fn replace_transformed_response_model_preserves_real_model() {
-        let response = Bytes::from_static(
-            br#"{"id":"msg_123","type":"message","model":"claude-sonnet-4-5","content":[]}"#,
-        );
+    #[tokio::test(start_paused = true)]
+    async fn response_cache_expires_entries_after_ttl() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let raw_response = chat_response_body();
+        let model = "gpt-5-mini";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), openai_spec(model, ModelFlavor::Chat));
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .with_response_cache(10, Duration::from_secs(30))
+            .add_provider(
+                "openai",
+                CountingProvider {
+                    calls: calls.clone(),
+                    response: raw_response.clone(),
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds");
 
-        let patched = replace_transformed_response_model(
-            response.clone(),
-            "global.anthropic.claude-opus-4-8",
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            model,
+            ProviderFormat::ChatCompletions,
         )
-        .expect("response model patches");
+        .await
+        .expect("request prepares");
+        router
+            .complete(prepared, &ClientHeaders::default())
+            .await
+            .expect("complete succeeds");
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            model,
+            ProviderFormat::ChatCompletions,
+        )
+        .await
+        .expect("request prepares");
+        router
+            .complete(prepared, &ClientHeaders::default())
+            .await
+            .expect("complete succeeds");
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn response_cache_skips_nondeterministic_requests_by_default() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let raw_response = chat_response_body();
+        let model = "gpt-5-mini";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), openai_spec(model, ModelFlavor::Chat));
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .with_response_cache(10, Duration::from_secs(60))
+            .add_provider(
+                "openai",
+                CountingProvider {
+                    calls: calls.clone(),
+                    response: raw_response.clone(),
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds");
+
+        let hot_request = Bytes::from_static(
+            br#"{"model":"gpt-5-mini","temperature":0.7,"messages":[{"role":"user","content":"hello"}]}"#,
+        );
+        for _ in 0..2 {
+            let (prepared, _) = create_test_request(
+                &router,
+                hot_request.clone(),
+                model,
+                ProviderFormat::ChatCompletions,
+            )
+            .await
+            .expect("request prepares");
+            router
+                .complete(prepared, &ClientHeaders::default())
+                .await
+                .expect("complete succeeds");
+        }
 
-        assert_eq!(patched, response);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
     }
 
-    #[test]
-    fn replace_transformed_response_model_preserves_missing_model() {
-        let response = Bytes::from_static(br#"{"id":"msg_123","type":"message","content":[]}"#);
+    #[tokio::test]
+    async fn complete_rejects_responses_format_request_for_chat_only_model() {
+        let model = "gpt-5-mini";
+        let mut spec = openai_spec(model, ModelFlavor::Chat);
+        spec.endpoints = vec![Endpoint::Chat];
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), spec);
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "openai",
+                StaticProvider {
+                    response: chat_response_body(),
+                    stream_chunks: Vec::new(),
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds");
 
-        let patched = replace_transformed_response_model(
-            response.clone(),
-            "global.anthropic.claude-opus-4-8",
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            model,
+            ProviderFormat::Responses,
         )
-        .expect("response model patches");
+        .await
+        .expect("request prepares even though the model can't serve it");
 
-        assert_eq!(patched, response);
-    }
+        let err = router
+            .complete(prepared, &ClientHeaders::default())
+            .await
+            .expect_err("responses-format request should be rejected for a chat-only model");
 
-    fn resolved_aliases(
-        router: &Router,
-        model: &str,
-        output_format: ProviderFormat,
-    ) -> Result<Vec<String>> {
-        router
-            .resolve_provider_routes(model, output_format, &[])
-            .map(|routes| {
-                routes
-                    .into_iter()
-                    .map(|route| route.provider_alias)
-                    .collect()
-            })
+        match err {
+            Error::UnsupportedEndpoint {
+                model: err_model,
+                endpoint,
+            } => {
+                assert_eq!(err_model, model);
+                assert_eq!(endpoint, Endpoint::Responses);
+            }
+            other => panic!("expected UnsupportedEndpoint, got {other:?}"),
+        }
     }
 
-    fn explicit_route_aliases(
-        router: &Router,
-        model: &str,
-        output_format: ProviderFormat,
-        aliases: &[&str],
-    ) -> Result<Vec<String>> {
-        let aliases = aliases
-            .iter()
-            .map(|alias| alias.to_string())
-            .collect::<Vec<_>>();
-        router
-            .resolve_provider_routes(model, output_format, &aliases)
-            .map(|routes| {
-                routes
-                    .into_iter()
-                    .map(|route| route.provider_alias)
-                    .collect()
-            })
-    }
+    #[tokio::test]
+    async fn complete_stream_rejects_responses_format_request_for_chat_only_model() {
+        let model = "gpt-5-mini";
+        let mut spec = openai_spec(model, ModelFlavor::Chat);
+        spec.endpoints = vec![Endpoint::Chat];
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), spec);
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "openai",
+                StaticProvider {
+                    response: chat_response_body(),
+                    stream_chunks: Vec::new(),
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds");
 
-    async fn create_test_request(
-        router: &Router,
-        body: Bytes,
-        model: &str,
-        output_format: ProviderFormat,
-    ) -> Result<(PreparedRequest, RouterMetadata)> {
-        let routes = router.resolve_provider_routes(model, output_format, &[])?;
-        let route = routes
-            .first()
-            .ok_or_else(|| Error::NoProvider(output_format))?;
-        router
-            .create_request(body, output_format, route, false)
-            .await
-    }
+        let (prepared, _) = create_test_stream_request(
+            &router,
+            chat_request_body(),
+            model,
+            ProviderFormat::Responses,
+        )
+        .await
+        .expect("request prepares even though the model can't serve it");
 
-    async fn create_test_stream_request(
-        router: &Router,
-        body: Bytes,
-        model: &str,
-        output_format: ProviderFormat,
-    ) -> Result<(PreparedStreamRequest, RouterMetadata)> {
-        let routes = router.resolve_provider_routes(model, output_format, &[])?;
-        let route = routes
-            .first()
-            .ok_or_else(|| Error::NoProvider(output_format))?;
-        router
-            .create_stream_request(body, output_format, route, false)
+        let err = router
+            .complete_stream(prepared, &ClientHeaders::default(), None)
             .await
+            .expect_err("responses-format stream request should be rejected for a chat-only model");
+
+        match err {
+            Error::UnsupportedEndpoint {
+                model: err_model,
+                endpoint,
+            } => {
+                assert_eq!(err_model, model);
+                assert_eq!(endpoint, Endpoint::Responses);
+            }
+            other => panic!("expected UnsupportedEndpoint, got {other:?}"),
+        }
     }
 
     #[tokio::test]
-    async fn prepare_provider_request_enables_stream_for_google_to_chat_completions() {
-        let body = Bytes::from_static(
-            br#"{"model":"gpt-5-mini","contents":[{"role":"user","parts":[{"text":"hello"}]}]}"#,
-        );
-        let spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+    async fn complete_universal_sends_universal_request_and_parses_universal_response() {
+        let router = router_with_static_provider(StaticProvider {
+            response: chat_response_body(),
+            stream_chunks: Vec::new(),
+        });
 
-        let (payload, _, _, _, _) = prepare_provider_request(
-            body,
-            &spec,
-            ProviderFormat::ChatCompletions,
-            true,
-            RequestPreparationOptions::default(),
-        )
-        .await
-        .expect("request prepares");
+        let req = UniversalRequest {
+            model: None,
+            messages: vec![Message::User {
+                content: UserContent::String("hello".into()),
+                name: None,
+            }],
+            params: Default::default(),
+        };
 
-        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
-        assert_eq!(parsed.get("stream"), Some(&Value::Bool(true)));
-        assert_eq!(parsed.get("stream_options"), None);
+        let response = router
+            .complete_universal(req, "gpt-5-mini", &ClientHeaders::default())
+            .await
+            .expect("complete_universal succeeds");
+
+        assert_eq!(response.id.as_deref(), Some("chatcmpl-test"));
+        match &response.messages[..] {
+            [Message::Assistant {
+                content: AssistantContent::String(text),
+                ..
+            }] => {
+                assert_eq!(text, "hello");
+            }
+            other => panic!("expected single assistant message, got {other:?}"),
+        }
     }
 
-    #[tokio::test]
-    async fn prepare_provider_request_leaves_non_streaming_google_to_chat_completions_without_stream_flag(
-    ) {
-        let body = Bytes::from_static(
-            br#"{"model":"gpt-5-mini","contents":[{"role":"user","parts":[{"text":"hello"}]}]}"#,
-        );
-        let spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+    struct BannedStringHook {
+        banned: &'static str,
+    }
 
-        let (payload, _, _, _, _) = prepare_provider_request(
-            body,
-            &spec,
-            ProviderFormat::ChatCompletions,
-            false,
-            RequestPreparationOptions::default(),
-        )
-        .await
-        .expect("request prepares");
+    impl RouterHook for BannedStringHook {
+        fn before_request(&self, req: &mut UniversalRequest) -> Result<()> {
+            for message in &req.messages {
+                if let Message::User {
+                    content: UserContent::String(text),
+                    ..
+                } = message
+                {
+                    if text.contains(self.banned) {
+                        return Err(Error::RejectedByHook(format!(
+                            "message contains banned string '{}'",
+                            self.banned
+                        )));
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
 
-        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
-        assert_eq!(parsed.get("stream"), None);
-        assert_eq!(parsed.get("stream_options"), None);
+    struct RedactingHook {
+        banned: &'static str,
+    }
+
+    impl RouterHook for RedactingHook {
+        fn before_request(&self, req: &mut UniversalRequest) -> Result<()> {
+            for message in &mut req.messages {
+                if let Message::User {
+                    content: UserContent::String(text),
+                    ..
+                } = message
+                {
+                    *text = text.replace(self.banned, "[REDACTED]");
+                }
+            }
+            Ok(())
+        }
     }
 
     #[tokio::test]
-    async fn prepare_provider_request_does_not_read_model_for_vertex_anthropic() {
-        let body = Bytes::from_static(
-            br#"{"model":"claude-sonnet-4-6","messages":[{"role":"user","content":"Ping"}]}"#,
-        );
-        let spec = ModelSpec {
-            model: "publishers/anthropic/models/claude-sonnet-4-6".to_string(),
-            format: ProviderFormat::Anthropic,
-            flavor: ModelFlavor::Chat,
-            display_name: None,
-            parent: None,
-            input_cost_per_mil_tokens: None,
-            output_cost_per_mil_tokens: None,
-            input_cache_read_cost_per_mil_tokens: None,
-            multimodal: None,
-            reasoning: None,
-            max_input_tokens: None,
-            max_output_tokens: None,
-            supports_streaming: true,
-            extra: Default::default(),
-            available_providers: vec!["vertex".to_string()],
+    async fn complete_universal_hook_rejects_banned_string() {
+        let router = Router::builder()
+            .with_catalog(Arc::new({
+                let mut catalog = ModelCatalog::empty();
+                catalog.insert(
+                    "gpt-5-mini".into(),
+                    openai_spec("gpt-5-mini", ModelFlavor::Chat),
+                );
+                catalog
+            }))
+            .add_provider(
+                "openai",
+                StaticProvider {
+                    response: chat_response_body(),
+                    stream_chunks: Vec::new(),
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .with_hook(Box::new(BannedStringHook {
+                banned: "ssn 123-45-6789",
+            }))
+            .build()
+            .expect("router builds");
+
+        let req = UniversalRequest {
+            model: None,
+            messages: vec![Message::User {
+                content: UserContent::String("my ssn 123-45-6789 is on file".into()),
+                name: None,
+            }],
+            params: Default::default(),
         };
 
-        let (payload, _, actual_format, _, _) = prepare_provider_request(
-            body,
-            &spec,
-            ProviderFormat::VertexAnthropic,
-            false,
-            RequestPreparationOptions::default(),
-        )
-        .await
-        .expect("request prepares");
-        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+        let err = router
+            .complete_universal(req, "gpt-5-mini", &ClientHeaders::default())
+            .await
+            .expect_err("hook rejects the request");
 
-        assert_eq!(actual_format, ProviderFormat::VertexAnthropic);
-        assert_eq!(parsed.get("model"), None);
-        assert!(parsed.get("anthropic_version").is_some());
-        assert!(parsed.get("messages").is_some());
+        assert!(matches!(err, Error::RejectedByHook(_)));
+        assert!(err.is_client_error());
     }
 
     #[tokio::test]
-    async fn prepare_provider_request_does_not_rewrite_model_for_google_pass_through() {
-        let body = Bytes::from_static(
-            br#"{"model":"models/gemini-2.5-flash","contents":[{"role":"user","parts":[{"text":"Ping"}]}]}"#,
-        );
-        let spec = ModelSpec {
-            model: "models/gemini-2.5-pro".to_string(),
-            format: ProviderFormat::Google,
-            flavor: ModelFlavor::Chat,
-            display_name: None,
-            parent: None,
-            input_cost_per_mil_tokens: None,
-            output_cost_per_mil_tokens: None,
-            input_cache_read_cost_per_mil_tokens: None,
-            multimodal: None,
-            reasoning: None,
-            max_input_tokens: None,
-            max_output_tokens: None,
-            supports_streaming: true,
-            extra: Default::default(),
-            available_providers: vec!["google".to_string()],
+    async fn complete_universal_hook_redacts_banned_string() {
+        let router = Router::builder()
+            .with_catalog(Arc::new({
+                let mut catalog = ModelCatalog::empty();
+                catalog.insert(
+                    "gpt-5-mini".into(),
+                    openai_spec("gpt-5-mini", ModelFlavor::Chat),
+                );
+                catalog
+            }))
+            .add_provider(
+                "openai",
+                StaticProvider {
+                    response: chat_response_body(),
+                    stream_chunks: Vec::new(),
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .with_hook(Box::new(RedactingHook {
+                banned: "123-45-6789",
+            }))
+            .build()
+            .expect("router builds");
+
+        let mut req = UniversalRequest {
+            model: None,
+            messages: vec![Message::User {
+                content: UserContent::String("my ssn 123-45-6789 is on file".into()),
+                name: None,
+            }],
+            params: Default::default(),
         };
 
-        let (payload, _, actual_format, _, _) = prepare_provider_request(
-            body,
-            &spec,
-            ProviderFormat::Google,
-            false,
-            RequestPreparationOptions::default(),
-        )
-        .await
-        .expect("request prepares");
-        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+        RedactingHook {
+            banned: "123-45-6789",
+        }
+        .before_request(&mut req)
+        .expect("redacting hook doesn't reject");
+        match &req.messages[0] {
+            Message::User {
+                content: UserContent::String(text),
+                ..
+            } => assert_eq!(text, "my ssn [REDACTED] is on file"),
+            other => panic!("expected user message, got {other:?}"),
+        }
 
-        assert_eq!(actual_format, ProviderFormat::Google);
-        assert_eq!(
-            parsed.get("model").and_then(Value::as_str),
-            Some("models/gemini-2.5-flash")
-        );
-        assert!(parsed.get("contents").is_some());
+        router
+            .complete_universal(req, "gpt-5-mini", &ClientHeaders::default())
+            .await
+            .expect("request succeeds after redaction");
     }
 
     #[tokio::test]
-    async fn prepare_provider_request_rewrites_same_format_chat_model_without_losing_native_fields()
-    {
-        let body = Bytes::from_static(
-            br#"{"model":"gpt-4","messages":[{"role":"user","name":"example_user","content":"Ping"}]}"#,
-        );
-        let spec = openai_spec("gpt-4o", ModelFlavor::Chat);
-
-        let (payload, _, actual_format, _, _) = prepare_provider_request(
-            body,
-            &spec,
+    async fn complete_with_raw_response_preserves_raw_response_on_transform_error() {
+        let raw_response = Bytes::from_static(b"not-json");
+        let router = router_with_static_provider(StaticProvider {
+            response: raw_response.clone(),
+            stream_chunks: Vec::new(),
+        });
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            "gpt-5-mini",
             ProviderFormat::ChatCompletions,
-            false,
-            RequestPreparationOptions::default(),
         )
         .await
         .expect("request prepares");
-        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
 
-        assert_eq!(actual_format, ProviderFormat::ChatCompletions);
-        assert_eq!(parsed.get("model").and_then(Value::as_str), Some("gpt-4o"));
-        assert_eq!(
-            parsed.pointer("/messages/0/name").and_then(Value::as_str),
-            Some("example_user")
-        );
+        let err = router
+            .complete_with_raw_response(prepared, &ClientHeaders::default())
+            .await
+            .expect_err("transform fails");
+
+        assert!(matches!(err, Error::ResponseTransform { .. }));
+        assert_eq!(err.raw_response(), Some(&raw_response));
     }
 
     #[tokio::test]
-    async fn prepare_provider_request_can_preserve_same_format_body_model() {
-        let body = Bytes::from_static(
-            br#"{"model":"gpt-4","messages":[{"role":"user","name":"example_user","content":"Ping"}]}"#,
-        );
-        let spec = openai_spec("gpt-4o", ModelFlavor::Chat);
-
-        let (payload, _, actual_format, _, _) = prepare_provider_request(
-            body,
-            &spec,
+    async fn complete_stream_raw_chunk_capture_runs_before_transform_error() {
+        let raw_chunk = Bytes::from_static(b"not-json");
+        let router = router_with_static_provider(StaticProvider {
+            response: Bytes::new(),
+            stream_chunks: vec![raw_chunk.clone()],
+        });
+        let (prepared, _) = create_test_stream_request(
+            &router,
+            chat_request_body(),
+            "gpt-5-mini",
             ProviderFormat::ChatCompletions,
-            false,
-            RequestPreparationOptions {
-                rewrite_body_model: false,
-            },
         )
         .await
-        .expect("request prepares");
-        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+        .expect("stream request prepares");
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let capture: RawStreamChunkCapture = Arc::new({
+            let captured = Arc::clone(&captured);
+            move |chunk: &StreamChunk| {
+                captured
+                    .lock()
+                    .expect("capture lock poisoned")
+                    .push(chunk.data.clone());
+            }
+        });
 
-        assert_eq!(actual_format, ProviderFormat::ChatCompletions);
-        assert_eq!(parsed.get("model").and_then(Value::as_str), Some("gpt-4"));
+        let mut stream = router
+            .complete_stream_with_raw_response_capture(
+                prepared,
+                &ClientHeaders::default(),
+                Some("request-id".to_string()),
+                capture,
+            )
+            .await
+            .expect("stream starts");
+        let first = stream.next().await.expect("stream item");
+
+        assert!(first.is_err());
         assert_eq!(
-            parsed.pointer("/messages/0/name").and_then(Value::as_str),
-            Some("example_user")
+            captured.lock().expect("capture lock poisoned").as_slice(),
+            &[raw_chunk]
         );
     }
 
     #[tokio::test]
-    async fn prepare_provider_request_can_preserve_body_model_across_format_transform() {
-        let body = Bytes::from_static(
-            br#"{"model":"claude-3-5-haiku-20241022","max_tokens":128,"messages":[{"role":"user","content":"Ping"}]}"#,
-        );
-        let spec = openai_spec("gpt-4o", ModelFlavor::Chat);
-
-        let (payload, detected_format, actual_format, _, _) = prepare_provider_request(
-            body,
-            &spec,
+    async fn complete_methods_work_without_raw_response_capture() {
+        let raw_response = chat_response_body();
+        let router = router_with_static_provider(StaticProvider {
+            response: raw_response.clone(),
+            stream_chunks: vec![chat_stream_chunk_body()],
+        });
+        let (prepared, _) = create_test_request(
+            &router,
+            chat_request_body(),
+            "gpt-5-mini",
             ProviderFormat::ChatCompletions,
-            false,
-            RequestPreparationOptions {
-                rewrite_body_model: false,
-            },
         )
         .await
         .expect("request prepares");
-        let parsed: Value = serde_json::from_slice(&payload).expect("valid request json");
+        let response = router
+            .complete(prepared, &ClientHeaders::default())
+            .await
+            .expect("complete succeeds");
+        assert_eq!(response, raw_response);
 
-        assert_eq!(detected_format, Some(ProviderFormat::Anthropic));
-        assert_eq!(actual_format, ProviderFormat::ChatCompletions);
-        assert_eq!(
-            parsed.get("model").and_then(Value::as_str),
-            Some("claude-3-5-haiku-20241022")
-        );
+        let (prepared_stream, _) = create_test_stream_request(
+            &router,
+            chat_request_body(),
+            "gpt-5-mini",
+            ProviderFormat::ChatCompletions,
+        )
+        .await
+        .expect("stream request prepares");
+        let mut response_stream = router
+            .complete_stream(
+                prepared_stream,
+                &ClientHeaders::default(),
+                Some("request-id".to_string()),
+            )
+            .await
+            .expect("stream starts");
+        let first = response_stream
+            .next()
+            .await
+            .expect("stream item")
+            .expect("stream item succeeds");
+        assert!(!first.data.is_empty());
     }
 
     #[tokio::test]
-    async fn prepare_provider_request_upgrades_actual_format_to_responses_for_reasoning_plus_tools()
-    {
-        // A chat-completions request with reasoning_effort + tools should have its actual_format
-        // upgraded to Responses so the router sends it to the correct endpoint.
-        let body = Bytes::from(
-            serde_json::json!({
-                "model": "gpt-5.4-mini",
-                "messages": [{"role": "user", "content": "Tokyo weather?"}],
-                "reasoning_effort": "medium",
-                "tools": [{
-                    "type": "function",
-                    "function": {
-                        "name": "get_weather",
-                        "description": "Get weather",
-                        "parameters": {
-                            "type": "object",
-                            "properties": {"location": {"type": "string"}},
-                            "required": ["location"]
-                        }
-                    }
-                }]
-            })
-            .to_string(),
-        );
-        let spec = openai_spec("gpt-5.4-mini", ModelFlavor::Chat);
+    async fn default_headers_reach_multiple_providers() {
+        let captured_a: Arc<Mutex<Option<HeaderMap>>> = Arc::new(Mutex::new(None));
+        let captured_b: Arc<Mutex<Option<HeaderMap>>> = Arc::new(Mutex::new(None));
+
+        let mut spec_a = openai_spec("model-a", ModelFlavor::Chat);
+        spec_a.available_providers = vec!["provider-a".into()];
+        let mut spec_b = openai_spec("model-b", ModelFlavor::Chat);
+        spec_b.available_providers = vec!["provider-b".into()];
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert("model-a".into(), spec_a);
+        catalog.insert("model-b".into(), spec_b);
 
-        let (_, _, actual_format, _, _) = prepare_provider_request(
-            body,
-            &spec,
-            ProviderFormat::ChatCompletions,
-            false,
-            RequestPreparationOptions::default(),
-        )
-        .await
-        .expect("request prepares");
+        let mut default_headers = HeaderMap::new();
+        default_headers.insert("x-tenant-id", HeaderValue::from_static("acme"));
 
-        assert_eq!(
-            actual_format,
-            ProviderFormat::Responses,
-            "actual_format must be Responses so the router uses the /v1/responses endpoint"
-        );
-    }
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "provider-a",
+                HeaderCapturingProvider {
+                    captured: captured_a.clone(),
+                },
+                dummy_auth(),
+                vec![],
+            )
+            .add_provider(
+                "provider-b",
+                HeaderCapturingProvider {
+                    captured: captured_b.clone(),
+                },
+                dummy_auth(),
+                vec![],
+            )
+            .with_default_headers(default_headers)
+            .build()
+            .expect("router builds");
 
-    fn dummy_auth() -> AuthConfig {
-        AuthConfig::ApiKey {
-            key: "test".into(),
-            header: Some("authorization".into()),
-            prefix: Some("Bearer".into()),
+        for (model, captured) in [("model-a", &captured_a), ("model-b", &captured_b)] {
+            let (prepared, _) = create_test_request(
+                &router,
+                chat_request_body(),
+                model,
+                ProviderFormat::ChatCompletions,
+            )
+            .await
+            .expect("request prepares");
+            router
+                .complete(prepared, &ClientHeaders::default())
+                .await
+                .expect("complete succeeds");
+            let headers = captured
+                .lock()
+                .unwrap()
+                .clone()
+                .expect("provider captured headers");
+            assert_eq!(headers.get("x-tenant-id").unwrap(), "acme");
         }
     }
 
-    #[tokio::test]
-    async fn complete_with_raw_response_returns_response_and_raw_response() {
-        let raw_response = chat_response_body();
-        let router = router_with_static_provider(StaticProvider {
-            response: raw_response.clone(),
-            stream_chunks: Vec::new(),
-        });
+    #[tokio::test]
+    async fn request_compression_gzips_body_and_sets_header_for_capable_providers() {
+        let captured: Arc<Mutex<Option<(HeaderMap, Bytes)>>> = Arc::new(Mutex::new(None));
+
+        let mut spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+        spec.available_providers = vec!["compression-capable".into()];
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert("gpt-5-mini".into(), spec);
+
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "compression-capable",
+                CompressionCapturingProvider {
+                    captured: captured.clone(),
+                },
+                dummy_auth(),
+                vec![],
+            )
+            .with_request_compression(true)
+            .build()
+            .expect("router builds");
+
+        // Pad the body well past MIN_COMPRESSION_BYTES so compression kicks in.
+        let long_content = "a".repeat(4096);
+        let original_body = Bytes::from(format!(
+            r#"{{"model":"gpt-5-mini","messages":[{{"role":"user","content":"{long_content}"}}]}}"#
+        ));
+
         let (prepared, _) = create_test_request(
             &router,
-            chat_request_body(),
+            original_body.clone(),
             "gpt-5-mini",
             ProviderFormat::ChatCompletions,
         )
         .await
         .expect("request prepares");
-
-        let result = router
-            .complete_with_raw_response(prepared, &ClientHeaders::default())
+        router
+            .complete(prepared, &ClientHeaders::default())
             .await
             .expect("complete succeeds");
 
-        assert_eq!(result.response, raw_response);
-        assert_eq!(result.raw_response, raw_response);
+        let (headers, sent_body) = captured
+            .lock()
+            .unwrap()
+            .clone()
+            .expect("provider captured the request");
+        assert_eq!(headers.get("content-encoding").unwrap(), "gzip");
+        assert!(sent_body.len() < original_body.len());
+
+        let mut decoder = flate2::read::GzDecoder::new(&sent_body[..]);
+        let mut decompressed = Vec::new();
+        std::io::Read::read_to_end(&mut decoder, &mut decompressed).unwrap();
+        assert_eq!(decompressed, original_body.to_vec());
     }
 
     #[tokio::test]
-    async fn complete_with_raw_response_preserves_raw_response_on_transform_error() {
-        let raw_response = Bytes::from_static(b"not-json");
-        let router = router_with_static_provider(StaticProvider {
-            response: raw_response.clone(),
-            stream_chunks: Vec::new(),
-        });
+    async fn request_compression_disabled_by_default_leaves_body_uncompressed() {
+        let captured: Arc<Mutex<Option<(HeaderMap, Bytes)>>> = Arc::new(Mutex::new(None));
+
+        let mut spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+        spec.available_providers = vec!["compression-capable".into()];
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert("gpt-5-mini".into(), spec);
+
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "compression-capable",
+                CompressionCapturingProvider {
+                    captured: captured.clone(),
+                },
+                dummy_auth(),
+                vec![],
+            )
+            .build()
+            .expect("router builds");
+
+        let long_content = "a".repeat(4096);
+        let original_body = Bytes::from(format!(
+            r#"{{"model":"gpt-5-mini","messages":[{{"role":"user","content":"{long_content}"}}]}}"#
+        ));
+
         let (prepared, _) = create_test_request(
             &router,
-            chat_request_body(),
+            original_body.clone(),
             "gpt-5-mini",
             ProviderFormat::ChatCompletions,
         )
         .await
         .expect("request prepares");
-
-        let err = router
-            .complete_with_raw_response(prepared, &ClientHeaders::default())
+        router
+            .complete(prepared, &ClientHeaders::default())
             .await
-            .expect_err("transform fails");
+            .expect("complete succeeds");
 
-        assert!(matches!(err, Error::ResponseTransform { .. }));
-        assert_eq!(err.raw_response(), Some(&raw_response));
+        let (headers, sent_body) = captured
+            .lock()
+            .unwrap()
+            .clone()
+            .expect("provider captured the request");
+        assert!(headers.get("content-encoding").is_none());
+        assert_eq!(sent_body, original_body);
     }
 
     #[tokio::test]
-    async fn complete_stream_raw_chunk_capture_runs_before_transform_error() {
-        let raw_chunk = Bytes::from_static(b"not-json");
-        let router = router_with_static_provider(StaticProvider {
-            response: Bytes::new(),
-            stream_chunks: vec![raw_chunk.clone()],
-        });
-        let (prepared, _) = create_test_stream_request(
+    async fn request_id_is_generated_sent_upstream_and_returned_to_caller() {
+        let captured: Arc<Mutex<Option<HeaderMap>>> = Arc::new(Mutex::new(None));
+
+        let mut spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+        spec.available_providers = vec!["header-capturing".into()];
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert("gpt-5-mini".into(), spec);
+
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "header-capturing",
+                HeaderCapturingProvider {
+                    captured: captured.clone(),
+                },
+                dummy_auth(),
+                vec![],
+            )
+            .build()
+            .expect("router builds");
+
+        let (prepared, _) = create_test_request(
             &router,
             chat_request_body(),
             "gpt-5-mini",
             ProviderFormat::ChatCompletions,
         )
         .await
-        .expect("stream request prepares");
-        let captured = Arc::new(Mutex::new(Vec::new()));
-        let capture: RawStreamChunkCapture = Arc::new({
-            let captured = Arc::clone(&captured);
-            move |chunk: &StreamChunk| {
-                captured
-                    .lock()
-                    .expect("capture lock poisoned")
-                    .push(chunk.data.clone());
-            }
-        });
-
-        let mut stream = router
-            .complete_stream_with_raw_response_capture(
-                prepared,
-                &ClientHeaders::default(),
-                Some("request-id".to_string()),
-                capture,
-            )
+        .expect("request prepares");
+        let result = router
+            .complete_with_raw_response(prepared, &ClientHeaders::default())
             .await
-            .expect("stream starts");
-        let first = stream.next().await.expect("stream item");
+            .expect("complete succeeds");
 
-        assert!(first.is_err());
-        assert_eq!(
-            captured.lock().expect("capture lock poisoned").as_slice(),
-            &[raw_chunk]
-        );
+        let headers = captured
+            .lock()
+            .unwrap()
+            .clone()
+            .expect("provider captured headers");
+        let sent_request_id = headers
+            .get(DEFAULT_REQUEST_ID_HEADER)
+            .expect("request-id header sent upstream")
+            .to_str()
+            .expect("request-id header is valid utf-8");
+        assert_eq!(sent_request_id, result.request_id);
+        assert!(!result.request_id.is_empty());
     }
 
     #[tokio::test]
-    async fn complete_methods_work_without_raw_response_capture() {
-        let raw_response = chat_response_body();
-        let router = router_with_static_provider(StaticProvider {
-            response: raw_response.clone(),
-            stream_chunks: vec![chat_stream_chunk_body()],
-        });
+    async fn client_supplied_request_id_header_is_reused_instead_of_generated() {
+        let captured: Arc<Mutex<Option<HeaderMap>>> = Arc::new(Mutex::new(None));
+
+        let mut spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+        spec.available_providers = vec!["header-capturing".into()];
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert("gpt-5-mini".into(), spec);
+
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "header-capturing",
+                HeaderCapturingProvider {
+                    captured: captured.clone(),
+                },
+                dummy_auth(),
+                vec![],
+            )
+            .build()
+            .expect("router builds");
+
+        let mut client_headers = ClientHeaders::default();
+        client_headers.insert_if_allowed(DEFAULT_REQUEST_ID_HEADER, "caller-supplied-id");
+
         let (prepared, _) = create_test_request(
             &router,
             chat_request_body(),
@@ -1924,34 +3809,85 @@ mod tests {
         )
         .await
         .expect("request prepares");
-        let response = router
-            .complete(prepared, &ClientHeaders::default())
+        let result = router
+            .complete_with_raw_response(prepared, &client_headers)
             .await
             .expect("complete succeeds");
-        assert_eq!(response, raw_response);
 
-        let (prepared_stream, _) = create_test_stream_request(
+        assert_eq!(result.request_id, "caller-supplied-id");
+        let headers = captured
+            .lock()
+            .unwrap()
+            .clone()
+            .expect("provider captured headers");
+        assert_eq!(
+            headers.get(DEFAULT_REQUEST_ID_HEADER).unwrap(),
+            "caller-supplied-id"
+        );
+    }
+
+    #[tokio::test]
+    async fn request_id_is_attached_to_upstream_http_error_on_failure() {
+        let mut spec = openai_spec("gpt-5-mini", ModelFlavor::Chat);
+        spec.available_providers = vec!["always-failing".into()];
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert("gpt-5-mini".into(), spec);
+
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "always-failing",
+                AlwaysFailingProvider,
+                dummy_auth(),
+                vec![],
+            )
+            .with_request_id_header("x-my-request-id")
+            .build()
+            .expect("router builds");
+
+        let mut client_headers = ClientHeaders::default();
+        client_headers.insert_if_allowed("x-my-request-id", "known-request-id");
+
+        let (prepared, _) = create_test_request(
             &router,
             chat_request_body(),
             "gpt-5-mini",
             ProviderFormat::ChatCompletions,
         )
         .await
-        .expect("stream request prepares");
-        let mut response_stream = router
-            .complete_stream(
-                prepared_stream,
-                &ClientHeaders::default(),
-                Some("request-id".to_string()),
-            )
-            .await
-            .expect("stream starts");
-        let first = response_stream
-            .next()
+        .expect("request prepares");
+        let err = router
+            .complete(prepared, &client_headers)
             .await
-            .expect("stream item")
-            .expect("stream item succeeds");
-        assert!(!first.data.is_empty());
+            .expect_err("provider fails");
+
+        match err {
+            Error::Provider {
+                http: Some(http), ..
+            } => {
+                assert_eq!(http.request_id.as_deref(), Some("known-request-id"));
+            }
+            other => panic!("expected Error::Provider with http details, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn models_lists_catalog_entries_as_openai_compatible_json() {
+        let router = router_with_static_provider(StaticProvider {
+            response: chat_response_body(),
+            stream_chunks: vec![],
+        });
+
+        let models = router.models();
+        assert!(!models.data.is_empty());
+
+        let value = serde_json::to_value(&models).expect("models list serializes");
+        assert_eq!(value["object"], serde_json::json!("list"));
+        let entry = &value["data"][0];
+        assert_eq!(entry["id"], serde_json::json!("gpt-5-mini"));
+        assert_eq!(entry["object"], serde_json::json!("model"));
+        assert_eq!(entry["created"], serde_json::json!(0));
+        assert_eq!(entry["owned_by"], serde_json::json!("openai"));
     }
 
     fn google_chat_router(model: &str) -> Router {
@@ -2664,6 +4600,7 @@ mod tests {
         let bedrock_spec =
             |model: &str, format: ProviderFormat, providers: Vec<String>| ModelSpec {
                 model: model.to_string(),
+                provider_model_id: None,
                 format,
                 flavor: ModelFlavor::Chat,
                 display_name: None,
@@ -2678,6 +4615,7 @@ mod tests {
                 supports_streaming: true,
                 extra: Default::default(),
                 available_providers: providers,
+                endpoints: vec![],
             };
         let model = "us.anthropic.claude-sonnet-4-6";
         let mut catalog = ModelCatalog::empty();
@@ -2715,6 +4653,7 @@ mod tests {
     fn bedrock_converse_catalog_format_keeps_converse_transport_for_chat_output() {
         let bedrock_spec = |model: &str, format: ProviderFormat| ModelSpec {
             model: model.to_string(),
+            provider_model_id: None,
             format,
             flavor: ModelFlavor::Chat,
             display_name: None,
@@ -2729,6 +4668,7 @@ mod tests {
             supports_streaming: true,
             extra: Default::default(),
             available_providers: Default::default(),
+            endpoints: vec![],
         };
         let model = "amazon.nova-lite-v1:0";
         let mut catalog = ModelCatalog::empty();
@@ -3103,6 +5043,143 @@ mod tests {
         assert_eq!(aliases, vec!["openai".to_string(), "azure".to_string()]);
     }
 
+    #[test]
+    fn resolve_returns_provider_and_format_for_versioned_model_without_dispatching() {
+        let model = "gpt-4o-2024-08-06";
+        let mut catalog = ModelCatalog::empty();
+        catalog.insert(model.into(), openai_spec(model, ModelFlavor::Chat));
+        let router = Router::builder()
+            .with_catalog(Arc::new(catalog))
+            .add_provider(
+                "openai",
+                FakeProvider {
+                    name: "openai",
+                    formats: vec![ProviderFormat::ChatCompletions],
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds");
+
+        let route = router.resolve(model).expect("model resolves");
+        assert_eq!(route.provider_id, "openai");
+        assert_eq!(route.format, ProviderFormat::ChatCompletions);
+        assert_eq!(route.spec.model, model);
+    }
+
+    #[test]
+    fn resolve_returns_none_for_unknown_model() {
+        let router = router_with_static_provider(StaticProvider {
+            response: chat_response_body(),
+            stream_chunks: Vec::new(),
+        });
+
+        assert!(router.resolve("does-not-exist").is_none());
+    }
+
+    #[test]
+    fn resolve_provider_routes_errors_for_unknown_model_by_default() {
+        let router = router_with_static_provider(StaticProvider {
+            response: chat_response_body(),
+            stream_chunks: Vec::new(),
+        });
+
+        let err = router
+            .resolve_provider_routes("does-not-exist", ProviderFormat::ChatCompletions, &[])
+            .expect_err("unknown model should fail with the default policy");
+        assert!(matches!(err, Error::UnknownModel(model) if model == "does-not-exist"));
+    }
+
+    #[test]
+    fn unknown_model_policy_assume_chat_completions_routes_to_default_provider() {
+        let router = Router::builder()
+            .with_catalog(Arc::new(ModelCatalog::empty()))
+            .with_unknown_model_policy(UnknownModelPolicy::AssumeChatCompletions)
+            .add_provider(
+                "openai",
+                FakeProvider {
+                    name: "openai",
+                    formats: vec![ProviderFormat::ChatCompletions],
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .build()
+            .expect("router builds");
+
+        let route = router
+            .resolve("some-brand-new-model")
+            .expect("unknown model should route via the fallback policy");
+        assert_eq!(route.provider_id, "openai");
+        assert_eq!(route.format, ProviderFormat::ChatCompletions);
+        assert_eq!(route.spec.model, "some-brand-new-model");
+    }
+
+    #[test]
+    fn unknown_model_policy_use_provider_routes_to_named_alias() {
+        let router = Router::builder()
+            .with_catalog(Arc::new(ModelCatalog::empty()))
+            .with_unknown_model_policy(UnknownModelPolicy::UseProvider("groq".to_string()))
+            .add_provider(
+                "openai",
+                FakeProvider {
+                    name: "openai",
+                    formats: vec![ProviderFormat::ChatCompletions],
+                },
+                dummy_auth(),
+                vec![ProviderFormat::ChatCompletions],
+            )
+            .add_provider(
+                "groq",
+                FakeProvider {
+                    name: "groq",
+                    formats: vec![ProviderFormat::ChatCompletions],
+                },
+                dummy_auth(),
+                vec![],
+            )
+            .build()
+            .expect("router builds");
+
+        let route = router
+            .resolve("some-brand-new-model")
+            .expect("unknown model should route via the fallback policy");
+        assert_eq!(route.provider_id, "groq");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn health_check_all_respects_configured_concurrency_limit() {
+        let current = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let max_observed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let mut builder = Router::builder()
+            .with_catalog(Arc::new(ModelCatalog::empty()))
+            .with_concurrency_limit(2);
+        for i in 0..5 {
+            builder = builder.add_provider(
+                format!("provider-{i}"),
+                ConcurrencyTrackingProvider {
+                    current: Arc::clone(&current),
+                    max_observed: Arc::clone(&max_observed),
+                },
+                dummy_auth(),
+                vec![],
+            );
+        }
+        let router = builder.build().expect("router builds");
+
+        let results = router.health_check_all().await;
+
+        assert_eq!(results.len(), 5);
+        assert!(results.values().all(|r| r.is_ok()));
+        assert_eq!(
+            max_observed.load(std::sync::atomic::Ordering::SeqCst),
+            2,
+            "at most 2 health checks should have been in flight at once"
+        );
+    }
+
     #[test]
     fn fallback_provider_routes_append_after_primary() {
         let model = "gpt-4o";
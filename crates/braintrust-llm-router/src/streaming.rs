@@ -1,6 +1,7 @@
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "provider-bedrock")]
 use base64::Engine as _;
@@ -79,10 +80,61 @@ pub type RawResponseStream = Pin<Box<dyn Stream<Item = Result<StreamChunk>> + Se
 
 pub type RawStreamChunkCapture = Arc<dyn Fn(&StreamChunk) + Send + Sync>;
 
+/// Called once per stream, with the time elapsed between request dispatch
+/// and the first content-bearing chunk (i.e. time-to-first-token).
+///
+/// Never invoked for streams that produce no content-bearing chunk (e.g. an
+/// error before any output, or a stream of only role/metadata events). A
+/// tool-call delta counts as content, so a tool-call-only response fires it.
+pub type FirstTokenCallback = Arc<dyn Fn(Duration) + Send + Sync>;
+
+/// Whether a transformed output chunk carries model-generated content, as
+/// opposed to a role-initialization, ping, or other metadata-only event.
+///
+/// Used to time first-token latency: the clock starts at request dispatch,
+/// and stops at the first chunk this returns `true` for.
+fn stream_chunk_is_content_bearing(chunk: &StreamChunk) -> bool {
+    // Anthropic-shaped output sets `event_type` to the SSE event name;
+    // `content_block_delta` covers text, tool-input, and thinking deltas.
+    if let Some(event_type) = chunk.event_type.as_deref() {
+        return event_type == "content_block_delta";
+    }
+
+    // OpenAI-shaped output (Chat Completions / Responses) has no SSE event
+    // name of its own; inspect the delta for actual generated content.
+    let Ok(value) = lingua::serde_json::from_slice::<lingua::serde_json::Value>(&chunk.data) else {
+        return false;
+    };
+
+    value
+        .get("choices")
+        .and_then(|choices| choices.as_array())
+        .into_iter()
+        .flatten()
+        .any(|choice| {
+            let Some(delta) = choice.get("delta") else {
+                return false;
+            };
+            let has_content = delta
+                .get("content")
+                .is_some_and(|c| !c.is_null() && c != "");
+            let has_tool_calls = delta
+                .get("tool_calls")
+                .and_then(|t| t.as_array())
+                .is_some_and(|t| !t.is_empty());
+            has_content || has_tool_calls
+        })
+}
+
 /// Create a raw SSE stream that yields JSON bytes without transformation.
 ///
 /// Parses Server-Sent Events from the HTTP response and yields raw JSON bytes.
 /// Use `transform_stream()` to convert to the desired output format.
+///
+/// Owns `response`'s body stream directly, with no buffering or detached
+/// task in between - dropping the returned stream (e.g. because a client
+/// disconnected) drops the underlying `reqwest` response and aborts its
+/// connection instead of draining it to completion.
 pub fn sse_stream(response: Response) -> RawResponseStream {
     Box::pin(RawSseStream::new(response.bytes_stream()))
 }
@@ -110,6 +162,31 @@ pub fn transform_stream(
 }
 
 pub fn transform_stream_with_capture(
+    raw: RawResponseStream,
+    output_format: ProviderFormat,
+    allow_full_response_fallback: bool,
+    gateway_request_id: Option<String>,
+    raw_chunk_capture: Option<RawStreamChunkCapture>,
+) -> ResponseStream {
+    transform_stream_with_instrumentation(
+        raw,
+        output_format,
+        allow_full_response_fallback,
+        gateway_request_id,
+        raw_chunk_capture,
+        None,
+    )
+}
+
+/// Transform a raw stream, optionally capturing raw chunks and/or timing
+/// time-to-first-token.
+///
+/// `first_token_callback`, if given, is a `(dispatch_time, callback)` pair:
+/// `callback` is invoked once with the elapsed time between `dispatch_time`
+/// (normally just before the provider request was sent) and the first
+/// content-bearing output chunk (see [`stream_chunk_is_content_bearing`]) -
+/// role-initialization and other metadata-only chunks don't count.
+pub fn transform_stream_with_instrumentation(
     raw: RawResponseStream,
     output_format: ProviderFormat,
     allow_full_response_fallback: bool,
@@ -117,7 +194,12 @@ pub fn transform_stream_with_capture(
         String,
     >,
     raw_chunk_capture: Option<RawStreamChunkCapture>,
+    first_token_callback: Option<(Instant, FirstTokenCallback)>,
 ) -> ResponseStream {
+    let (dispatch_start, first_token_callback) = match first_token_callback {
+        Some((start, callback)) => (Some(start), Some(callback)),
+        None => (None, None),
+    };
     Box::pin(SessionTransformStream {
         raw,
         session: lingua::StreamTransformSession::with_full_response_fallback(
@@ -127,6 +209,8 @@ pub fn transform_stream_with_capture(
         #[cfg(feature = "tracing")]
         gateway_request_id,
         raw_chunk_capture,
+        dispatch_start,
+        first_token_callback,
         pending: Vec::new(),
         done: false,
     })
@@ -138,10 +222,32 @@ struct SessionTransformStream {
     #[cfg(feature = "tracing")]
     gateway_request_id: Option<String>,
     raw_chunk_capture: Option<RawStreamChunkCapture>,
+    dispatch_start: Option<Instant>,
+    first_token_callback: Option<FirstTokenCallback>,
     pending: Vec<Result<StreamChunk>>,
     done: bool,
 }
 
+impl SessionTransformStream {
+    /// Fire `first_token_callback` on the first content-bearing chunk among
+    /// `chunks`, if it hasn't already fired for this stream.
+    fn record_first_token(&mut self, chunks: &[StreamChunk]) {
+        let Some(callback) = self.first_token_callback.take() else {
+            return;
+        };
+        let Some(start) = self.dispatch_start else {
+            return;
+        };
+        let Some(_first_content) = chunks.iter().find(|c| stream_chunk_is_content_bearing(c))
+        else {
+            // Not yet seen; put the callback back for the next batch of chunks.
+            self.first_token_callback = Some(callback);
+            return;
+        };
+        callback(start.elapsed());
+    }
+}
+
 impl Stream for SessionTransformStream {
     type Item = Result<StreamChunk>;
 
@@ -170,12 +276,15 @@ impl Stream for SessionTransformStream {
 
                     match this.session.push(data.clone()) {
                         Ok(chunks) => {
-                            this.pending.extend(chunks.into_iter().map(|chunk| {
-                                Ok(StreamChunk {
+                            let chunks: Vec<StreamChunk> = chunks
+                                .into_iter()
+                                .map(|chunk| StreamChunk {
                                     data: chunk.data,
                                     event_type: chunk.event_type,
                                 })
-                            }));
+                                .collect();
+                            this.record_first_token(&chunks);
+                            this.pending.extend(chunks.into_iter().map(Ok));
                             if !this.pending.is_empty() {
                                 return Poll::Ready(Some(this.pending.remove(0)));
                             }
@@ -260,6 +369,16 @@ impl Stream for SingleBytesStream {
 }
 
 /// Raw SSE stream that yields JSON bytes without parsing.
+///
+/// Incoming bytes accumulate in `buffer` across polls and are only handed to
+/// [`extract_json_bytes_from_sse`] (which decodes them as UTF-8) once
+/// [`split_event`] finds a complete event delimiter. This is boundary-safe
+/// for multi-byte UTF-8 characters split across TCP reads: `\n`/`\r` are
+/// ASCII bytes (0x0A/0x0D), which can never appear as a byte of a multi-byte
+/// UTF-8 sequence (continuation and lead bytes are always >= 0x80), so a
+/// character can never be torn apart by the delimiter search - a read that
+/// lands mid-character just leaves the event incomplete until more bytes
+/// arrive.
 struct RawSseStream<S>
 where
     S: Stream<Item = std::result::Result<Bytes, reqwest::Error>> + Unpin + Send + 'static,
@@ -299,7 +418,14 @@ where
             if let Some((event, rest)) = split_event(&this.buffer) {
                 this.buffer = rest;
                 match extract_json_bytes_from_sse(event) {
-                    Ok(Some(chunk)) => return Poll::Ready(Some(Ok(chunk))),
+                    Ok(Some(chunk)) => {
+                        if is_keep_alive_chunk(&chunk) {
+                            // SSE comment line (e.g. `: keep-alive`) or blank heartbeat -
+                            // carries no payload, so don't surface it as a stream item.
+                            continue;
+                        }
+                        return Poll::Ready(Some(Ok(chunk)));
+                    }
                     Ok(None) => {
                         // [DONE] signal
                         this.finished = true;
@@ -321,12 +447,13 @@ where
                     }
 
                     let remaining = this.buffer.split().freeze();
+                    this.finished = true;
                     match extract_json_bytes_from_sse(remaining) {
-                        Ok(Some(chunk)) => return Poll::Ready(Some(Ok(chunk))),
-                        Ok(None) => {
-                            this.finished = true;
+                        Ok(Some(chunk)) if is_keep_alive_chunk(&chunk) => {
                             return Poll::Ready(None);
                         }
+                        Ok(Some(chunk)) => return Poll::Ready(Some(Ok(chunk))),
+                        Ok(None) => return Poll::Ready(None),
                         Err(err) => return Poll::Ready(Some(Err(err))),
                     }
                 }
@@ -354,12 +481,23 @@ fn parse_non_sse_chunk(raw: &str) -> Option<StreamChunk> {
     Some(StreamChunk::data(Bytes::new()))
 }
 
+/// Returns `true` for a chunk that carries no payload (an SSE comment line like
+/// `: keep-alive`, or a blank heartbeat), which callers should skip rather than
+/// surface as a stream item.
+fn is_keep_alive_chunk(chunk: &StreamChunk) -> bool {
+    chunk.event_type.is_none() && chunk.data.is_empty()
+}
+
 fn extract_json_bytes_from_sse(event: Bytes) -> Result<Option<StreamChunk>> {
     let raw = String::from_utf8_lossy(&event);
     let mut data = String::new();
     let mut event_type: Option<String> = None;
 
     for line in raw.lines() {
+        if line.starts_with(':') {
+            // SSE comment line (e.g. `: keep-alive`) - not part of the event data.
+            continue;
+        }
         if let Some(payload) = line.strip_prefix("data:") {
             let payload = payload.trim_start();
             if payload == "[DONE]" {
@@ -562,6 +700,45 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn extract_json_bytes_skips_comment_line() {
+        let event = Bytes::from(": keep-alive\n\n");
+        let result = extract_json_bytes_from_sse(event).unwrap();
+        assert!(result.is_some());
+        assert!(is_keep_alive_chunk(&result.unwrap()));
+    }
+
+    #[test]
+    fn extract_json_bytes_skips_comment_line_interleaved_with_data() {
+        let event = Bytes::from(": keep-alive\ndata: {\"test\": 1}\n\n");
+        let result = extract_json_bytes_from_sse(event).unwrap();
+        assert!(result.is_some());
+        let chunk = result.unwrap();
+        assert_eq!(chunk.data.as_ref(), b"{\"test\": 1}");
+        assert!(!is_keep_alive_chunk(&chunk));
+    }
+
+    #[test]
+    fn raw_sse_stream_ignores_comment_lines_between_real_events() {
+        let mut buffer = BytesMut::from(
+            "data: {\"test\": 1}\n\n: keep-alive\n\ndata: {\"test\": 2}\n\n: keep-alive\n\n",
+        );
+        let mut chunks = Vec::new();
+        while let Some((event, rest)) = split_event(&buffer) {
+            buffer = rest;
+            if let Some(chunk) = extract_json_bytes_from_sse(event).unwrap() {
+                if is_keep_alive_chunk(&chunk) {
+                    continue;
+                }
+                chunks.push(chunk);
+            }
+        }
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].data.as_ref(), b"{\"test\": 1}");
+        assert_eq!(chunks[1].data.as_ref(), b"{\"test\": 2}");
+    }
+
     #[test]
     fn split_event_handles_lf_delimiter() {
         let mut buffer = BytesMut::from("data: {\"test\": 1}\n\ndata: {\"test\": 2}\n\n");
@@ -571,6 +748,31 @@ mod tests {
         assert!(!buffer.is_empty());
     }
 
+    #[test]
+    fn raw_sse_stream_reassembles_multibyte_utf8_character_split_across_chunks() {
+        // "😀" encodes to the 4 UTF-8 bytes F0 9F 98 80. Split the SSE event
+        // so a TCP-level read boundary lands inside that sequence, mirroring
+        // how a real socket can hand back a partial multi-byte character.
+        let full_event = Bytes::from_static("data: {\"text\":\"😀\"}\n\n".as_bytes());
+        let split_at = full_event
+            .iter()
+            .position(|&b| b == 0xF0)
+            .expect("event contains the emoji's lead byte")
+            + 2; // land inside the 4-byte sequence, after its first two bytes
+        let first_chunk = full_event.slice(0..split_at);
+        let second_chunk = full_event.slice(split_at..);
+
+        let chunks: Vec<std::result::Result<Bytes, reqwest::Error>> =
+            vec![Ok(first_chunk), Ok(second_chunk)];
+        let mut stream = RawSseStream::new(futures::stream::iter(chunks));
+
+        let chunk = futures::executor::block_on(stream.next())
+            .expect("stream yields a chunk")
+            .expect("chunk decodes without error");
+
+        assert_eq!(chunk.data.as_ref(), "{\"text\":\"😀\"}".as_bytes());
+    }
+
     #[test]
     fn transform_stream_can_disable_full_response_fallback() {
         let full_response = Bytes::from_static(
@@ -591,4 +793,137 @@ mod tests {
         assert!(first.event_type.is_none());
         assert!(next.is_none());
     }
+
+    /// A stream that never completes on its own (every poll after the first
+    /// is `Pending`) and records into `cancelled` when dropped - standing in
+    /// for the upstream reqwest byte stream, whose connection is torn down
+    /// when its `Response`/`bytes_stream()` is dropped mid-flight.
+    struct NeverEndingStream {
+        yielded_first: bool,
+        cancelled: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl Stream for NeverEndingStream {
+        type Item = Result<StreamChunk>;
+
+        fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+            if !this.yielded_first {
+                this.yielded_first = true;
+                return Poll::Ready(Some(Ok(StreamChunk::data(Bytes::from_static(
+                    br#"{"id":"chatcmpl-test","object":"chat.completion.chunk","created":123,"model":"gpt-4","choices":[{"index":0,"delta":{"role":"assistant","content":"Hi"},"finish_reason":null}]}"#,
+                )))));
+            }
+            Poll::Pending
+        }
+    }
+
+    impl Drop for NeverEndingStream {
+        fn drop(&mut self) {
+            self.cancelled
+                .store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn dropping_transformed_stream_cancels_upstream_raw_stream() {
+        let cancelled = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let raw: RawResponseStream = Box::pin(NeverEndingStream {
+            yielded_first: false,
+            cancelled: cancelled.clone(),
+        });
+
+        let mut stream = transform_stream(raw, ProviderFormat::ChatCompletions, false, None);
+
+        let first = futures::executor::block_on(stream.next())
+            .expect("stream should yield a chunk")
+            .expect("chunk should be ok");
+        assert!(first.data.len() > 0);
+        assert!(
+            !cancelled.load(std::sync::atomic::Ordering::SeqCst),
+            "upstream must still be alive while the caller keeps reading"
+        );
+
+        // The caller (e.g. a disconnected client) gives up on the response
+        // without draining it. Dropping the transformed stream handle must
+        // propagate all the way down to the raw upstream stream.
+        drop(stream);
+
+        assert!(
+            cancelled.load(std::sync::atomic::Ordering::SeqCst),
+            "dropping the response stream should cancel the upstream raw stream"
+        );
+    }
+
+    #[test]
+    fn first_token_callback_fires_once_on_first_content_chunk() {
+        use std::sync::{Arc, Mutex};
+
+        // A role-only delta (no content yet) followed by a real content delta,
+        // mirroring how a Chat Completions stream opens.
+        let role_only = Bytes::from(
+            lingua::serde_json::to_vec(&lingua::serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion.chunk",
+                "created": 123,
+                "model": "gpt-4",
+                "choices": [{
+                    "index": 0,
+                    "delta": { "role": "assistant", "content": "" },
+                    "finish_reason": null
+                }]
+            }))
+            .unwrap(),
+        );
+        let with_content = Bytes::from(
+            lingua::serde_json::to_vec(&lingua::serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion.chunk",
+                "created": 123,
+                "model": "gpt-4",
+                "choices": [{
+                    "index": 0,
+                    "delta": { "content": "Hello" },
+                    "finish_reason": null
+                }]
+            }))
+            .unwrap(),
+        );
+
+        let raw: RawResponseStream = Box::pin(futures::stream::iter(vec![
+            Ok(StreamChunk::data(role_only)),
+            Ok(StreamChunk::data(with_content)),
+        ]));
+
+        let fired: Arc<Mutex<Vec<Duration>>> = Arc::new(Mutex::new(Vec::new()));
+        let fired_for_callback = fired.clone();
+        let callback: FirstTokenCallback = Arc::new(move |elapsed| {
+            fired_for_callback.lock().unwrap().push(elapsed);
+        });
+
+        let dispatch_start = Instant::now();
+        let mut stream = transform_stream_with_instrumentation(
+            raw,
+            ProviderFormat::Anthropic,
+            false,
+            None,
+            None,
+            Some((dispatch_start, callback)),
+        );
+
+        // First transformed chunk is the role-only `message_start` - the
+        // callback must not have fired yet.
+        let first = futures::executor::block_on(stream.next())
+            .expect("stream should yield a chunk")
+            .expect("chunk should be ok");
+        assert_eq!(first.event_type.as_deref(), Some("message_start"));
+        assert!(fired.lock().unwrap().is_empty());
+
+        // Drain the rest of the stream (content_block_start, content_block_delta, ...).
+        while futures::executor::block_on(stream.next()).is_some() {}
+
+        let recorded = fired.lock().unwrap();
+        assert_eq!(recorded.len(), 1, "callback should fire exactly once");
+        assert!(recorded[0] >= Duration::ZERO);
+    }
 }
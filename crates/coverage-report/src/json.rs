@@ -0,0 +1,205 @@
+/*!
+Structured JSON report generation.
+
+Machine-readable mirror of the markdown/compact reports, intended for CI to diff
+transform fidelity numerically across PRs rather than scrape formatted text.
+*/
+
+use std::collections::HashMap;
+
+use lingua::processing::adapters::ProviderAdapter;
+use serde::Serialize;
+
+use crate::types::{CoverageSelection, PairResult, RoundtripDiff};
+
+#[derive(Debug, Serialize)]
+pub struct JsonReport {
+    pub sections: Vec<JsonSection>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonSection {
+    pub name: &'static str,
+    pub pairs: Vec<JsonPairResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonPairResult {
+    pub source: String,
+    pub target: String,
+    pub passed: usize,
+    pub failed: usize,
+    pub limitations: usize,
+    pub failures: Vec<JsonFailure>,
+    pub limitation_details: Vec<JsonLimitation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonFailure {
+    pub test_case: String,
+    pub error: String,
+    pub diff: Option<JsonDiff>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonLimitation {
+    pub test_case: String,
+    pub reason: String,
+    pub diff: Option<JsonDiff>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonDiff {
+    pub lost_fields: Vec<String>,
+    pub added_fields: Vec<String>,
+    pub changed_fields: Vec<JsonChangedField>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonChangedField {
+    pub path: String,
+    pub before: String,
+    pub after: String,
+}
+
+impl From<&RoundtripDiff> for JsonDiff {
+    fn from(diff: &RoundtripDiff) -> Self {
+        JsonDiff {
+            lost_fields: diff.lost_fields.clone(),
+            added_fields: diff.added_fields.clone(),
+            changed_fields: diff
+                .changed_fields
+                .iter()
+                .map(|(path, before, after)| JsonChangedField {
+                    path: path.clone(),
+                    before: before.clone(),
+                    after: after.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn section_pairs(
+    results: &HashMap<(usize, usize), PairResult>,
+    adapters: &[Box<dyn ProviderAdapter>],
+) -> Vec<JsonPairResult> {
+    let mut pairs: Vec<JsonPairResult> = results
+        .iter()
+        .map(|((source_idx, target_idx), pair_result)| JsonPairResult {
+            source: adapters[*source_idx].display_name().to_string(),
+            target: adapters[*target_idx].display_name().to_string(),
+            passed: pair_result.passed,
+            failed: pair_result.failed,
+            limitations: pair_result.limitations,
+            failures: pair_result
+                .failures
+                .iter()
+                .map(|(test_case, error, diff)| JsonFailure {
+                    test_case: test_case.clone(),
+                    error: error.clone(),
+                    diff: diff.as_ref().map(JsonDiff::from),
+                })
+                .collect(),
+            limitation_details: pair_result
+                .limitation_details
+                .iter()
+                .map(|(test_case, reason, diff)| JsonLimitation {
+                    test_case: test_case.clone(),
+                    reason: reason.clone(),
+                    diff: diff.as_ref().map(JsonDiff::from),
+                })
+                .collect(),
+        })
+        .collect();
+
+    pairs.sort_by(|a, b| {
+        (a.source.as_str(), a.target.as_str()).cmp(&(b.source.as_str(), b.target.as_str()))
+    });
+    pairs
+}
+
+/// Build a [`JsonReport`] covering the sections selected by `selection`.
+pub fn generate_json_report(
+    request_results: &HashMap<(usize, usize), PairResult>,
+    response_results: &HashMap<(usize, usize), PairResult>,
+    streaming_results: &HashMap<(usize, usize), PairResult>,
+    adapters: &[Box<dyn ProviderAdapter>],
+    selection: CoverageSelection,
+) -> JsonReport {
+    let mut sections = Vec::new();
+    if selection.requests {
+        sections.push(JsonSection {
+            name: "requests",
+            pairs: section_pairs(request_results, adapters),
+        });
+    }
+    if selection.responses {
+        sections.push(JsonSection {
+            name: "responses",
+            pairs: section_pairs(response_results, adapters),
+        });
+    }
+    if selection.streaming {
+        sections.push(JsonSection {
+            name: "streaming",
+            pairs: section_pairs(streaming_results, adapters),
+        });
+    }
+    JsonReport { sections }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CoverageSelection;
+
+    #[test]
+    fn json_report_shape_is_stable() {
+        let mut results: HashMap<(usize, usize), PairResult> = HashMap::new();
+        results.insert(
+            (0, 1),
+            PairResult {
+                passed: 2,
+                failed: 1,
+                limitations: 0,
+                failures: vec![(
+                    "seedParam".to_string(),
+                    "field mismatch".to_string(),
+                    Some(RoundtripDiff {
+                        lost_fields: vec!["seed".to_string()],
+                        added_fields: vec![],
+                        changed_fields: vec![(
+                            "model".to_string(),
+                            "gpt-4o".to_string(),
+                            "gpt-4o-mini".to_string(),
+                        )],
+                        expected_diffs: vec![],
+                    }),
+                )],
+                limitation_details: vec![],
+            },
+        );
+
+        let adapters = lingua::processing::adapters::adapters();
+        let report = generate_json_report(
+            &results,
+            &HashMap::new(),
+            &HashMap::new(),
+            adapters,
+            CoverageSelection::all(),
+        );
+
+        let value = big_serde_json::to_value(&report).unwrap();
+        assert_eq!(value["sections"][0]["name"], "requests");
+        let pair = &value["sections"][0]["pairs"][0];
+        assert_eq!(pair["passed"], 2);
+        assert_eq!(pair["failed"], 1);
+        assert_eq!(pair["failures"][0]["test_case"], "seedParam");
+        assert_eq!(pair["failures"][0]["diff"]["lost_fields"][0], "seed");
+        assert_eq!(
+            pair["failures"][0]["diff"]["changed_fields"][0]["path"],
+            "model"
+        );
+    }
+}
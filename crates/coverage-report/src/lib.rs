@@ -19,7 +19,8 @@ let (requests, responses, streaming) = run_all_tests(adapters, &filter);
 pub mod compact;
 pub mod discovery;
 pub mod expected;
-mod normalizers;
+pub mod json;
+pub mod normalizers;
 pub mod report;
 pub mod runner;
 pub mod types;
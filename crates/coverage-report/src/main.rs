@@ -19,6 +19,7 @@ Usage:
 
 use std::str::FromStr;
 
+use coverage_report::normalizers::set_ignore_volatile_fields;
 use coverage_report::report::generate_report;
 use coverage_report::runner::run_all_tests;
 use coverage_report::types::{parse_provider, CoverageSelection, OutputFormat, TestFilter};
@@ -28,6 +29,8 @@ struct CliArgs {
     selection: CoverageSelection,
     filter: TestFilter,
     format: OutputFormat,
+    ignore_volatile: bool,
+    verbose: bool,
 }
 
 fn parse_cli_args() -> Result<CliArgs, String> {
@@ -37,6 +40,8 @@ fn parse_cli_args() -> Result<CliArgs, String> {
     let mut source_arg: Option<String> = None;
     let mut target_arg: Option<String> = None;
     let mut format_arg: Option<String> = None;
+    let mut ignore_volatile = false;
+    let mut verbose = false;
 
     let mut args = std::env::args().skip(1);
 
@@ -78,6 +83,12 @@ fn parse_cli_args() -> Result<CliArgs, String> {
                     return Err("Missing value for --format".to_string());
                 }
             }
+            "--ignore-volatile" => {
+                ignore_volatile = true;
+            }
+            "--verbose" | "-v" => {
+                verbose = true;
+            }
             _ if arg.starts_with("--coverage=") => {
                 selection_arg = Some(arg.strip_prefix("--coverage=").unwrap().to_string());
             }
@@ -166,6 +177,8 @@ fn parse_cli_args() -> Result<CliArgs, String> {
         selection,
         filter,
         format,
+        ignore_volatile,
+        verbose,
     })
 }
 
@@ -182,7 +195,9 @@ fn print_usage() {
     );
     eprintln!("  --source <names>         Filter source providers");
     eprintln!("  --target <names>         Filter target providers");
-    eprintln!("  -f, --format <format>    Output format: markdown (default), compact");
+    eprintln!("  -f, --format <format>    Output format: markdown (default), compact, json");
+    eprintln!("  --ignore-volatile        Mask non-deterministic fields (id, created, timestamp) before comparing");
+    eprintln!("  -v, --verbose            Show per-field lost/added/changed diffs for failures in the markdown report");
     eprintln!();
     eprintln!("Provider names: responses, chat-completions, anthropic, google, bedrock, bedrock-anthropic");
     eprintln!();
@@ -206,6 +221,8 @@ fn main() {
         selection,
         filter,
         format,
+        ignore_volatile,
+        verbose,
     } = match parse_cli_args() {
         Ok(args) => args,
         Err(error) => {
@@ -216,6 +233,8 @@ fn main() {
         }
     };
 
+    set_ignore_volatile_fields(ignore_volatile);
+
     let adapters = adapters();
 
     // Run all transformation tests (including roundtrip when source == target)
@@ -228,6 +247,7 @@ fn main() {
         adapters,
         selection,
         format,
+        verbose,
     );
     println!("{}", report);
 }
@@ -4,6 +4,8 @@ Semantic-equivalence normalizers for coverage-report diffs.
 These rules apply only to Universal types and keep scope explicit and type-safe.
 */
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use lingua::serde_json::Value;
 use lingua::universal::{
     message::{
@@ -13,6 +15,58 @@ use lingua::universal::{
     UniversalRequest, UniversalResponse, UniversalStreamChunk,
 };
 
+/// Field names that vary between otherwise-identical provider responses (request ids,
+/// creation timestamps, etc.) and should not cause spurious coverage-report diffs.
+const DEFAULT_VOLATILE_FIELDS: &[&str] = &["id", "created", "created_at", "timestamp"];
+
+static IGNORE_VOLATILE_FIELDS: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable masking of [`DEFAULT_VOLATILE_FIELDS`] before comparison.
+///
+/// Controlled by the coverage-report CLI's `--ignore-volatile` flag.
+pub fn set_ignore_volatile_fields(enabled: bool) {
+    IGNORE_VOLATILE_FIELDS.store(enabled, Ordering::Relaxed);
+}
+
+fn ignore_volatile_fields_enabled() -> bool {
+    IGNORE_VOLATILE_FIELDS.load(Ordering::Relaxed)
+}
+
+/// Recursively mask `field_names` in a JSON value with a stable placeholder, so that
+/// non-deterministic values (ids, timestamps) don't register as diffs.
+pub fn mask_volatile_fields(value: &Value, field_names: &[&str]) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, val)| {
+                    if field_names.contains(&key.as_str()) && !val.is_null() {
+                        (key.clone(), Value::String("<volatile>".to_string()))
+                    } else {
+                        (key.clone(), mask_volatile_fields(val, field_names))
+                    }
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| mask_volatile_fields(item, field_names))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Apply [`mask_volatile_fields`] with the default field list, if enabled via
+/// [`set_ignore_volatile_fields`].
+pub fn mask_volatile_fields_if_enabled(value: Value) -> Value {
+    if ignore_volatile_fields_enabled() {
+        mask_volatile_fields(&value, DEFAULT_VOLATILE_FIELDS)
+    } else {
+        value
+    }
+}
+
 /// Normalize a UniversalRequest for semantic comparison.
 ///
 /// Rule: message content strings are equivalent to a single text-part array.
@@ -54,7 +108,7 @@ fn normalize_message_content(message: &mut Message) {
     match message {
         Message::System { content }
         | Message::Developer { content }
-        | Message::User { content } => {
+        | Message::User { content, .. } => {
             normalize_user_content(content);
         }
         Message::Assistant { content, .. } => {
@@ -97,3 +151,42 @@ fn text_part_value(text: String) -> Value {
         .collect(),
     )])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lingua::serde_json::json;
+
+    #[test]
+    fn mask_volatile_fields_replaces_only_named_fields() {
+        let value = json!({
+            "id": "chatcmpl-abc123",
+            "created": 1_700_000_000,
+            "model": "gpt-4o",
+            "usage": { "id": "nested-id", "total_tokens": 10 }
+        });
+
+        let masked = mask_volatile_fields(&value, DEFAULT_VOLATILE_FIELDS);
+
+        assert_eq!(masked["id"], json!("<volatile>"));
+        assert_eq!(masked["created"], json!("<volatile>"));
+        assert_eq!(masked["model"], json!("gpt-4o"));
+        assert_eq!(masked["usage"]["id"], json!("<volatile>"));
+        assert_eq!(masked["usage"]["total_tokens"], json!(10));
+    }
+
+    #[test]
+    fn mask_volatile_fields_if_enabled_respects_flag() {
+        let value = json!({ "id": "abc" });
+
+        set_ignore_volatile_fields(false);
+        assert_eq!(mask_volatile_fields_if_enabled(value.clone()), value);
+
+        set_ignore_volatile_fields(true);
+        assert_eq!(
+            mask_volatile_fields_if_enabled(value.clone()),
+            json!({ "id": "<volatile>" })
+        );
+        set_ignore_volatile_fields(false);
+    }
+}
@@ -130,21 +130,42 @@ pub fn generate_table(
     }
 }
 
+/// Cap on how many lost/added/changed paths `format_diff` renders per field
+/// kind, so a pathological diff (e.g. an array reordering with hundreds of
+/// entries) doesn't blow up the report.
+const MAX_DIFF_PATHS: usize = 10;
+
+/// Render `field_kind: path, path, ...` for up to `MAX_DIFF_PATHS` entries of
+/// `paths`, noting how many were omitted.
+fn format_diff_paths(field_kind: &str, paths: &[String]) -> String {
+    if paths.is_empty() {
+        return String::new();
+    }
+    let shown = paths
+        .iter()
+        .take(MAX_DIFF_PATHS)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    let remaining = paths.len().saturating_sub(MAX_DIFF_PATHS);
+    if remaining > 0 {
+        format!("\n        {}: {} (+{} more)", field_kind, shown, remaining)
+    } else {
+        format!("\n        {}: {}", field_kind, shown)
+    }
+}
+
+/// Render a per-field breakdown of `diff` (lost/added/changed paths, with
+/// before/after values truncated) for the `--verbose` report mode.
 fn format_diff(diff: &Option<RoundtripDiff>) -> String {
     match diff {
         Some(d) if !d.is_empty() => {
             let mut output = String::new();
-            if !d.lost_fields.is_empty() {
-                output.push_str("\n        Lost: ");
-                output.push_str(&d.lost_fields.join(", "));
-            }
-            if !d.added_fields.is_empty() {
-                output.push_str("\n        Added: ");
-                output.push_str(&d.added_fields.join(", "));
-            }
+            output.push_str(&format_diff_paths("Lost", &d.lost_fields));
+            output.push_str(&format_diff_paths("Added", &d.added_fields));
             if !d.changed_fields.is_empty() {
                 output.push_str("\n        Changed:");
-                for (path, original, roundtripped) in &d.changed_fields {
+                for (path, original, roundtripped) in d.changed_fields.iter().take(MAX_DIFF_PATHS) {
                     let orig_display = truncate_display(original, 50);
                     let round_display = truncate_display(roundtripped, 50);
                     output.push_str(&format!(
@@ -152,6 +173,10 @@ fn format_diff(diff: &Option<RoundtripDiff>) -> String {
                         path, orig_display, round_display
                     ));
                 }
+                let remaining = d.changed_fields.len().saturating_sub(MAX_DIFF_PATHS);
+                if remaining > 0 {
+                    output.push_str(&format!("\n          - (+{} more)", remaining));
+                }
             }
             output
         }
@@ -166,6 +191,7 @@ pub fn generate_report(
     adapters: &[Box<dyn ProviderAdapter>],
     selection: CoverageSelection,
     format: OutputFormat,
+    verbose: bool,
 ) -> String {
     match format {
         OutputFormat::Markdown => generate_markdown_report(
@@ -174,6 +200,7 @@ pub fn generate_report(
             streaming_results,
             adapters,
             selection,
+            verbose,
         ),
         OutputFormat::Compact => generate_compact_report(
             request_results,
@@ -182,6 +209,18 @@ pub fn generate_report(
             adapters,
             selection,
         ),
+        OutputFormat::Json => {
+            let report = crate::json::generate_json_report(
+                request_results,
+                response_results,
+                streaming_results,
+                adapters,
+                selection,
+            );
+            big_serde_json::to_string_pretty(&report).unwrap_or_else(|e| {
+                format!("{{\"error\": \"failed to serialize JSON report: {}\"}}", e)
+            })
+        }
     }
 }
 
@@ -245,6 +284,7 @@ fn generate_markdown_report(
     streaming_results: &HashMap<(usize, usize), PairResult>,
     adapters: &[Box<dyn ProviderAdapter>],
     selection: CoverageSelection,
+    verbose: bool,
 ) -> String {
     let mut report = String::new();
 
@@ -484,7 +524,11 @@ fn generate_markdown_report(
                             "      - `{}` - {}{}\n",
                             test_case,
                             compact::truncate_str(&error, 200),
-                            format_diff(&diff)
+                            if verbose {
+                                format_diff(&diff)
+                            } else {
+                                String::new()
+                            }
                         ));
                     }
 
@@ -534,7 +578,11 @@ fn generate_markdown_report(
                             "      - `{}` - {}{}\n",
                             test_case,
                             compact::truncate_str(&error, 200),
-                            format_diff(&diff)
+                            if verbose {
+                                format_diff(&diff)
+                            } else {
+                                String::new()
+                            }
                         ));
                     }
 
@@ -584,7 +632,11 @@ fn generate_markdown_report(
                             "      - `{}` - {}{}\n",
                             test_case,
                             compact::truncate_str(&error, 200),
-                            format_diff(&diff)
+                            if verbose {
+                                format_diff(&diff)
+                            } else {
+                                String::new()
+                            }
                         ));
                     }
 
@@ -5,18 +5,20 @@ Test execution for coverage-report.
 use std::collections::HashMap;
 
 use lingua::capabilities::ProviderFormat;
+use lingua::processing::adapter_for_format;
 use lingua::processing::adapters::ProviderAdapter;
 use lingua::serde_json::Value;
 use lingua::universal::{
-    UniversalRequest, UniversalResponse, UniversalStreamChoice, UniversalStreamChunk,
-    UniversalStreamDelta, UniversalToolCallDelta,
+    AssistantContent, AssistantContentPart, Message, UniversalAudioDelta, UniversalRequest,
+    UniversalResponse, UniversalStreamChoice, UniversalStreamChunk, UniversalStreamDelta,
+    UniversalToolCallDelta,
 };
 
 use crate::discovery::{discover_test_cases_filtered, load_payload};
 use crate::expected::TestCategory;
 use crate::normalizers::{
-    normalize_request_for_comparison, normalize_response_for_comparison,
-    normalize_stream_chunk_for_comparison,
+    mask_volatile_fields_if_enabled, normalize_request_for_comparison,
+    normalize_response_for_comparison, normalize_stream_chunk_for_comparison,
 };
 use crate::types::{PairResult, TestFilter, TransformResult, ValidationLevel};
 
@@ -28,7 +30,9 @@ fn universal_request_to_value(req: &UniversalRequest) -> Value {
 }
 
 fn universal_response_to_value(resp: &UniversalResponse) -> Value {
-    lingua::serde_json::to_value(normalize_response_for_comparison(resp)).unwrap_or(Value::Null)
+    let value = lingua::serde_json::to_value(normalize_response_for_comparison(resp))
+        .unwrap_or(Value::Null);
+    mask_volatile_fields_if_enabled(value)
 }
 
 fn universal_stream_to_value(chunk: &UniversalStreamChunk) -> Value {
@@ -475,6 +479,189 @@ pub fn test_streaming_transformation(
     }
 }
 
+/// Test that a recorded streaming transcript reconstructs the same assistant text as the
+/// non-streaming response for the same test case.
+///
+/// `test_streaming_transformation` only checks that each event converts without error; it
+/// can't see a transcript that converts cleanly event-by-event but drops or duplicates
+/// content across the whole stream. This walks the transcript, accumulates the assistant
+/// text deltas into a single string, and compares it against the plain text extracted from
+/// the direct (non-streaming) response transform - catching streaming-only regressions the
+/// request/response suites miss.
+///
+/// Responses whose assistant content has no plain text (e.g. tool-call-only turns) are
+/// skipped, since this check only reconstructs text.
+pub fn test_streaming_response_fidelity(
+    test_case: &str,
+    source_adapter: &dyn ProviderAdapter,
+    response_filename: &str,
+    streaming_filename: &str,
+) -> TransformResult {
+    let response_payload = match load_payload(
+        test_case,
+        source_adapter.directory_name(),
+        response_filename,
+    ) {
+        Some(p) => p,
+        None => {
+            return TransformResult {
+                level: ValidationLevel::Skipped,
+                error: Some(format!("Response payload not found: {}", response_filename)),
+                diff: None,
+                limitation_reason: None,
+            }
+        }
+    };
+
+    let streaming_payload = match load_payload(
+        test_case,
+        source_adapter.directory_name(),
+        streaming_filename,
+    ) {
+        Some(p) => p,
+        None => {
+            return TransformResult {
+                level: ValidationLevel::Skipped,
+                error: Some(format!(
+                    "Streaming payload not found: {}",
+                    streaming_filename
+                )),
+                diff: None,
+                limitation_reason: None,
+            }
+        }
+    };
+
+    let response_value: Value = match lingua::serde_json::from_slice(&response_payload) {
+        Ok(v) => v,
+        Err(e) => {
+            return TransformResult {
+                level: ValidationLevel::Fail,
+                error: Some(format!("Failed to parse response payload: {}", e)),
+                diff: None,
+                limitation_reason: None,
+            }
+        }
+    };
+
+    let direct_universal = match source_adapter.response_to_universal(response_value) {
+        Ok(u) => u,
+        Err(e) => {
+            return TransformResult {
+                level: ValidationLevel::Fail,
+                error: Some(format!("Direct response conversion failed: {}", e)),
+                diff: None,
+                limitation_reason: None,
+            }
+        }
+    };
+
+    let Some(direct_text) = first_assistant_text(&direct_universal) else {
+        return TransformResult {
+            level: ValidationLevel::Skipped,
+            error: Some("Response has no plain-text assistant content to compare".to_string()),
+            diff: None,
+            limitation_reason: None,
+        };
+    };
+
+    let streaming_value: Value = match lingua::serde_json::from_slice(&streaming_payload) {
+        Ok(v) => v,
+        Err(e) => {
+            return TransformResult {
+                level: ValidationLevel::Fail,
+                error: Some(format!("Failed to parse streaming payload: {}", e)),
+                diff: None,
+                limitation_reason: None,
+            }
+        }
+    };
+
+    let events = match streaming_value.as_array() {
+        Some(arr) => arr,
+        None => {
+            return TransformResult {
+                level: ValidationLevel::Fail,
+                error: Some("Streaming payload is not an array".to_string()),
+                diff: None,
+                limitation_reason: None,
+            }
+        }
+    };
+
+    let mut reconstructed_text = String::new();
+    for (idx, event) in events.iter().enumerate() {
+        match source_adapter.stream_to_universal(event.clone()) {
+            Ok(Some(chunk)) => {
+                for choice in &chunk.choices {
+                    if let Some(content) = choice.delta_view().and_then(|delta| delta.content) {
+                        reconstructed_text.push_str(&content);
+                    }
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                return TransformResult {
+                    level: ValidationLevel::Fail,
+                    error: Some(format!(
+                        "Event {} conversion to universal failed: {}",
+                        idx, e
+                    )),
+                    diff: None,
+                    limitation_reason: None,
+                }
+            }
+        }
+    }
+
+    if reconstructed_text == direct_text {
+        TransformResult {
+            level: ValidationLevel::Pass,
+            error: None,
+            diff: None,
+            limitation_reason: None,
+        }
+    } else {
+        TransformResult {
+            level: ValidationLevel::Fail,
+            error: Some(
+                "Reconstructed streaming text does not match direct response text".to_string(),
+            ),
+            diff: Some(RoundtripDiff {
+                changed_fields: vec![(
+                    "assistant_text".to_string(),
+                    direct_text,
+                    reconstructed_text,
+                )],
+                ..Default::default()
+            }),
+            limitation_reason: None,
+        }
+    }
+}
+
+/// Extract the plain text of the first assistant message, if any. Returns `None` for
+/// messages that are entirely non-text (e.g. tool calls, reasoning, refusals).
+fn first_assistant_text(response: &UniversalResponse) -> Option<String> {
+    response.messages.iter().find_map(|message| match message {
+        Message::Assistant { content, .. } => match content {
+            AssistantContent::String(text) => Some(text.clone()),
+            AssistantContent::Array(parts) => {
+                let mut text = String::new();
+                let mut has_text = false;
+                for part in parts {
+                    if let AssistantContentPart::Text(text_part) = part {
+                        has_text = true;
+                        text.push_str(&text_part.text);
+                    }
+                }
+                has_text.then_some(text)
+            }
+        },
+        _ => None,
+    })
+}
+
 /// Test a single streaming event transformation
 fn test_single_stream_event(
     event: &Value,
@@ -771,6 +958,29 @@ fn merge_stream_delta_values(existing: Option<Value>, incoming: Option<Value>) -
     if incoming.reasoning_signature.is_some() {
         merged.reasoning_signature = incoming.reasoning_signature;
     }
+    if let Some(incoming_audio) = incoming.audio {
+        let audio = merged
+            .audio
+            .get_or_insert_with(UniversalAudioDelta::default);
+        if incoming_audio.id.is_some() {
+            audio.id = incoming_audio.id;
+        }
+        if let Some(data) = incoming_audio.data {
+            match &mut audio.data {
+                Some(existing_data) => existing_data.push_str(&data),
+                None => audio.data = Some(data),
+            }
+        }
+        if let Some(transcript) = incoming_audio.transcript {
+            match &mut audio.transcript {
+                Some(existing_transcript) => existing_transcript.push_str(&transcript),
+                None => audio.transcript = Some(transcript),
+            }
+        }
+        if incoming_audio.expires_at.is_some() {
+            audio.expires_at = incoming_audio.expires_at;
+        }
+    }
 
     lingua::serde_json::to_value(merged).ok()
 }
@@ -1018,6 +1228,45 @@ pub fn run_all_tests(adapters: &[Box<dyn ProviderAdapter>], filter: &TestFilter)
                         ));
                     }
                 }
+
+                // Streaming transcript fidelity only depends on the source provider's own
+                // response/streaming fixtures, so it's only meaningful once per source -
+                // check it on the self-pair, alongside the other roundtrip checks.
+                if source_idx == target_idx {
+                    let fidelity_result = test_streaming_response_fidelity(
+                        test_case,
+                        source,
+                        "response.json",
+                        "response-streaming.json",
+                    );
+                    match fidelity_result.level {
+                        ValidationLevel::Skipped => { /* do nothing */ }
+                        ValidationLevel::Pass => stream_pair_result.passed += 1,
+                        ValidationLevel::Fail => {
+                            stream_pair_result.failed += 1;
+                            let error = fidelity_result
+                                .error
+                                .unwrap_or_else(|| "Unknown error".to_string());
+                            stream_pair_result.failures.push((
+                                format!("{} (streaming-fidelity)", test_case),
+                                error,
+                                fidelity_result.diff,
+                            ));
+                        }
+                        ValidationLevel::Limitation => {
+                            stream_pair_result.limitations += 1;
+                            let detail = fidelity_result
+                                .limitation_reason
+                                .or(fidelity_result.error)
+                                .unwrap_or_else(|| "Unknown limitation".to_string());
+                            stream_pair_result.limitation_details.push((
+                                format!("{} (streaming-fidelity)", test_case),
+                                detail,
+                                fidelity_result.diff,
+                            ));
+                        }
+                    }
+                }
             }
         }
     }
@@ -1050,6 +1299,64 @@ pub fn diff_json(original: &Value, roundtripped: &Value) -> RoundtripDiff {
     diff
 }
 
+/// Round-trip `payload` through the provider adapter registered for `format`
+/// (provider JSON -> Universal -> provider JSON) and assert the result is
+/// unchanged.
+///
+/// This is the same check the fuzz harness runs on every generated payload,
+/// pulled out so downstream crates (and the gateway's own tests) can assert
+/// a payload survives a round trip without reimplementing the diffing logic.
+/// On mismatch, returns one human-readable line per lost, added, or changed
+/// field.
+///
+/// # Examples
+///
+/// ```rust
+/// use coverage_report::runner::assert_roundtrip;
+/// use lingua::serde_json::json;
+/// use lingua::ProviderFormat;
+///
+/// let payload = json!({
+///     "model": "gpt-4",
+///     "messages": [{"role": "user", "content": "Hello"}]
+/// });
+///
+/// assert_eq!(assert_roundtrip(ProviderFormat::ChatCompletions, &payload), Ok(()));
+/// ```
+pub fn assert_roundtrip(format: ProviderFormat, payload: &Value) -> Result<(), Vec<String>> {
+    let adapter = adapter_for_format(format)
+        .ok_or_else(|| vec![format!("no adapter registered for {:?}", format)])?;
+
+    let universal = adapter
+        .request_to_universal(payload.clone())
+        .map_err(|e| vec![format!("request_to_universal error: {}", e)])?;
+    let output = adapter
+        .request_from_universal(&universal)
+        .map_err(|e| vec![format!("request_from_universal error: {}", e)])?;
+
+    if *payload == output {
+        return Ok(());
+    }
+
+    let diff = diff_json(payload, &output);
+    let mut issues = Vec::new();
+    for f in &diff.lost_fields {
+        issues.push(format!("lost: {}", f));
+    }
+    for f in &diff.added_fields {
+        issues.push(format!("added: {}", f));
+    }
+    for (f, before, after) in &diff.changed_fields {
+        issues.push(format!("changed: {} ({} -> {})", f, before, after));
+    }
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
 /// Context for value comparison, carrying provider names for expected-difference filtering.
 struct CompareContext<'a> {
     category: TestCategory,
@@ -10,6 +10,7 @@ pub enum OutputFormat {
     #[default]
     Markdown,
     Compact,
+    Json,
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -19,6 +20,7 @@ impl std::str::FromStr for OutputFormat {
         match s.to_lowercase().as_str() {
             "compact" | "c" | "token" | "t" => Ok(OutputFormat::Compact),
             "markdown" | "md" | "full" => Ok(OutputFormat::Markdown),
+            "json" | "j" => Ok(OutputFormat::Json),
             _ => Err(format!("Unknown output format: {}", s)),
         }
     }
@@ -107,19 +109,12 @@ fn glob_match(pattern: &str, text: &str) -> bool {
 }
 
 /// Parse a provider name string into a ProviderFormat.
+///
+/// Delegates to `ProviderFormat`'s canonical `FromStr` impl so this crate
+/// doesn't maintain its own copy of the provider name/alias table.
 pub fn parse_provider(name: &str) -> Result<ProviderFormat, String> {
-    match name.to_lowercase().as_str() {
-        "responses" | "response" | "openai-responses" => Ok(ProviderFormat::Responses),
-        "chat-completions" | "chatcompletions" | "completions" | "openai" => {
-            Ok(ProviderFormat::ChatCompletions)
-        }
-        "anthropic" => Ok(ProviderFormat::Anthropic),
-        "google" | "gemini" => Ok(ProviderFormat::Google),
-        "bedrock" | "converse" => Ok(ProviderFormat::Converse),
-        "bedrock-anthropic" => Ok(ProviderFormat::BedrockAnthropic),
-        "vertex-anthropic" => Ok(ProviderFormat::VertexAnthropic),
-        _ => Err(format!("Unknown provider: {}", name)),
-    }
+    name.parse::<ProviderFormat>()
+        .map_err(|_| format!("Unknown provider: {}", name))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lingua::capabilities::ProviderFormat;
+use lingua::processing::ProviderAdapter;
+use lingua::providers::anthropic::AnthropicAdapter;
+use lingua::serde_json::json;
+use lingua::universal::UniversalRequestBuilder;
+
+fn request_with_raw_anthropic_messages() -> lingua::UniversalRequest {
+    let raw_messages = json!([
+        {"role": "system", "content": "You are a helpful assistant."},
+        {"role": "user", "content": "Hello there, this is a benchmark message."},
+        {"role": "assistant", "content": "Hi! How can I help you today?"},
+        {"role": "user", "content": "Tell me about the weather."},
+    ]);
+
+    UniversalRequestBuilder::new("claude-sonnet-4-5-20250929")
+        .params(|p| {
+            let mut extras = lingua::serde_json::Map::new();
+            extras.insert("messages".into(), raw_messages);
+            p.extras.insert(ProviderFormat::Anthropic, extras);
+        })
+        .build()
+}
+
+fn bench_request_from_universal(c: &mut Criterion) {
+    let adapter = AnthropicAdapter;
+    let req = request_with_raw_anthropic_messages();
+
+    c.bench_function("anthropic_from_universal/checked", |b| {
+        b.iter(|| adapter.request_from_universal(black_box(&req)).unwrap())
+    });
+
+    c.bench_function("anthropic_from_universal/unchecked", |b| {
+        b.iter(|| {
+            adapter
+                .request_from_universal_unchecked(black_box(&req))
+                .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, bench_request_from_universal);
+criterion_main!(benches);
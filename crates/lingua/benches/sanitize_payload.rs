@@ -0,0 +1,27 @@
+use bytes::Bytes;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lingua::capabilities::ProviderFormat;
+use lingua::processing::sanitize_payload;
+use lingua::serde_json::json;
+
+fn already_normalized_anthropic_payload() -> Bytes {
+    Bytes::from(
+        lingua::serde_json::to_vec(&json!({
+            "model": "claude-3-5-sonnet-20241022",
+            "messages": [{"role": "user", "content": "Hello there, this is a benchmark message."}],
+            "max_tokens": 1024
+        }))
+        .unwrap(),
+    )
+}
+
+fn bench_sanitize_payload_passthrough(c: &mut Criterion) {
+    let input = already_normalized_anthropic_payload();
+
+    c.bench_function("sanitize_payload/already_normalized_anthropic", |b| {
+        b.iter(|| sanitize_payload(black_box(input.clone()), ProviderFormat::Anthropic).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_sanitize_payload_passthrough);
+criterion_main!(benches);
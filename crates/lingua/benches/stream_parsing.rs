@@ -0,0 +1,52 @@
+use bytes::Bytes;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lingua::capabilities::ProviderFormat;
+use lingua::processing::{parse_stream_event, parse_stream_event_borrowed};
+use lingua::serde_json::json;
+
+fn openai_delta_chunk() -> Bytes {
+    Bytes::from(
+        lingua::serde_json::to_vec(&json!({
+            "id": "chatcmpl-bench",
+            "object": "chat.completion.chunk",
+            "created": 1_700_000_000,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "delta": { "content": "Hello there, this is a benchmark chunk of text." },
+                "finish_reason": null
+            }]
+        }))
+        .unwrap(),
+    )
+}
+
+fn bench_owned_vs_borrowed(c: &mut Criterion) {
+    let input = openai_delta_chunk();
+
+    let mut group = c.benchmark_group("parse_stream_event");
+    group.bench_function("owned", |b| {
+        b.iter(|| {
+            parse_stream_event(
+                black_box(input.clone()),
+                ProviderFormat::ChatCompletions,
+                ProviderFormat::ChatCompletions,
+            )
+            .unwrap()
+        })
+    });
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            parse_stream_event_borrowed(
+                black_box(&input),
+                ProviderFormat::ChatCompletions,
+                ProviderFormat::ChatCompletions,
+            )
+            .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_owned_vs_borrowed);
+criterion_main!(benches);
@@ -69,17 +69,27 @@ impl std::fmt::Display for ProviderFormat {
     }
 }
 
+/// Canonical string parser for [`ProviderFormat`], covering every variant
+/// (including the internal-only `BedrockAnthropic`/`VertexAnthropic` ones,
+/// which are excluded from serde since they never appear on the wire).
+/// Downstream crates that need to parse a provider name from a CLI flag or
+/// config file should use this instead of hand-rolling their own match.
 impl std::str::FromStr for ProviderFormat {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "openai" | "chat-completions" => Ok(ProviderFormat::ChatCompletions),
+            "openai" | "chat-completions" | "chatcompletions" | "completions" => {
+                Ok(ProviderFormat::ChatCompletions)
+            }
             "anthropic" => Ok(ProviderFormat::Anthropic),
-            "google" => Ok(ProviderFormat::Google),
+            "google" | "gemini" => Ok(ProviderFormat::Google),
             "mistral" => Ok(ProviderFormat::Mistral),
             "converse" | "bedrock" => Ok(ProviderFormat::Converse),
-            "responses" => Ok(ProviderFormat::Responses),
+            "responses" | "response" | "openai-responses" => Ok(ProviderFormat::Responses),
+            "bedrock_anthropic" | "bedrock-anthropic" => Ok(ProviderFormat::BedrockAnthropic),
+            "vertex_anthropic" | "vertex-anthropic" => Ok(ProviderFormat::VertexAnthropic),
+            "unknown" => Ok(ProviderFormat::Unknown),
             _ => Err(()),
         }
     }
@@ -114,4 +124,28 @@ mod tests {
             ProviderFormat::Unknown
         );
     }
+
+    #[test]
+    fn test_display_parse_round_trip_covers_every_variant() {
+        let all = [
+            ProviderFormat::ChatCompletions,
+            ProviderFormat::Anthropic,
+            ProviderFormat::Google,
+            ProviderFormat::Mistral,
+            ProviderFormat::Converse,
+            ProviderFormat::Responses,
+            ProviderFormat::BedrockAnthropic,
+            ProviderFormat::VertexAnthropic,
+            ProviderFormat::Unknown,
+        ];
+        for format in all {
+            let rendered = format.to_string();
+            let parsed: ProviderFormat = rendered.parse().unwrap_or_else(|_| {
+                panic!(
+                    "Display output {rendered:?} for {format:?} should round-trip through FromStr"
+                )
+            });
+            assert_eq!(parsed, format, "round-trip mismatch for {rendered:?}");
+        }
+    }
 }
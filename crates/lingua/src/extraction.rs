@@ -5,11 +5,42 @@
 
 use std::borrow::Cow;
 
+use crate::serde_json::Value;
+
+/// The broad request shape inferred from the payload, used to route to the
+/// right transform/provider method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestKind {
+    /// A messages/contents-based chat request (OpenAI Chat Completions,
+    /// Anthropic, Google, Mistral, Converse, ...).
+    #[default]
+    Chat,
+    /// An OpenAI Responses API request (`input` holding an array of
+    /// message-like items). A plain-string `input` is classified as
+    /// [`RequestKind::Embeddings`], not as a Responses request.
+    Responses,
+    /// An embeddings request (`input` holding a string or array of plain
+    /// strings, with no `messages`/`contents`).
+    Embeddings,
+}
+
 /// Hints extracted from request body for routing decisions.
 #[derive(Debug, Clone, Default)]
 pub struct RequestHints {
     pub model: Option<String>,
     pub stream: bool,
+    pub kind: RequestKind,
+    /// Whether the request asked OpenAI to persist the completion (`store: true`).
+    ///
+    /// A gateway can use this to route store-enabled requests to a different
+    /// pipeline (e.g. one that also forwards to a logging/eval store) instead
+    /// of a plain realtime chat path.
+    pub store: bool,
+    /// The value of a caller-chosen key under the request's `metadata` object,
+    /// only populated by [`extract_request_hints_with_metadata_key`]. Lets a
+    /// routing layer consult a caller-supplied tag (e.g. a canary `route_hint`
+    /// or a `tier`) without this crate needing to know the tag's name in advance.
+    pub metadata_hint: Option<String>,
 }
 
 /// Extract routing hints from request body.
@@ -18,9 +49,28 @@ pub struct RequestHints {
 /// - `model` (OpenAI, Anthropic, Mistral, Google)
 /// - `modelId` (Bedrock)
 /// - `stream` (most providers)
+/// - `messages`/`contents`/`input` (request kind, see [`RequestKind`])
+/// - `store` (OpenAI-only; defaults to `false` when absent)
 ///
 /// Returns `None` if the body is invalid JSON.
 pub fn extract_request_hints(body: &[u8]) -> Option<RequestHints> {
+    extract_request_hints_with_metadata_key(body, None)
+}
+
+/// Same as [`extract_request_hints`], but also pulls `metadata_key` out of the
+/// request's `metadata` object (OpenAI-style arbitrary string tags) into
+/// [`RequestHints::metadata_hint`], for routing on a caller-supplied tag such
+/// as a canary `route_hint` or a `tier`. `metadata_key` is caller-chosen since
+/// this crate has no fixed opinion on what tags mean; pass `None` to skip
+/// metadata extraction entirely.
+///
+/// `metadata_hint` is only set when `metadata_key` is present, the request has
+/// a `metadata` object, and the key's value is a string; anything else (a
+/// missing key, a non-object `metadata`, a non-string value) leaves it `None`.
+pub fn extract_request_hints_with_metadata_key(
+    body: &[u8],
+    metadata_key: Option<&str>,
+) -> Option<RequestHints> {
     #[derive(serde::Deserialize)]
     struct Hints<'a> {
         #[serde(borrow)]
@@ -28,15 +78,67 @@ pub fn extract_request_hints(body: &[u8]) -> Option<RequestHints> {
         #[serde(alias = "modelId", borrow)]
         model_id: Option<Cow<'a, str>>,
         stream: Option<bool>,
+        messages: Option<Value>,
+        contents: Option<Value>,
+        input: Option<Value>,
+        encoding_format: Option<Value>,
+        dimensions: Option<Value>,
+        store: Option<bool>,
+        metadata: Option<Value>,
     }
 
     let hints: Hints = crate::serde_json::from_slice(body).ok()?;
+    let kind = detect_request_kind(
+        hints.messages.as_ref(),
+        hints.contents.as_ref(),
+        hints.input.as_ref(),
+        hints.encoding_format.is_some() || hints.dimensions.is_some(),
+    );
+    let metadata_hint = metadata_key.and_then(|key| {
+        hints
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    });
     Some(RequestHints {
         model: hints.model.or(hints.model_id).map(|s| s.into_owned()),
         stream: hints.stream.unwrap_or(false),
+        kind,
+        store: hints.store.unwrap_or(false),
+        metadata_hint,
     })
 }
 
+/// Infer [`RequestKind`] from the presence/shape of the fields providers use
+/// to carry a request's content.
+///
+/// `messages`/`contents` always wins (chat). Otherwise, an `input` field is
+/// an embeddings request when it's a plain string or an array of plain
+/// strings, or when an embeddings-only field like
+/// `encoding_format`/`dimensions` is present (whatever the shape of
+/// `input`), and a Responses request in every other case.
+fn detect_request_kind(
+    messages: Option<&Value>,
+    contents: Option<&Value>,
+    input: Option<&Value>,
+    has_embedding_only_field: bool,
+) -> RequestKind {
+    if messages.is_some() || contents.is_some() {
+        return RequestKind::Chat;
+    }
+    let Some(input) = input else {
+        return RequestKind::Chat;
+    };
+    match input {
+        Value::String(_) => RequestKind::Embeddings,
+        Value::Array(items) if items.iter().all(|item| item.is_string()) => RequestKind::Embeddings,
+        _ if has_embedding_only_field => RequestKind::Embeddings,
+        _ => RequestKind::Responses,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +171,81 @@ mod tests {
         let body = b"not json";
         assert!(extract_request_hints(body).is_none());
     }
+
+    #[test]
+    fn extract_hints_kind_defaults_to_chat_for_messages() {
+        let body = br#"{"model": "gpt-4", "messages": [{"role": "user", "content": "hi"}]}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert_eq!(hints.kind, RequestKind::Chat);
+    }
+
+    #[test]
+    fn extract_hints_kind_detects_google_contents_as_chat() {
+        let body = br#"{"contents": [{"role": "user", "parts": [{"text": "hi"}]}]}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert_eq!(hints.kind, RequestKind::Chat);
+    }
+
+    #[test]
+    fn extract_hints_kind_detects_embeddings_single_input() {
+        let body = br#"{"model": "text-embedding-3-small", "input": "hello world"}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert_eq!(hints.kind, RequestKind::Embeddings);
+    }
+
+    #[test]
+    fn extract_hints_kind_detects_embeddings_batch_input() {
+        let body = br#"{"model": "text-embedding-3-small", "input": ["a", "b"], "encoding_format": "float"}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert_eq!(hints.kind, RequestKind::Embeddings);
+    }
+
+    #[test]
+    fn extract_hints_kind_detects_responses_item_array() {
+        let body = br#"{"model": "o3-pro", "input": [{"role": "user", "content": "hi"}]}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert_eq!(hints.kind, RequestKind::Responses);
+    }
+
+    #[test]
+    fn extract_hints_kind_without_messages_or_input_defaults_to_chat() {
+        let body = br#"{"model": "gpt-4"}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert_eq!(hints.kind, RequestKind::Chat);
+    }
+
+    #[test]
+    fn extract_hints_detects_store_enabled() {
+        let body = br#"{"model": "gpt-4o-mini", "messages": [], "store": true}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert!(hints.store);
+    }
+
+    #[test]
+    fn extract_hints_store_defaults_to_false() {
+        let body = br#"{"model": "gpt-4o-mini", "messages": []}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert!(!hints.store);
+    }
+
+    #[test]
+    fn extract_hints_metadata_key_extracts_matching_value() {
+        let body = br#"{"model": "gpt-4o-mini", "messages": [], "metadata": {"tier": "premium"}}"#;
+        let hints = extract_request_hints_with_metadata_key(body, Some("tier")).unwrap();
+        assert_eq!(hints.metadata_hint, Some("premium".to_string()));
+    }
+
+    #[test]
+    fn extract_hints_metadata_key_none_when_key_absent() {
+        let body = br#"{"model": "gpt-4o-mini", "messages": [], "metadata": {"tier": "premium"}}"#;
+        let hints = extract_request_hints_with_metadata_key(body, Some("route_hint")).unwrap();
+        assert_eq!(hints.metadata_hint, None);
+    }
+
+    #[test]
+    fn extract_hints_metadata_key_none_when_not_requested() {
+        let body = br#"{"model": "gpt-4o-mini", "messages": [], "metadata": {"tier": "premium"}}"#;
+        let hints = extract_request_hints(body).unwrap();
+        assert_eq!(hints.metadata_hint, None);
+    }
 }
@@ -29,25 +29,33 @@ pub mod python;
 // ============================================================================
 
 // Re-export extraction functions
-pub use extraction::{extract_request_hints, RequestHints};
+pub use extraction::{
+    extract_request_hints, extract_request_hints_with_metadata_key, RequestHints, RequestKind,
+};
 
 // Re-export capabilities
 pub use capabilities::ProviderFormat;
 
 // Re-export key processing functions (bytes-based API)
 pub use processing::{
-    extract_model, normalize_json_lone_surrogate_escapes, parse_json, parse_json_body,
-    parse_json_value, parse_stream_event, request_to_universal, response_to_universal,
-    sanitize_payload, transform_request, transform_response, transform_stream_chunk,
-    ParsedJsonBody, ParsedStreamEvent, RequestTransformResult, ResponseTransformResult,
-    StreamOutputChunk, StreamTransformSession, TransformError, TransformResult,
+    canonicalize_payload, extract_model, from_std_value, normalize_json_lone_surrogate_escapes,
+    parse_json, parse_json_body, parse_json_value, parse_stream_event, parse_stream_event_borrowed,
+    request_to_universal, response_to_universal, sanitize_payload, to_std_value,
+    transform_embedding_request, transform_embedding_response, transform_request,
+    transform_request_auto, transform_request_from_value, transform_request_with_config,
+    transform_request_with_limit, transform_response, transform_response_with_limit,
+    transform_stream_chunk, AppliedNormalization, IntoLinguaJson, ParsedJsonBody,
+    ParsedStreamEvent, ParsedStreamEventBorrowed, RequestTransformResult, ResponseTransformResult,
+    StreamOutputChunk, StreamTransformSession, TransformConfig, TransformError, TransformResult,
 };
 
 // Re-export universal types
 pub use universal::{
-    FinishReason, Message, ParsableResponseInfo, ResponseRequirement, UniversalParams,
-    UniversalRequest, UniversalResponse, UniversalStreamChoice, UniversalStreamChunk,
-    UniversalStreamDelta, UniversalToolCallDelta, UniversalToolFunctionDelta, UniversalUsage,
+    FinishReason, Message, ParsableResponseInfo, ResponseRequirement, UniversalEmbeddingRequest,
+    UniversalEmbeddingResponse, UniversalEmbeddingUsage, UniversalError, UniversalParams,
+    UniversalRequest, UniversalRequestBuilder, UniversalResponse, UniversalStreamChoice,
+    UniversalStreamChunk, UniversalStreamDelta, UniversalToolCallDelta, UniversalToolFunctionDelta,
+    UniversalUsage,
 };
 
 // Re-export bedrock-anthropic model utilities for router integration
@@ -16,8 +16,8 @@ use std::sync::LazyLock;
 
 use crate::capabilities::ProviderFormat;
 use crate::processing::transform::TransformError;
-use crate::serde_json::{Map, Number, Value};
-use crate::universal::{UniversalRequest, UniversalResponse, UniversalStreamChunk};
+use crate::serde_json::{json, Map, Number, Value};
+use crate::universal::{UniversalError, UniversalRequest, UniversalResponse, UniversalStreamChunk};
 
 /// Trait for provider-specific request and response handling.
 ///
@@ -71,6 +71,19 @@ pub trait ProviderAdapter: Send + Sync {
     /// This builds a complete request payload in the provider's format.
     fn request_from_universal(&self, req: &UniversalRequest) -> Result<Value, TransformError>;
 
+    /// Same as [`Self::request_from_universal`], but for callers that already
+    /// trust `req` to be well-formed (e.g. it was built by internal code
+    /// rather than parsed from a wire payload). Adapters may skip validation
+    /// that exists purely to catch a hand-written client's mistakes. Defaults
+    /// to the checked path, since most adapters' validation is cheap enough
+    /// that a separate fast path isn't worth the extra surface.
+    fn request_from_universal_unchecked(
+        &self,
+        req: &UniversalRequest,
+    ) -> Result<Value, TransformError> {
+        self.request_from_universal(req)
+    }
+
     // =========================================================================
     // Response handling
     // =========================================================================
@@ -138,6 +151,75 @@ pub trait ProviderAdapter: Send + Sync {
             self.display_name().to_string(),
         ))
     }
+
+    // =========================================================================
+    // Error handling
+    // =========================================================================
+
+    /// Convert a provider-specific error response body to the universal error envelope.
+    ///
+    /// Default implementation assumes an OpenAI-shaped `{"error": {...}}` body.
+    /// Providers with a different error shape (e.g. Anthropic) should override this.
+    fn error_to_universal(&self, payload: Value) -> Result<UniversalError, TransformError> {
+        default_error_to_universal(&payload)
+    }
+
+    /// Convert a universal error to this provider's error response shape.
+    ///
+    /// Default implementation produces an OpenAI-shaped `{"error": {...}}` body.
+    /// Providers with a different error shape (e.g. Anthropic) should override this.
+    fn error_from_universal(&self, err: &UniversalError) -> Value {
+        default_error_from_universal(err)
+    }
+
+    // =========================================================================
+    // Validation
+    // =========================================================================
+
+    /// Round-trips a handful of representative requests through
+    /// [`Self::request_from_universal`] and [`Self::request_to_universal`] and
+    /// checks that no field is lost or changed along the way.
+    ///
+    /// Useful as a cheap sanity check when registering a new or third-party
+    /// adapter - a real provider's format may still need dedicated snapshot
+    /// tests, but this catches an adapter that drops messages or parameters
+    /// outright. Default implementation covers the common request shapes;
+    /// override if your adapter needs different or additional coverage.
+    fn self_test(&self) -> Result<(), Vec<String>> {
+        crate::processing::self_test::self_test(self)
+    }
+}
+
+/// Parse an OpenAI-shaped `{"error": {"message", "type", "code", "param"}}` body
+/// into the universal error envelope. Used as the default `error_to_universal`
+/// for providers that don't override it.
+pub fn default_error_to_universal(payload: &Value) -> Result<UniversalError, TransformError> {
+    let error = payload.get("error").unwrap_or(payload);
+    let message = error
+        .get("message")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    Ok(UniversalError {
+        message,
+        error_type: error.get("type").and_then(Value::as_str).map(String::from),
+        code: error.get("code").and_then(Value::as_str).map(String::from),
+        param: error.get("param").and_then(Value::as_str).map(String::from),
+    })
+}
+
+/// Build an OpenAI-shaped `{"error": {"message", "type", "code", "param"}}` body
+/// from the universal error envelope. Used as the default `error_from_universal`
+/// for providers that don't override it.
+pub fn default_error_from_universal(err: &UniversalError) -> Value {
+    json!({
+        "error": {
+            "message": err.message,
+            "type": err.error_type,
+            "code": err.code,
+            "param": err.param,
+        }
+    })
 }
 
 // ============================================================================
@@ -270,4 +352,47 @@ mod tests {
         let a2 = adapter_for_format(ProviderFormat::ChatCompletions).unwrap();
         assert!(std::ptr::eq(a1, a2));
     }
+
+    /// Whether `ADAPTERS` is expected to carry an entry for `format`, given
+    /// the features enabled in this build. This match has no wildcard arm on
+    /// purpose: adding a `ProviderFormat` variant without deciding here
+    /// whether it should be wired into the adapter registry is a compile
+    /// error, not a silently-missing test case.
+    fn expects_registered_adapter(format: ProviderFormat) -> bool {
+        match format {
+            ProviderFormat::ChatCompletions => cfg!(feature = "openai"),
+            ProviderFormat::Anthropic => cfg!(feature = "anthropic"),
+            ProviderFormat::Google => cfg!(feature = "google"),
+            ProviderFormat::Responses => cfg!(feature = "openai"),
+            ProviderFormat::BedrockAnthropic => cfg!(feature = "anthropic"),
+            ProviderFormat::VertexAnthropic => cfg!(feature = "anthropic"),
+            ProviderFormat::Converse => cfg!(feature = "bedrock"),
+            // Mistral is handled as an OpenAI-compatible ChatCompletions
+            // payload at the router layer; lingua has no dedicated adapter.
+            ProviderFormat::Mistral => false,
+            // Placeholder for undetectable input - never backed by an adapter.
+            ProviderFormat::Unknown => false,
+        }
+    }
+
+    #[test]
+    fn test_adapter_for_format_matches_registry_for_every_format() {
+        for format in [
+            ProviderFormat::ChatCompletions,
+            ProviderFormat::Anthropic,
+            ProviderFormat::Google,
+            ProviderFormat::Mistral,
+            ProviderFormat::Converse,
+            ProviderFormat::Responses,
+            ProviderFormat::BedrockAnthropic,
+            ProviderFormat::VertexAnthropic,
+            ProviderFormat::Unknown,
+        ] {
+            assert_eq!(
+                adapter_for_format(format).is_some(),
+                expects_registered_adapter(format),
+                "adapter_for_format({format:?}) did not match expected registration"
+            );
+        }
+    }
 }
@@ -35,7 +35,7 @@ fn hash_message(msg: &Message) -> u64 {
             "developer".hash(&mut hasher);
             hash_user_content(content, &mut hasher);
         }
-        Message::User { content } => {
+        Message::User { content, .. } => {
             "user".hash(&mut hasher);
             hash_user_content(content, &mut hasher);
         }
@@ -137,6 +137,10 @@ fn hash_assistant_content(content: &AssistantContent, hasher: &mut DefaultHasher
                         "text".hash(hasher);
                         hash_text_content_part(text_part, hasher);
                     }
+                    AssistantContentPart::Refusal { text } => {
+                        "refusal".hash(hasher);
+                        text.hash(hasher);
+                    }
                     AssistantContentPart::File {
                         data,
                         filename,
@@ -377,6 +381,52 @@ pub fn deduplicate_messages(messages: Vec<Message>) -> Vec<Message> {
     result
 }
 
+/// Returns `true` if a message carries no meaningful content and should be dropped by
+/// [`prune_empty_messages`].
+///
+/// `Message::Tool` (tool results) and `Message::AdditionalTools` are never considered
+/// empty, even if their payload happens to be an empty vec - they carry structural
+/// meaning independent of text content.
+fn is_message_empty(msg: &Message) -> bool {
+    match msg {
+        Message::System { content }
+        | Message::Developer { content }
+        | Message::User { content, .. } => is_user_content_empty(content),
+        Message::Assistant { content, .. } => is_assistant_content_empty(content),
+        Message::Tool { .. } | Message::AdditionalTools { .. } => false,
+    }
+}
+
+fn is_user_content_empty(content: &UserContent) -> bool {
+    match content {
+        UserContent::String(text) => text.is_empty(),
+        UserContent::Array(parts) => parts.is_empty(),
+    }
+}
+
+fn is_assistant_content_empty(content: &AssistantContent) -> bool {
+    match content {
+        AssistantContent::String(text) => text.is_empty(),
+        AssistantContent::Array(parts) => parts.is_empty(),
+    }
+}
+
+/// Removes messages with no content and no tool calls (empty assistant turns, blank user
+/// turns, etc.), preserving `Message::Tool` results unconditionally.
+///
+/// Spans imported from wrapper frameworks sometimes surface an empty assistant message
+/// (e.g. a turn where the model only emitted a tool call that got recorded separately, or
+/// a placeholder turn with `content: ""`). Left in place, these break providers like
+/// Anthropic that require strict user/assistant alternation. This pass is opt-in - call it
+/// explicitly (for example after [`deduplicate_messages`]) rather than baking it into
+/// every import path, since not every caller wants empty turns silently dropped.
+pub fn prune_empty_messages(messages: Vec<Message>) -> Vec<Message> {
+    messages
+        .into_iter()
+        .filter(|msg| !is_message_empty(msg))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -390,12 +440,15 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("hello".to_string()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("hello".to_string()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("world".to_string()),
+                name: None,
             },
         ];
 
@@ -408,6 +461,7 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("foo".to_string()),
+                name: None,
             },
             Message::User {
                 content: UserContent::Array(vec![UserContentPart::Text(TextContentPart {
@@ -416,9 +470,11 @@ mod tests {
                     cache_control: None,
                     provider_options: None,
                 })]),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("bar".to_string()),
+                name: None,
             },
         ];
 
@@ -428,6 +484,7 @@ mod tests {
         // Check that first "foo" was kept (as String, not Array)
         if let Message::User {
             content: UserContent::String(s),
+            ..
         } = &result[0]
         {
             assert_eq!(s, "foo");
@@ -439,7 +496,8 @@ mod tests {
         assert!(matches!(
             result[0],
             Message::User {
-                content: UserContent::String(_)
+                content: UserContent::String(_),
+                name: None
             }
         ));
     }
@@ -449,6 +507,7 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("foo".to_string()),
+                name: None,
             },
             Message::User {
                 content: UserContent::Array(vec![UserContentPart::Text(TextContentPart {
@@ -460,6 +519,7 @@ mod tests {
                     }),
                     provider_options: None,
                 })]),
+                name: None,
             },
         ];
 
@@ -469,7 +529,7 @@ mod tests {
             &result[1],
             Message::User {
                 content: UserContent::Array(parts)
-            } if matches!(
+            , name: None} if matches!(
                 parts.first(),
                 Some(UserContentPart::Text(TextContentPart {
                     cache_control: Some(_),
@@ -485,6 +545,7 @@ mod tests {
             Message::Assistant {
                 content: AssistantContent::String("response".to_string()),
                 id: None,
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::Array(vec![AssistantContentPart::Text(
@@ -496,6 +557,7 @@ mod tests {
                     },
                 )]),
                 id: None,
+                name: None,
             },
         ];
 
@@ -518,6 +580,7 @@ mod tests {
             Message::Assistant {
                 content: AssistantContent::String("response".to_string()),
                 id: None,
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::Array(vec![AssistantContentPart::Text(
@@ -532,6 +595,7 @@ mod tests {
                     },
                 )]),
                 id: None,
+                name: None,
             },
         ];
 
@@ -557,16 +621,20 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("first".to_string()),
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::String("second".to_string()),
                 id: None,
+                name: None,
             },
             Message::User {
                 content: UserContent::String("third".to_string()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("first".to_string()),
+                name: None,
             },
         ];
 
@@ -576,6 +644,7 @@ mod tests {
         // Verify order
         if let Message::User {
             content: UserContent::String(s),
+            ..
         } = &result[0]
         {
             assert_eq!(s, "first");
@@ -589,6 +658,7 @@ mod tests {
         }
         if let Message::User {
             content: UserContent::String(s),
+            ..
         } = &result[2]
         {
             assert_eq!(s, "third");
@@ -600,10 +670,12 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("same content".to_string()),
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::String("same content".to_string()),
                 id: None,
+                name: None,
             },
             Message::System {
                 content: UserContent::String("same content".to_string()),
@@ -625,6 +697,7 @@ mod tests {
     fn test_dedup_single_message() {
         let messages = vec![Message::User {
             content: UserContent::String("only one".to_string()),
+            name: None,
         }];
 
         let result = deduplicate_messages(messages);
@@ -636,12 +709,15 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("same".to_string()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("same".to_string()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("same".to_string()),
+                name: None,
             },
         ];
 
@@ -667,6 +743,7 @@ mod tests {
                         provider_options: None,
                     }),
                 ]),
+                name: None,
             },
             Message::User {
                 content: UserContent::Array(vec![
@@ -683,6 +760,7 @@ mod tests {
                         provider_options: None,
                     }),
                 ]),
+                name: None,
             },
         ];
 
@@ -700,6 +778,7 @@ mod tests {
                     cache_control: None,
                     provider_options: None,
                 })]),
+                name: None,
             },
             Message::User {
                 content: UserContent::Array(vec![UserContentPart::Text(TextContentPart {
@@ -710,6 +789,7 @@ mod tests {
                         options: serde_json::Map::new(),
                     }),
                 })]),
+                name: None,
             },
         ];
 
@@ -807,6 +887,7 @@ mod tests {
                     provider_executed: None,
                 }]),
                 id: None,
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::Array(vec![AssistantContentPart::ToolCall {
@@ -822,6 +903,7 @@ mod tests {
                     provider_executed: None,
                 }]),
                 id: None,
+                name: None,
             },
         ];
 
@@ -846,6 +928,7 @@ mod tests {
                     provider_executed: None,
                 }]),
                 id: None,
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::Array(vec![AssistantContentPart::ToolCall {
@@ -861,6 +944,7 @@ mod tests {
                     provider_executed: None,
                 }]),
                 id: None,
+                name: None,
             },
         ];
 
@@ -932,6 +1016,7 @@ mod tests {
                     },
                 }),
             })]),
+            name: None,
         };
 
         let messages = vec![original.clone()];
@@ -942,6 +1027,7 @@ mod tests {
         // Verify it's still an Array, not converted to String
         if let Message::User {
             content: UserContent::Array(parts),
+            ..
         } = &result[0]
         {
             assert_eq!(parts.len(), 1);
@@ -960,4 +1046,60 @@ mod tests {
             panic!("Expected Array content to be preserved");
         }
     }
+
+    #[test]
+    fn test_prune_empty_messages_removes_empty_assistant_turn() {
+        // An empty assistant turn sandwiched between two user turns should be dropped,
+        // leaving a valid alternating user/assistant sequence for providers like
+        // Anthropic that require strict role alternation.
+        let messages = vec![
+            Message::User {
+                content: UserContent::String("hi".to_string()),
+                name: None,
+            },
+            Message::Assistant {
+                content: AssistantContent::String(String::new()),
+                id: None,
+                name: None,
+            },
+            Message::User {
+                content: UserContent::String("still there?".to_string()),
+                name: None,
+            },
+        ];
+
+        let result = prune_empty_messages(messages);
+
+        assert_eq!(result.len(), 2);
+        assert!(matches!(result[0], Message::User { .. }));
+        assert!(matches!(result[1], Message::User { .. }));
+    }
+
+    #[test]
+    fn test_prune_empty_messages_preserves_tool_results() {
+        let messages = vec![Message::Tool { content: vec![] }];
+
+        let result = prune_empty_messages(messages);
+
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn test_prune_empty_messages_keeps_nonempty_messages() {
+        let messages = vec![
+            Message::User {
+                content: UserContent::String("hi".to_string()),
+                name: None,
+            },
+            Message::Assistant {
+                content: AssistantContent::String("hello".to_string()),
+                id: None,
+                name: None,
+            },
+        ];
+
+        let result = prune_empty_messages(messages.clone());
+
+        assert_eq!(result.len(), messages.len());
+    }
 }
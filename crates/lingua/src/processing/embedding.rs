@@ -0,0 +1,194 @@
+/*!
+Minimal transform path for embedding requests/responses.
+
+Unlike [`crate::processing::transform`], which auto-detects the source
+format for chat/messages payloads, embedding bodies are too little
+differentiated (OpenAI's request is `{model, input}`; nothing else in this
+module infers a format from that alone) so callers must supply both the
+source and target [`ProviderFormat`] explicitly. Only OpenAI and Google are
+supported; other formats return [`TransformError::UnsupportedSourceFormat`]
+or [`TransformError::UnsupportedTargetFormat`].
+*/
+
+use bytes::Bytes;
+
+use crate::capabilities::ProviderFormat;
+use crate::processing::transform::TransformError;
+use crate::serde_json;
+use crate::universal::{UniversalEmbeddingRequest, UniversalEmbeddingResponse};
+
+fn universal_embedding_request_from_bytes(
+    input: &Bytes,
+    source_format: ProviderFormat,
+) -> Result<UniversalEmbeddingRequest, TransformError> {
+    match source_format {
+        #[cfg(feature = "openai")]
+        ProviderFormat::ChatCompletions => {
+            let request: crate::providers::openai::OpenAIEmbeddingRequest =
+                serde_json::from_slice(input)
+                    .map_err(|e| TransformError::DeserializationFailed(e.to_string()))?;
+            Ok(crate::providers::openai::openai_embedding_request_to_universal(request))
+        }
+        #[cfg(feature = "google")]
+        ProviderFormat::Google => {
+            let request: crate::providers::google::GoogleBatchEmbedContentsRequest =
+                serde_json::from_slice(input)
+                    .map_err(|e| TransformError::DeserializationFailed(e.to_string()))?;
+            crate::providers::google::google_embedding_request_to_universal(request)
+                .map_err(|e| TransformError::ToUniversalFailed(e.to_string()))
+        }
+        other => Err(TransformError::UnsupportedSourceFormat(other)),
+    }
+}
+
+fn universal_embedding_request_to_bytes(
+    request: &UniversalEmbeddingRequest,
+    target_format: ProviderFormat,
+) -> Result<Bytes, TransformError> {
+    match target_format {
+        #[cfg(feature = "openai")]
+        ProviderFormat::ChatCompletions => {
+            let request = crate::providers::openai::universal_to_openai_embedding_request(request);
+            serde_json::to_vec(&request)
+                .map(Bytes::from)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))
+        }
+        #[cfg(feature = "google")]
+        ProviderFormat::Google => {
+            let request = crate::providers::google::universal_to_google_embedding_request(request);
+            serde_json::to_vec(&request)
+                .map(Bytes::from)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))
+        }
+        other => Err(TransformError::UnsupportedTargetFormat(other)),
+    }
+}
+
+/// Convert an embedding request from `source_format` to `target_format`.
+///
+/// Returns the input unchanged (as `Bytes`, no reparsing) when the two
+/// formats match.
+pub fn transform_embedding_request(
+    input: Bytes,
+    source_format: ProviderFormat,
+    target_format: ProviderFormat,
+) -> Result<Bytes, TransformError> {
+    if source_format == target_format {
+        return Ok(input);
+    }
+    let universal = universal_embedding_request_from_bytes(&input, source_format)?;
+    universal_embedding_request_to_bytes(&universal, target_format)
+}
+
+fn universal_embedding_response_from_bytes(
+    input: &Bytes,
+    source_format: ProviderFormat,
+) -> Result<UniversalEmbeddingResponse, TransformError> {
+    match source_format {
+        #[cfg(feature = "openai")]
+        ProviderFormat::ChatCompletions => {
+            let response: crate::providers::openai::OpenAIEmbeddingResponse =
+                serde_json::from_slice(input)
+                    .map_err(|e| TransformError::DeserializationFailed(e.to_string()))?;
+            Ok(crate::providers::openai::openai_embedding_response_to_universal(response))
+        }
+        #[cfg(feature = "google")]
+        ProviderFormat::Google => {
+            let response: crate::providers::google::GoogleBatchEmbedContentsResponse =
+                serde_json::from_slice(input)
+                    .map_err(|e| TransformError::DeserializationFailed(e.to_string()))?;
+            Ok(crate::providers::google::google_embedding_response_to_universal(response))
+        }
+        other => Err(TransformError::UnsupportedSourceFormat(other)),
+    }
+}
+
+fn universal_embedding_response_to_bytes(
+    response: &UniversalEmbeddingResponse,
+    target_format: ProviderFormat,
+) -> Result<Bytes, TransformError> {
+    match target_format {
+        #[cfg(feature = "openai")]
+        ProviderFormat::ChatCompletions => {
+            let response =
+                crate::providers::openai::universal_to_openai_embedding_response(response)
+                    .map_err(|e| TransformError::FromUniversalFailed(e.to_string()))?;
+            serde_json::to_vec(&response)
+                .map(Bytes::from)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))
+        }
+        #[cfg(feature = "google")]
+        ProviderFormat::Google => {
+            let response =
+                crate::providers::google::universal_to_google_embedding_response(response);
+            serde_json::to_vec(&response)
+                .map(Bytes::from)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))
+        }
+        other => Err(TransformError::UnsupportedTargetFormat(other)),
+    }
+}
+
+/// Convert an embedding response from `source_format` to `target_format`.
+///
+/// Returns the input unchanged (as `Bytes`, no reparsing) when the two
+/// formats match.
+pub fn transform_embedding_response(
+    input: Bytes,
+    source_format: ProviderFormat,
+    target_format: ProviderFormat,
+) -> Result<Bytes, TransformError> {
+    if source_format == target_format {
+        return Ok(input);
+    }
+    let universal = universal_embedding_response_from_bytes(&input, source_format)?;
+    universal_embedding_response_to_bytes(&universal, target_format)
+}
+
+#[cfg(all(test, feature = "openai", feature = "google"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transform_embedding_request_openai_to_google_maps_input_to_content() {
+        let input = Bytes::from_static(
+            br#"{"model":"text-embedding-004","input":["hello","world"],"dimensions":16}"#,
+        );
+        let output = transform_embedding_request(
+            input,
+            ProviderFormat::ChatCompletions,
+            ProviderFormat::Google,
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        let requests = value["requests"].as_array().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0]["model"], "models/text-embedding-004");
+        assert_eq!(requests[0]["content"]["parts"][0]["text"], "hello");
+        assert_eq!(requests[0]["outputDimensionality"], 16);
+    }
+
+    #[test]
+    fn transform_embedding_response_same_format_is_passthrough() {
+        let input = Bytes::from_static(br#"{"embeddings":[{"values":[0.1,0.2]}]}"#);
+        let output = transform_embedding_response(
+            input.clone(),
+            ProviderFormat::Google,
+            ProviderFormat::Google,
+        )
+        .unwrap();
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn transform_embedding_request_same_format_is_passthrough() {
+        let input = Bytes::from_static(br#"{"model":"m","input":["a"]}"#);
+        let output = transform_embedding_request(
+            input.clone(),
+            ProviderFormat::ChatCompletions,
+            ProviderFormat::ChatCompletions,
+        )
+        .unwrap();
+        assert_eq!(output, input);
+    }
+}
@@ -358,6 +358,7 @@ fn parse_lenient_message_item(item: &Value) -> Option<Message> {
     match role_str {
         "user" => Some(Message::User {
             content: parse_user_content(content_value)?,
+            name: None,
         }),
         "system" => Some(Message::System {
             content: parse_user_content(content_value)?,
@@ -368,6 +369,7 @@ fn parse_lenient_message_item(item: &Value) -> Option<Message> {
         "assistant" => Some(Message::Assistant {
             content: parse_assistant_content(content_value)?,
             id: None,
+            name: None,
         }),
         "tool" => parse_lenient_tool_message(item, content_value),
         _ => None,
@@ -618,10 +620,23 @@ pub fn import_messages_from_spans(spans: Vec<Span>) -> Vec<Message> {
     for mut span in spans {
         let mut span_messages = Vec::new();
 
+        // Trace replay needs stable per-turn identity, so carry the span's own
+        // id onto the assistant message built from a plain-string output (the
+        // only message variant with an `id` slot). Messages converted from a
+        // structured output keep whatever id the conversion gives them. There's
+        // no field to carry a timestamp on, so `created_at`/similar span fields
+        // aren't preserved here.
+        let span_id = span
+            .other
+            .get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
         match span.input.take() {
             Some(Value::String(input_text)) => {
                 span_messages.push(Message::User {
                     content: UserContent::String(input_text),
+                    name: None,
                 });
             }
             Some(input) => {
@@ -648,7 +663,8 @@ pub fn import_messages_from_spans(spans: Vec<Span>) -> Vec<Message> {
             Some(Value::String(output_text)) if !output_text.is_empty() => {
                 messages.push(Message::Assistant {
                     content: AssistantContent::String(output_text),
-                    id: None,
+                    id: span_id,
+                    name: None,
                 });
             }
             Some(output) => {
@@ -666,3 +682,43 @@ pub fn import_and_deduplicate_messages(spans: Vec<Span>) -> Vec<Message> {
     let messages = import_messages_from_spans(spans);
     super::dedup::deduplicate_messages(messages)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_messages_from_spans_preserves_span_id_on_assistant_message() {
+        let span: Span = serde_json::from_value(serde_json::json!({
+            "id": "span-123",
+            "input": "Hello there",
+            "output": "Hi! How can I help?",
+        }))
+        .unwrap();
+
+        let messages = import_messages_from_spans(vec![span]);
+
+        let assistant_id = messages.iter().find_map(|message| match message {
+            Message::Assistant { id, .. } => Some(id.clone()),
+            _ => None,
+        });
+        assert_eq!(assistant_id, Some(Some("span-123".to_string())));
+    }
+
+    #[test]
+    fn test_import_messages_from_spans_without_id_leaves_id_none() {
+        let span: Span = serde_json::from_value(serde_json::json!({
+            "input": "Hello there",
+            "output": "Hi! How can I help?",
+        }))
+        .unwrap();
+
+        let messages = import_messages_from_spans(vec![span]);
+
+        let assistant_id = messages.iter().find_map(|message| match message {
+            Message::Assistant { id, .. } => Some(id.clone()),
+            _ => None,
+        });
+        assert_eq!(assistant_id, Some(None));
+    }
+}
@@ -334,10 +334,12 @@ fn parse_message(message: AISDKMessageCompat) -> Option<Message> {
         }),
         "user" => Some(Message::User {
             content: parse_user_content(message.content)?,
+            name: None,
         }),
         "assistant" => Some(Message::Assistant {
             content: parse_assistant_content(message.content)?,
             id: None,
+            name: None,
         }),
         "tool" => {
             let content = parse_tool_content(message.content).or_else(|| {
@@ -386,6 +388,7 @@ fn try_parse_prompt_value(value: &Value) -> Option<Vec<Message>> {
     match value {
         Value::String(text) => Some(vec![Message::User {
             content: UserContent::String(text.clone()),
+            name: None,
         }]),
         Value::Array(_) => parse_message_sequence(value),
         _ => None,
@@ -458,7 +461,11 @@ fn has_ai_sdk_output_signal(obj: &serde_json::Map<String, Value>) -> bool {
 fn build_assistant_message_from_fields(obj: &serde_json::Map<String, Value>) -> Option<Message> {
     if let Some(content) = obj.get("content") {
         let content = parse_assistant_content(content.clone())?;
-        return Some(Message::Assistant { content, id: None });
+        return Some(Message::Assistant {
+            content,
+            id: None,
+            name: None,
+        });
     }
 
     let mut parts = Vec::new();
@@ -493,6 +500,7 @@ fn build_assistant_message_from_fields(obj: &serde_json::Map<String, Value>) ->
             return Some(Message::Assistant {
                 content: AssistantContent::String(text),
                 id: None,
+                name: None,
             });
         }
         return None;
@@ -508,7 +516,11 @@ fn build_assistant_message_from_fields(obj: &serde_json::Map<String, Value>) ->
         AssistantContent::Array(parts)
     };
 
-    Some(Message::Assistant { content, id: None })
+    Some(Message::Assistant {
+        content,
+        id: None,
+        name: None,
+    })
 }
 
 fn parse_step_message(step: &Value) -> Option<Message> {
@@ -589,7 +601,11 @@ fn parse_step_message(step: &Value) -> Option<Message> {
             } else {
                 AssistantContent::Array(assistant_parts)
             };
-            return Some(Message::Assistant { content, id: None });
+            return Some(Message::Assistant {
+                content,
+                id: None,
+                name: None,
+            });
         }
     }
 
@@ -485,10 +485,12 @@ fn convert_message(normalized: NormalizedLangChainMessage) -> Option<Message> {
         }),
         LangChainRole::User => Some(Message::User {
             content: parse_user_content(normalized.content)?,
+            name: None,
         }),
         LangChainRole::Assistant | LangChainRole::Function => Some(Message::Assistant {
             content: parse_assistant_content(normalized.content, normalized.tool_calls)?,
             id: None,
+            name: None,
         }),
         LangChainRole::Tool => parse_tool_message_content(
             normalized.content,
@@ -252,7 +252,11 @@ fn flush_assistant_parts(
         AssistantContent::Array(std::mem::take(assistant_parts))
     };
 
-    messages.push(Message::Assistant { content, id: None });
+    messages.push(Message::Assistant {
+        content,
+        id: None,
+        name: None,
+    });
 }
 
 fn convert_message_parts(
@@ -279,13 +283,19 @@ fn convert_message_parts(
             "user-prompt" | "retry-prompt" => {
                 flush_assistant_parts(&mut messages, &mut assistant_parts);
                 let content = parse_user_content(part.content?)?;
-                messages.push(Message::User { content });
+                messages.push(Message::User {
+                    content,
+                    name: None,
+                });
             }
             "text" => match kind {
                 PydanticAIMessageKindCompat::Request => {
                     flush_assistant_parts(&mut messages, &mut assistant_parts);
                     let content = parse_user_content(part.content?)?;
-                    messages.push(Message::User { content });
+                    messages.push(Message::User {
+                        content,
+                        name: None,
+                    });
                 }
                 PydanticAIMessageKindCompat::Response => {
                     if let Some(text) = assistant_text_part(part.content) {
@@ -365,6 +375,7 @@ fn try_parse_wrapper_input(data: &Value) -> Option<Vec<Message>> {
     if let Some(user_prompt) = wrapper.user_prompt {
         messages.push(Message::User {
             content: parse_user_content(user_prompt)?,
+            name: None,
         });
     }
 
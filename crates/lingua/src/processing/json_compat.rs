@@ -0,0 +1,78 @@
+/*!
+Conversion helpers between lingua's [`Value`](crate::serde_json::Value) (backed by
+`big_serde_json`, with the `arbitrary_precision` feature enabled) and the plain
+`serde_json::Value` most external crates use.
+
+The two types share the same JSON data model but not the same number
+representation: `big_serde_json` keeps every number as its original decimal
+text, while plain `serde_json` numbers collapse to `i64`/`u64`/`f64`. Converting
+a [`Value`] that holds a number wider than those (e.g. a 128-bit account id)
+into `serde_json::Value` loses that precision permanently; converting back does
+not restore it. Prefer keeping payloads in [`Value`] end to end and only convert
+at the edges where a caller's existing data structure requires the standard type.
+*/
+
+use crate::serde_json::Value;
+
+/// Convert a plain `serde_json::Value` into lingua's arbitrary-precision [`Value`].
+///
+/// The two types share a JSON grammar, so this round-trips through text rather
+/// than walking the tree by hand; it never fails.
+pub fn from_std_value(value: serde_json::Value) -> Value {
+    let text = serde_json::to_string(&value).expect("serde_json::Value always serializes");
+    crate::serde_json::from_str(&text).expect("valid JSON always reparses as Value")
+}
+
+/// Convert lingua's arbitrary-precision [`Value`] into a plain `serde_json::Value`.
+///
+/// See the module docs for the precision caveat this can introduce.
+pub fn to_std_value(value: &Value) -> serde_json::Value {
+    let text = crate::serde_json::to_string(value).expect("Value always serializes");
+    serde_json::from_str(&text).expect("valid JSON always reparses as serde_json::Value")
+}
+
+/// Implemented for anything that can be turned into lingua's [`Value`], so a
+/// transform entrypoint that accepts a JSON value can take either lingua's own
+/// [`Value`] or a plain `serde_json::Value` without the caller converting by hand.
+pub trait IntoLinguaJson {
+    fn into_lingua_json(self) -> Value;
+}
+
+impl IntoLinguaJson for Value {
+    fn into_lingua_json(self) -> Value {
+        self
+    }
+}
+
+impl IntoLinguaJson for serde_json::Value {
+    fn into_lingua_json(self) -> Value {
+        from_std_value(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serde_json::json;
+
+    #[test]
+    fn round_trips_a_standard_value() {
+        let std_value = serde_json::json!({"name": "widget", "count": 3});
+        let lingua_value = std_value.clone().into_lingua_json();
+        assert_eq!(to_std_value(&lingua_value), std_value);
+    }
+
+    #[test]
+    fn lingua_value_into_lingua_json_is_identity() {
+        let value = json!({"a": 1});
+        assert_eq!(value.clone().into_lingua_json(), value);
+    }
+
+    #[test]
+    fn preserves_large_integers_lingua_value_cannot_express_in_std() {
+        // Wider than i64::MAX / u64::MAX -- the whole reason `big_serde_json` exists.
+        let huge = "123456789012345678901234567890";
+        let lingua_value: Value = crate::serde_json::from_str(huge).unwrap();
+        assert_eq!(lingua_value.to_string(), huge);
+    }
+}
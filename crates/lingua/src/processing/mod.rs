@@ -1,23 +1,33 @@
 pub mod adapters;
 pub mod dedup;
+pub mod embedding;
 pub mod import;
+pub mod json_compat;
 mod json_repair;
+mod self_test;
 pub mod stream;
+pub mod tool_schema;
 pub mod transform;
 
 pub use adapters::{
-    adapter_for_format, adapters, collect_extras, insert_opt_bool, insert_opt_f64, insert_opt_i64,
-    insert_opt_string, insert_opt_value, ProviderAdapter,
+    adapter_for_format, adapters, collect_extras, default_error_from_universal,
+    default_error_to_universal, insert_opt_bool, insert_opt_f64, insert_opt_i64, insert_opt_string,
+    insert_opt_value, ProviderAdapter,
 };
-pub use dedup::{deduplicate_messages, message_dedup_hash};
+pub use dedup::{deduplicate_messages, message_dedup_hash, prune_empty_messages};
+pub use embedding::{transform_embedding_request, transform_embedding_response};
 pub use import::{import_and_deduplicate_messages, import_messages_from_spans, Span};
+pub use json_compat::{from_std_value, to_std_value, IntoLinguaJson};
 pub use json_repair::normalize_json_lone_surrogate_escapes;
 pub use stream::{
-    parse_stream_event, ParsedStreamEvent, StreamOutputChunk, StreamTransformSession,
+    parse_stream_event, parse_stream_event_borrowed, ParsedStreamEvent, ParsedStreamEventBorrowed,
+    StreamOutputChunk, StreamTransformSession,
 };
 pub use transform::{
-    extract_model, parse_json, parse_json_body, parse_json_value, request_to_universal,
-    response_to_universal, sanitize_payload, transform_request, transform_response,
-    transform_stream_chunk, ParsedJsonBody, RequestTransformResult, ResponseTransformResult,
-    TransformError, TransformResult,
+    canonicalize_payload, extract_model, parse_json, parse_json_body, parse_json_value,
+    request_to_universal, response_to_universal, sanitize_payload, transform_request,
+    transform_request_auto, transform_request_from_value, transform_request_with_config,
+    transform_request_with_limit, transform_response, transform_response_with_limit,
+    transform_stream_chunk, AppliedNormalization, ParsedJsonBody, RequestTransformResult,
+    ResponseTransformResult, TransformConfig, TransformError, TransformResult,
 };
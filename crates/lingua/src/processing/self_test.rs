@@ -0,0 +1,252 @@
+/*!
+Round-trip self-check for [`ProviderAdapter`](crate::processing::ProviderAdapter)
+implementations, backing [`ProviderAdapter::self_test`](crate::processing::ProviderAdapter::self_test).
+
+This intentionally reimplements a small, dependency-free version of the
+field-level diff `coverage-report`'s fuzz harness uses to compare a
+provider payload before and after a round trip - `coverage-report` can't
+be a real dependency here since it already depends on `lingua` for its own
+adapter access, and a custom adapter's fidelity should be checkable from
+this crate alone, without pulling in the reporting tool.
+*/
+
+use crate::serde_json::Value;
+use crate::universal::UniversalRequest;
+
+use super::adapters::ProviderAdapter;
+
+/// A plain single-turn exchange, and a turn exercising a system prompt plus
+/// common sampling parameters - enough to catch an adapter that drops a
+/// message, a role, or a widely-used parameter.
+fn representative_requests() -> Vec<UniversalRequest> {
+    vec![
+        UniversalRequest::builder("self-test-model")
+            .user_text("Hello, how are you?")
+            .build(),
+        UniversalRequest::builder("self-test-model")
+            .system("You are a terse assistant.")
+            .user_text("What's 2+2?")
+            .assistant_text("4")
+            .temperature(0.5)
+            .max_tokens(256)
+            .build(),
+    ]
+}
+
+/// Runs `adapter` against [`representative_requests`] and collects one
+/// issue string per lost or changed field across every request that fails
+/// to round-trip cleanly.
+pub(crate) fn self_test(adapter: &dyn ProviderAdapter) -> Result<(), Vec<String>> {
+    let mut issues = Vec::new();
+    for (index, request) in representative_requests().into_iter().enumerate() {
+        if let Err(request_issues) = round_trip(adapter, &request) {
+            issues.extend(
+                request_issues
+                    .into_iter()
+                    .map(|issue| format!("request #{index}: {issue}")),
+            );
+        }
+    }
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+fn round_trip(
+    adapter: &dyn ProviderAdapter,
+    request: &UniversalRequest,
+) -> Result<(), Vec<String>> {
+    let payload = adapter
+        .request_from_universal(request)
+        .map_err(|e| vec![format!("request_from_universal error: {e}")])?;
+    let round_tripped = adapter
+        .request_to_universal(payload)
+        .map_err(|e| vec![format!("request_to_universal error: {e}")])?;
+
+    let before = crate::serde_json::to_value(request).expect("UniversalRequest always serializes");
+    let after =
+        crate::serde_json::to_value(&round_tripped).expect("UniversalRequest always serializes");
+
+    let issues = diff_values(&before, &after, "");
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+/// Minimal recursive diff producing one line per lost, added, or changed
+/// leaf value. Doesn't special-case things a fuller diff would (array
+/// reordering, semantically-equal-but-differently-typed numbers) - good
+/// enough to flag an obviously incomplete adapter.
+fn diff_values(before: &Value, after: &Value, path: &str) -> Vec<String> {
+    match (before, after) {
+        (Value::Object(before_map), Value::Object(after_map)) => {
+            let mut issues = Vec::new();
+            for (key, before_value) in before_map {
+                let field_path = child_path(path, key);
+                match after_map.get(key) {
+                    Some(after_value) => {
+                        issues.extend(diff_values(before_value, after_value, &field_path))
+                    }
+                    None => issues.push(format!("lost field '{field_path}'")),
+                }
+            }
+            for key in after_map.keys() {
+                if !before_map.contains_key(key) {
+                    issues.push(format!("added field '{}'", child_path(path, key)));
+                }
+            }
+            issues
+        }
+        (Value::Array(before_items), Value::Array(after_items)) => {
+            if before_items.len() != after_items.len() {
+                vec![format!(
+                    "changed '{path}': array length {} -> {}",
+                    before_items.len(),
+                    after_items.len()
+                )]
+            } else {
+                before_items
+                    .iter()
+                    .zip(after_items)
+                    .enumerate()
+                    .flat_map(|(i, (b, a))| diff_values(b, a, &format!("{path}[{i}]")))
+                    .collect()
+            }
+        }
+        (before, after) if before == after => Vec::new(),
+        (before, after) => vec![format!("changed '{path}': {before} -> {after}")],
+    }
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capabilities::ProviderFormat;
+    use crate::processing::transform::TransformError;
+    use crate::universal::UniversalResponse;
+
+    /// Passes every message straight through unchanged - a correct, minimal adapter.
+    struct FaithfulAdapter;
+
+    impl ProviderAdapter for FaithfulAdapter {
+        fn format(&self) -> ProviderFormat {
+            ProviderFormat::Unknown
+        }
+
+        fn directory_name(&self) -> &'static str {
+            "faithful"
+        }
+
+        fn display_name(&self) -> &'static str {
+            "Faithful"
+        }
+
+        fn detect_request(&self, _payload: &Value) -> bool {
+            true
+        }
+
+        fn request_to_universal(&self, payload: Value) -> Result<UniversalRequest, TransformError> {
+            crate::serde_json::from_value(payload)
+                .map_err(|e| TransformError::DeserializationFailed(e.to_string()))
+        }
+
+        fn request_from_universal(&self, req: &UniversalRequest) -> Result<Value, TransformError> {
+            crate::serde_json::to_value(req)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))
+        }
+
+        fn detect_response(&self, _payload: &Value) -> bool {
+            true
+        }
+
+        fn response_to_universal(
+            &self,
+            _payload: Value,
+        ) -> Result<UniversalResponse, TransformError> {
+            unimplemented!("not exercised by self_test")
+        }
+
+        fn response_from_universal(
+            &self,
+            _resp: &UniversalResponse,
+        ) -> Result<Value, TransformError> {
+            unimplemented!("not exercised by self_test")
+        }
+    }
+
+    /// Drops every message's content on the way to the wire format - the
+    /// kind of bug `self_test` exists to catch.
+    struct LossyAdapter;
+
+    impl ProviderAdapter for LossyAdapter {
+        fn format(&self) -> ProviderFormat {
+            ProviderFormat::Unknown
+        }
+
+        fn directory_name(&self) -> &'static str {
+            "lossy"
+        }
+
+        fn display_name(&self) -> &'static str {
+            "Lossy"
+        }
+
+        fn detect_request(&self, _payload: &Value) -> bool {
+            true
+        }
+
+        fn request_to_universal(&self, payload: Value) -> Result<UniversalRequest, TransformError> {
+            crate::serde_json::from_value(payload)
+                .map_err(|e| TransformError::DeserializationFailed(e.to_string()))
+        }
+
+        fn request_from_universal(&self, req: &UniversalRequest) -> Result<Value, TransformError> {
+            let mut req = req.clone();
+            req.messages.clear();
+            crate::serde_json::to_value(&req)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))
+        }
+
+        fn detect_response(&self, _payload: &Value) -> bool {
+            true
+        }
+
+        fn response_to_universal(
+            &self,
+            _payload: Value,
+        ) -> Result<UniversalResponse, TransformError> {
+            unimplemented!("not exercised by self_test")
+        }
+
+        fn response_from_universal(
+            &self,
+            _resp: &UniversalResponse,
+        ) -> Result<Value, TransformError> {
+            unimplemented!("not exercised by self_test")
+        }
+    }
+
+    #[test]
+    fn faithful_adapter_passes_self_test() {
+        assert_eq!(self_test(&FaithfulAdapter), Ok(()));
+    }
+
+    #[test]
+    fn lossy_adapter_flags_dropped_messages() {
+        let issues = self_test(&LossyAdapter).expect_err("lossy adapter should fail self-test");
+        assert!(!issues.is_empty());
+        assert!(issues.iter().any(|issue| issue.contains("messages")));
+    }
+}
@@ -13,7 +13,7 @@ use crate::providers::openai::responses_adapter::{
     responses_stream_events_from_universal_with_output_index_offset,
 };
 use crate::serde_json::Value;
-use crate::universal::UniversalStreamChunk;
+use crate::universal::{UniversalStreamChunk, UniversalUsage};
 
 static EMPTY_JSON: Bytes = Bytes::from_static(b"{}");
 static SSE_DATA_PREFIX: &[u8] = b"data: ";
@@ -96,6 +96,12 @@ pub struct StreamTransformSession {
     next_responses_tool_call_index: u32,
     bedrock_tool_call_indexes: BTreeMap<u32, u32>,
     next_bedrock_tool_call_index: u32,
+    // Anthropic `message_start` reports input-token usage up front, while
+    // non-Anthropic targets (OpenAI, etc.) expect usage only on the terminal
+    // chunk. This holds the input-side usage until the terminating
+    // `message_delta` arrives with output tokens, so the two can be merged
+    // into a single usage object.
+    anthropic_source_pending_input_usage: Option<UniversalUsage>,
 }
 
 impl StreamTransformSession {
@@ -125,6 +131,7 @@ impl StreamTransformSession {
             next_responses_tool_call_index: 0,
             bedrock_tool_call_indexes: BTreeMap::new(),
             next_bedrock_tool_call_index: 0,
+            anthropic_source_pending_input_usage: None,
         }
     }
 
@@ -182,6 +189,13 @@ impl StreamTransformSession {
             return self.normalize_responses_tool_call_indexes_stream_result(step);
         }
 
+        if step.source_format == ProviderFormat::Anthropic
+            && self.target_format != ProviderFormat::Anthropic
+            && step.source_is_native_stream
+        {
+            return self.normalize_anthropic_source_usage_stream_result(step);
+        }
+
         Ok(step.result.clone())
     }
 
@@ -213,6 +227,7 @@ impl StreamTransformSession {
             bytes,
             source_format: step.source_format,
             actual_target_format: self.target_format,
+            applied_normalizations: Vec::new(),
         })
     }
 
@@ -244,6 +259,50 @@ impl StreamTransformSession {
             bytes,
             source_format: step.source_format,
             actual_target_format: self.target_format,
+            applied_normalizations: Vec::new(),
+        })
+    }
+
+    fn normalize_anthropic_source_usage_stream_result(
+        &mut self,
+        step: &crate::processing::transform::StreamTransformStep,
+    ) -> Result<TransformResult, TransformError> {
+        let Some(mut universal) = step.universal.clone() else {
+            return Ok(step.result.clone());
+        };
+
+        let Some(usage) = universal.usage.take() else {
+            return Ok(step.result.clone());
+        };
+
+        let has_finish = universal
+            .choices
+            .iter()
+            .any(|choice| choice.finish_reason.is_some());
+
+        if has_finish {
+            // `message_delta`: merge the input-token usage captured at
+            // `message_start` with this delta's output tokens into the single
+            // terminal usage object non-Anthropic targets expect.
+            universal.usage = Some(merge_anthropic_stream_usage(
+                self.anthropic_source_pending_input_usage.take(),
+                usage,
+            ));
+        } else {
+            // `message_start`: hold the input-token usage rather than emitting
+            // it now; it will be merged into the terminal chunk above.
+            self.anthropic_source_pending_input_usage = Some(usage);
+        }
+
+        let target_adapter = adapter_for_format(self.target_format)
+            .ok_or(TransformError::UnsupportedTargetFormat(self.target_format))?;
+        let bytes = serialize_stream_value(&target_adapter.stream_from_universal(&universal)?)?;
+
+        Ok(TransformResult::Transformed {
+            bytes,
+            source_format: step.source_format,
+            actual_target_format: self.target_format,
+            applied_normalizations: Vec::new(),
         })
     }
 
@@ -1384,6 +1443,40 @@ fn merge_delta_usage(
     merged.unwrap_or(finish_delta)
 }
 
+/// Combine Anthropic's split usage reporting (input tokens on `message_start`,
+/// output tokens on the terminal `message_delta`) into a single usage object.
+fn merge_anthropic_stream_usage(
+    pending_input_usage: Option<UniversalUsage>,
+    final_usage: UniversalUsage,
+) -> UniversalUsage {
+    let Some(input_usage) = pending_input_usage else {
+        return final_usage;
+    };
+
+    UniversalUsage {
+        prompt_tokens: input_usage.prompt_tokens.or(final_usage.prompt_tokens),
+        completion_tokens: final_usage
+            .completion_tokens
+            .or(input_usage.completion_tokens),
+        prompt_cached_tokens: input_usage
+            .prompt_cached_tokens
+            .or(final_usage.prompt_cached_tokens),
+        prompt_cache_creation_tokens: input_usage
+            .prompt_cache_creation_tokens
+            .or(final_usage.prompt_cache_creation_tokens),
+        prompt_cache_creation_5m_tokens: input_usage
+            .prompt_cache_creation_5m_tokens
+            .or(final_usage.prompt_cache_creation_5m_tokens),
+        prompt_cache_creation_1h_tokens: input_usage
+            .prompt_cache_creation_1h_tokens
+            .or(final_usage.prompt_cache_creation_1h_tokens),
+        prompt_tokens_exclude_cache: input_usage.prompt_tokens_exclude_cache,
+        completion_reasoning_tokens: final_usage
+            .completion_reasoning_tokens
+            .or(input_usage.completion_reasoning_tokens),
+    }
+}
+
 /// Result of parsing a streaming event.
 ///
 /// Contains the transformed bytes, metadata about the event, and optionally
@@ -1410,6 +1503,17 @@ pub fn parse_stream_event(
     source_format: ProviderFormat,
     target_format: ProviderFormat,
 ) -> Result<ParsedStreamEvent, TransformError> {
+    if input.as_ref() == KEEP_ALIVE_BYTES {
+        return Ok(ParsedStreamEvent {
+            bytes: input,
+            source_format,
+            target_format,
+            universal: None,
+            is_keep_alive: true,
+            is_final: false,
+        });
+    }
+
     let chunk: Value = crate::serde_json::from_slice(&input)
         .map_err(|e| TransformError::DeserializationFailed(e.to_string()))?;
 
@@ -1458,6 +1562,97 @@ pub fn parse_stream_event(
     })
 }
 
+/// Borrowed, low-allocation counterpart of [`ParsedStreamEvent`].
+///
+/// Only usable on the pass-through path (`source_format == target_format`), where
+/// [`parse_stream_event`] would forward `input` unchanged anyway. Rather than paying for a full
+/// [`UniversalStreamChunk`], this only extracts the `is_keep_alive`/`is_final` bookkeeping flags,
+/// borrowing `&str`s from `input` instead of allocating owned [`Value`] trees. `bytes` is a
+/// reference into `input`, hence the tied lifetime.
+#[derive(Debug, Clone, Copy)]
+pub struct ParsedStreamEventBorrowed<'a> {
+    /// The payload to forward, borrowed from the input buffer.
+    pub bytes: &'a [u8],
+    /// The detected source format (equal to `target_format` on this path).
+    pub source_format: ProviderFormat,
+    /// The target format requested.
+    pub target_format: ProviderFormat,
+    /// Whether this is a keep-alive event (no content, just maintains connection).
+    /// Only the literal keep-alive sentinel payload sets this; an event that
+    /// [`parse_stream_event`] reports as keep-alive because the source adapter
+    /// produced no chunk for it is reported as `false` here.
+    pub is_keep_alive: bool,
+    /// Whether this event contains a finish_reason (indicates end of generation)
+    pub is_final: bool,
+}
+
+/// Borrowed shape used to detect a `finish_reason`/`stop_reason` without allocating a full
+/// [`Value`]. Only the fields needed for that check are declared; the rest of the payload is
+/// skipped by serde instead of being copied into owned storage.
+#[derive(Debug, Default, Deserialize)]
+struct FinishReasonPeek<'a> {
+    #[serde(borrow, default)]
+    choices: Vec<FinishReasonChoicePeek<'a>>,
+    #[serde(borrow, default)]
+    delta: Option<FinishReasonDeltaPeek<'a>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FinishReasonChoicePeek<'a> {
+    #[serde(borrow, default)]
+    finish_reason: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FinishReasonDeltaPeek<'a> {
+    #[serde(borrow, default)]
+    stop_reason: Option<&'a str>,
+}
+
+/// Parse a streaming event using borrowed, zero-copy structures where possible.
+///
+/// This is a fast path for high-throughput pass-through proxying: instead of building the full
+/// [`UniversalStreamChunk`] that [`parse_stream_event`] does, it deserializes just enough of the
+/// payload — borrowing `&str`s from `input` rather than allocating owned `String`s or a full
+/// [`Value`] tree — to report [`ParsedStreamEventBorrowed::is_keep_alive`] and
+/// [`ParsedStreamEventBorrowed::is_final`]. It only covers the pass-through case
+/// (`source_format == target_format`); cross-format translation still needs
+/// [`parse_stream_event`], which stays the default since most callers want the fully-typed
+/// universal representation more than they want to avoid this allocation.
+pub fn parse_stream_event_borrowed<'a>(
+    input: &'a [u8],
+    source_format: ProviderFormat,
+    target_format: ProviderFormat,
+) -> Result<ParsedStreamEventBorrowed<'a>, TransformError> {
+    if source_format != target_format {
+        return Err(TransformError::UnsupportedTargetFormat(target_format));
+    }
+
+    if input == KEEP_ALIVE_BYTES {
+        return Ok(ParsedStreamEventBorrowed {
+            bytes: input,
+            source_format,
+            target_format,
+            is_keep_alive: true,
+            is_final: false,
+        });
+    }
+
+    let peek: FinishReasonPeek =
+        crate::serde_json::from_slice(input).unwrap_or_else(|_| FinishReasonPeek::default());
+
+    let is_final = peek.choices.iter().any(|c| c.finish_reason.is_some())
+        || peek.delta.as_ref().is_some_and(|d| d.stop_reason.is_some());
+
+    Ok(ParsedStreamEventBorrowed {
+        bytes: input,
+        source_format,
+        target_format,
+        is_keep_alive: false,
+        is_final,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3044,6 +3239,46 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_stream_session_anthropic_to_openai_role_only_on_first_chunk() {
+        let mut session = StreamTransformSession::new(ProviderFormat::ChatCompletions);
+
+        let message_start = to_bytes(&json!({
+            "type": "message_start",
+            "message": {
+                "id": "msg_1",
+                "model": "claude-sonnet-4-5",
+                "usage": {"input_tokens": 10}
+            }
+        }));
+        let start_out = session.push(message_start).unwrap();
+        assert_eq!(start_out.len(), 1);
+        let start_chunk: Value = crate::serde_json::from_slice(&start_out[0].data).unwrap();
+        assert_eq!(
+            start_chunk["choices"][0]["delta"]["role"],
+            json!("assistant"),
+            "the opener chunk must carry the synthetic assistant role"
+        );
+
+        let text_delta = to_bytes(&json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": {"type": "text_delta", "text": "Hello"}
+        }));
+        let delta_out = session.push(text_delta).unwrap();
+        assert_eq!(delta_out.len(), 1);
+        let delta_chunk: Value = crate::serde_json::from_slice(&delta_out[0].data).unwrap();
+        assert!(
+            delta_chunk["choices"][0]["delta"].get("role").is_none(),
+            "content_block_delta must not repeat the role"
+        );
+        assert_eq!(
+            delta_chunk["choices"][0]["delta"]["content"],
+            json!("Hello")
+        );
+    }
+
     #[test]
     #[cfg(all(feature = "openai", feature = "bedrock"))]
     fn test_stream_session_converts_bedrock_tool_events_to_openai_chunks() {
@@ -3807,4 +4042,221 @@ mod tests {
             .collect();
         assert_eq!(partial_json, "{\"city\": \"SF\"}");
     }
+
+    // Cross-provider re-encode: an Anthropic-native SSE sequence (message_start,
+    // content_block_start/delta/stop, message_delta, message_stop) is bridged into a
+    // coherent OpenAI chat.completion.chunk sequence, including the final chunk carrying
+    // `finish_reason` and the trailing usage-only chunk.
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_stream_session_reencodes_anthropic_sequence_to_openai_chunks() {
+        let mut session = StreamTransformSession::new(ProviderFormat::ChatCompletions);
+
+        let events = [
+            to_bytes(&json!({
+                "type": "message_start",
+                "message": {
+                    "id": "msg_cross_provider",
+                    "type": "message",
+                    "role": "assistant",
+                    "model": "claude-3-5-sonnet-20241022",
+                    "content": [],
+                    "stop_reason": null,
+                    "stop_sequence": null,
+                    "usage": { "input_tokens": 10, "output_tokens": 0 }
+                }
+            })),
+            to_bytes(&json!({
+                "type": "content_block_start",
+                "index": 0,
+                "content_block": { "type": "text", "text": "" }
+            })),
+            to_bytes(&json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "text_delta", "text": "Hello" }
+            })),
+            to_bytes(&json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "text_delta", "text": " world" }
+            })),
+            to_bytes(&json!({
+                "type": "content_block_stop",
+                "index": 0
+            })),
+            to_bytes(&json!({
+                "type": "message_delta",
+                "delta": { "stop_reason": "end_turn", "stop_sequence": null },
+                "usage": { "output_tokens": 5 }
+            })),
+            to_bytes(&json!({ "type": "message_stop" })),
+        ];
+
+        let mut chunks: Vec<StreamOutputChunk> = Vec::new();
+        for event in events {
+            chunks.extend(session.push(event).unwrap());
+        }
+        chunks.extend(session.finish());
+
+        let parsed: Vec<Value> = chunks
+            .iter()
+            .map(|chunk| crate::serde_json::from_slice(&chunk.data).unwrap())
+            .collect();
+
+        let text: String = parsed
+            .iter()
+            .filter_map(|event| event["choices"][0]["delta"]["content"].as_str())
+            .collect();
+        assert_eq!(text, "Hello world");
+
+        let finish_reasons: Vec<&Value> = parsed
+            .iter()
+            .filter(|event| event["choices"].get(0).is_some())
+            .map(|event| &event["choices"][0]["finish_reason"])
+            .filter(|reason| !reason.is_null())
+            .collect();
+        assert_eq!(finish_reasons, vec![&json!("stop")]);
+    }
+
+    // Anthropic reports input tokens on `message_start` and output tokens on the
+    // terminal `message_delta`; OpenAI expects a single usage object on the last
+    // chunk. The session must buffer the `message_start` usage and merge it into
+    // the `message_delta` usage rather than emitting either on its own.
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_stream_session_assembles_anthropic_usage_into_terminal_openai_chunk() {
+        let mut session = StreamTransformSession::new(ProviderFormat::ChatCompletions);
+
+        let events = [
+            to_bytes(&json!({
+                "type": "message_start",
+                "message": {
+                    "id": "msg_usage_assembly",
+                    "type": "message",
+                    "role": "assistant",
+                    "model": "claude-3-5-sonnet-20241022",
+                    "content": [],
+                    "stop_reason": null,
+                    "stop_sequence": null,
+                    "usage": { "input_tokens": 25, "output_tokens": 0 }
+                }
+            })),
+            to_bytes(&json!({
+                "type": "content_block_start",
+                "index": 0,
+                "content_block": { "type": "text", "text": "" }
+            })),
+            to_bytes(&json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "text_delta", "text": "Hi" }
+            })),
+            to_bytes(&json!({
+                "type": "content_block_stop",
+                "index": 0
+            })),
+            to_bytes(&json!({
+                "type": "message_delta",
+                "delta": { "stop_reason": "end_turn", "stop_sequence": null },
+                "usage": { "output_tokens": 7 }
+            })),
+            to_bytes(&json!({ "type": "message_stop" })),
+        ];
+
+        let mut chunks: Vec<StreamOutputChunk> = Vec::new();
+        for event in events {
+            chunks.extend(session.push(event).unwrap());
+        }
+        chunks.extend(session.finish());
+
+        let parsed: Vec<Value> = chunks
+            .iter()
+            .map(|chunk| crate::serde_json::from_slice(&chunk.data).unwrap())
+            .collect();
+
+        let usage_chunks: Vec<&Value> = parsed
+            .iter()
+            .filter(|event| !event["usage"].is_null())
+            .collect();
+
+        assert_eq!(
+            usage_chunks.len(),
+            1,
+            "usage should appear on exactly one, terminal chunk: {parsed:?}"
+        );
+        assert_eq!(usage_chunks[0]["usage"]["prompt_tokens"], json!(25));
+        assert_eq!(usage_chunks[0]["usage"]["completion_tokens"], json!(7));
+        assert_eq!(usage_chunks[0]["usage"]["total_tokens"], json!(32));
+    }
+
+    #[test]
+    fn test_parse_stream_event_detects_keep_alive() {
+        let parsed = parse_stream_event(
+            Bytes::from_static(KEEP_ALIVE_BYTES),
+            ProviderFormat::ChatCompletions,
+            ProviderFormat::ChatCompletions,
+        )
+        .unwrap();
+        assert!(parsed.is_keep_alive);
+        assert!(!parsed.is_final);
+        assert!(parsed.universal.is_none());
+        assert_eq!(parsed.bytes.as_ref(), KEEP_ALIVE_BYTES);
+    }
+
+    #[test]
+    fn test_parse_stream_event_borrowed_detects_keep_alive() {
+        let parsed = parse_stream_event_borrowed(
+            KEEP_ALIVE_BYTES,
+            ProviderFormat::ChatCompletions,
+            ProviderFormat::ChatCompletions,
+        )
+        .unwrap();
+        assert!(parsed.is_keep_alive);
+        assert!(!parsed.is_final);
+        assert_eq!(parsed.bytes, KEEP_ALIVE_BYTES);
+    }
+
+    #[test]
+    fn test_parse_stream_event_borrowed_detects_openai_finish_reason() {
+        let input = to_bytes(&json!({
+            "choices": [{ "index": 0, "delta": {}, "finish_reason": "stop" }]
+        }));
+        let parsed = parse_stream_event_borrowed(
+            &input,
+            ProviderFormat::ChatCompletions,
+            ProviderFormat::ChatCompletions,
+        )
+        .unwrap();
+        assert!(!parsed.is_keep_alive);
+        assert!(parsed.is_final);
+    }
+
+    #[test]
+    fn test_parse_stream_event_borrowed_detects_anthropic_stop_reason() {
+        let input = to_bytes(&json!({
+            "type": "message_delta",
+            "delta": { "stop_reason": "end_turn", "stop_sequence": null },
+            "usage": { "output_tokens": 5 }
+        }));
+        let parsed = parse_stream_event_borrowed(
+            &input,
+            ProviderFormat::Anthropic,
+            ProviderFormat::Anthropic,
+        )
+        .unwrap();
+        assert!(parsed.is_final);
+    }
+
+    #[test]
+    fn test_parse_stream_event_borrowed_rejects_cross_format() {
+        let input = to_bytes(&json!({ "choices": [] }));
+        let err = parse_stream_event_borrowed(
+            &input,
+            ProviderFormat::ChatCompletions,
+            ProviderFormat::Anthropic,
+        )
+        .unwrap_err();
+        assert!(matches!(err, TransformError::UnsupportedTargetFormat(_)));
+    }
 }
@@ -0,0 +1,265 @@
+/*!
+Per-provider JSON-schema sanitization for tool `parameters`.
+
+Providers disagree on which JSON-schema keywords are legal in a tool's
+parameter schema:
+
+- OpenAI strict mode (`strict: true`) requires every object schema to set
+  `additionalProperties: false` and list all of its properties as `required`.
+- Google/Gemini rejects `$ref` (schemas must be self-contained) and a handful
+  of `format` values it doesn't recognize for the given `type`.
+- Anthropic accepts JSON Schema largely as-is and needs no adjustment.
+
+This module only rewrites the schema enough to satisfy each target's
+constraints; it does not attempt full JSON-schema validation.
+*/
+
+use crate::capabilities::ProviderFormat;
+use crate::serde_json::Value;
+
+const GOOGLE_ALLOWED_STRING_FORMATS: &[&str] = &["enum", "date-time"];
+const GOOGLE_ALLOWED_NUMBER_FORMATS: &[&str] = &["float", "double"];
+const GOOGLE_ALLOWED_INTEGER_FORMATS: &[&str] = &["int32", "int64"];
+
+/// Sanitize a tool's `parameters` JSON schema for the quirks of `target`.
+///
+/// `strict` selects OpenAI's stricter schema requirements and is ignored for
+/// other targets.
+pub fn sanitize_tool_parameters(schema: &Value, target: ProviderFormat, strict: bool) -> Value {
+    let mut schema = schema.clone();
+    match target {
+        ProviderFormat::ChatCompletions | ProviderFormat::Responses if strict => {
+            enforce_openai_strict(&mut schema);
+        }
+        ProviderFormat::Google => {
+            inline_refs(&mut schema);
+            strip_unsupported_google_formats(&mut schema);
+        }
+        _ => {}
+    }
+    schema
+}
+
+/// Recursively require `additionalProperties: false` and a full `required`
+/// list on every object schema, per OpenAI's strict-mode constraints.
+fn enforce_openai_strict(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let is_object_type = matches!(map.get("type"), Some(Value::String(t)) if t == "object");
+            if is_object_type {
+                map.insert("additionalProperties".into(), Value::Bool(false));
+                if let Some(Value::Object(props)) = map.get("properties") {
+                    let required: Vec<Value> = props.keys().cloned().map(Value::String).collect();
+                    map.insert("required".into(), Value::Array(required));
+                }
+            }
+            for v in map.values_mut() {
+                enforce_openai_strict(v);
+            }
+        }
+        Value::Array(items) => {
+            for v in items.iter_mut() {
+                enforce_openai_strict(v);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replace `$ref` pointers with the schema they point to, since Google's
+/// Schema proto has no notion of references. Resolves only same-document
+/// JSON pointers (`#/...`); an unresolvable `$ref` is left as-is.
+fn inline_refs(schema: &mut Value) {
+    let root = schema.clone();
+    inline_refs_against(&root, schema, 0);
+}
+
+/// Guards against pathologically deep or cyclic `$ref` chains.
+const MAX_REF_INLINE_DEPTH: u8 = 16;
+
+fn inline_refs_against(root: &Value, value: &mut Value, depth: u8) {
+    if depth >= MAX_REF_INLINE_DEPTH {
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(pointer)) = map.get("$ref").cloned() {
+                if let Some(resolved) = resolve_json_pointer(root, &pointer) {
+                    let mut resolved = resolved.clone();
+                    inline_refs_against(root, &mut resolved, depth + 1);
+                    *value = resolved;
+                    return;
+                }
+            }
+            for v in map.values_mut() {
+                inline_refs_against(root, v, depth + 1);
+            }
+        }
+        Value::Array(items) => {
+            for v in items.iter_mut() {
+                inline_refs_against(root, v, depth + 1);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn resolve_json_pointer<'a>(root: &'a Value, pointer: &str) -> Option<&'a Value> {
+    let mut current = root;
+    for part in pointer
+        .strip_prefix('#')?
+        .split('/')
+        .filter(|s| !s.is_empty())
+    {
+        let part = part.replace("~1", "/").replace("~0", "~");
+        current = match current {
+            Value::Object(map) => map.get(&part)?,
+            Value::Array(items) => items.get(part.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+/// Drop `format` values Google doesn't recognize for the schema's `type`,
+/// leaving the field itself in place.
+fn strip_unsupported_google_formats(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let schema_type = map.get("type").and_then(Value::as_str).map(str::to_string);
+            if let Some(format) = map.get("format").and_then(Value::as_str) {
+                let allowed: &[&str] = match schema_type.as_deref() {
+                    Some("string") => GOOGLE_ALLOWED_STRING_FORMATS,
+                    Some("number") => GOOGLE_ALLOWED_NUMBER_FORMATS,
+                    Some("integer") => GOOGLE_ALLOWED_INTEGER_FORMATS,
+                    _ => &[],
+                };
+                if !allowed.contains(&format) {
+                    map.remove("format");
+                }
+            }
+            for v in map.values_mut() {
+                strip_unsupported_google_formats(v);
+            }
+        }
+        Value::Array(items) => {
+            for v in items.iter_mut() {
+                strip_unsupported_google_formats(v);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serde_json::json;
+
+    #[test]
+    fn test_openai_strict_adds_additional_properties_and_required() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "city": {"type": "string"},
+                "unit": {"type": "string"}
+            }
+        });
+
+        let sanitized = sanitize_tool_parameters(&schema, ProviderFormat::ChatCompletions, true);
+
+        assert_eq!(sanitized["additionalProperties"], json!(false));
+        let required = sanitized["required"].as_array().unwrap();
+        assert_eq!(required.len(), 2);
+        assert!(required.contains(&json!("city")));
+        assert!(required.contains(&json!("unit")));
+    }
+
+    #[test]
+    fn test_openai_strict_recurses_into_nested_objects() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "location": {
+                    "type": "object",
+                    "properties": {"city": {"type": "string"}}
+                }
+            }
+        });
+
+        let sanitized = sanitize_tool_parameters(&schema, ProviderFormat::Responses, true);
+
+        assert_eq!(sanitized["additionalProperties"], json!(false));
+        assert_eq!(
+            sanitized["properties"]["location"]["additionalProperties"],
+            json!(false)
+        );
+        assert_eq!(
+            sanitized["properties"]["location"]["required"],
+            json!(["city"])
+        );
+    }
+
+    #[test]
+    fn test_non_strict_openai_leaves_schema_untouched() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"city": {"type": "string"}}
+        });
+
+        let sanitized = sanitize_tool_parameters(&schema, ProviderFormat::ChatCompletions, false);
+
+        assert_eq!(sanitized, schema);
+    }
+
+    #[test]
+    fn test_google_inlines_ref() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "location": {"$ref": "#/$defs/Location"}
+            },
+            "$defs": {
+                "Location": {"type": "string", "description": "a city"}
+            }
+        });
+
+        let sanitized = sanitize_tool_parameters(&schema, ProviderFormat::Google, false);
+
+        assert_eq!(
+            sanitized["properties"]["location"],
+            json!({"type": "string", "description": "a city"})
+        );
+    }
+
+    #[test]
+    fn test_google_strips_unsupported_string_format() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "string", "format": "uuid"},
+                "created_at": {"type": "string", "format": "date-time"}
+            }
+        });
+
+        let sanitized = sanitize_tool_parameters(&schema, ProviderFormat::Google, false);
+
+        assert!(sanitized["properties"]["id"].get("format").is_none());
+        assert_eq!(
+            sanitized["properties"]["created_at"]["format"],
+            json!("date-time")
+        );
+    }
+
+    #[test]
+    fn test_anthropic_leaves_schema_untouched() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"id": {"$ref": "#/$defs/Id"}}
+        });
+
+        let sanitized = sanitize_tool_parameters(&schema, ProviderFormat::Anthropic, false);
+
+        assert_eq!(sanitized, schema);
+    }
+}
@@ -9,6 +9,15 @@ payloads between different provider formats. The key principle is:
 
 All public functions take `Bytes` input and return `Bytes` output for zero-copy
 passthrough in async contexts.
+
+**Key ordering.** A passthrough result reuses the original request/response
+bytes verbatim, so its object keys stay in whatever order the caller sent.
+A transformed result is serialized from `crate::serde_json::Value`, whose
+`Object` variant is a `BTreeMap` (see [`canonicalize_payload`]) rather than
+an insertion-order map, so keys within any single JSON object always come
+out sorted lexicographically. That ordering is stable across runs for the
+same logical payload regardless of the input's own key order, which is what
+lets [`canonicalize_payload`] use a plain reserialize as a cache key.
 */
 
 use bytes::Bytes;
@@ -24,8 +33,8 @@ use crate::serde_json::Value;
 use crate::universal::{
     AssistantContent, AssistantContentPart, Message, ParsableResponseInfo, TextContentPart,
     ToolCallArguments, UniversalReasoningDelta, UniversalRequest, UniversalResponse,
-    UniversalStreamChoice, UniversalStreamChunk, UniversalStreamDelta, UniversalToolCallDelta,
-    UniversalToolFunctionDelta, UserContent, UserContentPart,
+    UniversalStreamChoice, UniversalStreamChunk, UniversalStreamDelta, UniversalTool,
+    UniversalToolCallDelta, UniversalToolFunctionDelta, UserContent, UserContentPart,
 };
 use serde::de::DeserializeOwned;
 use thiserror::Error;
@@ -75,6 +84,12 @@ pub enum TransformError {
 
     #[error("Streaming not implemented: {0}")]
     StreamingNotImplemented(String),
+
+    #[error("Payload size {size} bytes exceeds limit of {limit} bytes")]
+    TooLarge { size: usize, limit: usize },
+
+    #[error("Tool \"{name}\" is defined more than once with conflicting schemas")]
+    ConflictingToolDefinition { name: String },
 }
 
 impl TransformError {
@@ -97,8 +112,56 @@ impl TransformError {
                 | TransformError::UnsupportedSourceFormat(_)
                 | TransformError::ToUniversalFailed(_)
                 | TransformError::FromUniversalFailed(_)
+                | TransformError::TooLarge { .. }
+                | TransformError::ConflictingToolDefinition { .. }
         )
     }
+
+    /// Render this error as an OpenAI-shaped `{"error": {...}}` body.
+    ///
+    /// Intended for gateways that need to hand a `transform_request`/
+    /// `transform_response` failure straight back to an OpenAI-format client.
+    pub fn to_openai_error(&self) -> Value {
+        serde_json::json!({
+            "error": {
+                "message": self.to_string(),
+                "type": "invalid_request_error",
+                "code": Value::Null,
+                "param": Value::Null,
+            }
+        })
+    }
+
+    /// Render this error as an Anthropic-shaped `{"type": "error", "error": {...}}` body.
+    ///
+    /// Intended for gateways that need to hand a `transform_request`/
+    /// `transform_response` failure straight back to an Anthropic-format client.
+    pub fn to_anthropic_error(&self) -> Value {
+        serde_json::json!({
+            "type": "error",
+            "error": {
+                "type": "invalid_request_error",
+                "message": self.to_string(),
+            }
+        })
+    }
+}
+
+/// Return `Err(TransformError::TooLarge)` if `input` exceeds `max_payload_bytes`,
+/// checked before any JSON parsing is attempted.
+fn check_payload_size(
+    input: &Bytes,
+    max_payload_bytes: Option<usize>,
+) -> Result<(), TransformError> {
+    if let Some(limit) = max_payload_bytes {
+        if input.len() > limit {
+            return Err(TransformError::TooLarge {
+                size: input.len(),
+                limit,
+            });
+        }
+    }
+    Ok(())
 }
 
 impl From<ConvertError> for TransformError {
@@ -107,6 +170,86 @@ impl From<ConvertError> for TransformError {
     }
 }
 
+/// A single top-level normalization applied while converting a request from
+/// one provider format to another, surfaced so a gateway can log exactly
+/// what it did to a client's payload (useful for debugging client
+/// complaints about "my parameter got dropped/renamed").
+///
+/// Computed by [`diff_top_level_fields`] as a structural diff between the
+/// source and target JSON objects: a source field whose value reappears
+/// unchanged under a different target key is a rename; a source field with
+/// no matching target value was dropped; a target field with no matching
+/// source value was injected (e.g. a provider-required default).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppliedNormalization {
+    /// A field was renamed, keeping the same value (e.g. OpenAI's `stop` ->
+    /// Anthropic's `stop_sequences`).
+    Renamed { from: String, to: String },
+    /// A source field had no equivalent in the target format and was dropped
+    /// (e.g. OpenAI's `frequency_penalty` when targeting Anthropic).
+    Dropped { field: String },
+    /// A target field with no corresponding source value was added, usually
+    /// a provider-required default (e.g. Anthropic's required `max_tokens`).
+    Injected { field: String },
+}
+
+/// Diff the top-level fields of a source and target request body, returning
+/// the [`AppliedNormalization`]s that explain the difference.
+///
+/// This is a structural, value-based diff rather than a hardcoded per-field
+/// mapping: a dropped source field and an injected target field are paired
+/// into a single `Renamed` entry whenever their values are identical, which
+/// naturally captures renames without either side needing to know about the
+/// other's field names in advance. Nested renames (e.g. a field moving into
+/// a nested object) are not detected - only top-level fields are compared.
+pub(crate) fn diff_top_level_fields(source: &Value, target: &Value) -> Vec<AppliedNormalization> {
+    let (Some(source_obj), Some(target_obj)) = (source.as_object(), target.as_object()) else {
+        return Vec::new();
+    };
+
+    let mut dropped: Vec<(&String, &Value)> = source_obj
+        .iter()
+        .filter(|(key, _)| !target_obj.contains_key(*key))
+        .collect();
+    let mut injected: Vec<(&String, &Value)> = target_obj
+        .iter()
+        .filter(|(key, _)| !source_obj.contains_key(*key))
+        .collect();
+
+    let mut normalizations = Vec::new();
+    dropped.retain(|(from_key, from_value)| {
+        if let Some(pos) = injected
+            .iter()
+            .position(|(_, to_value)| *to_value == *from_value)
+        {
+            let (to_key, _) = injected.remove(pos);
+            normalizations.push(AppliedNormalization::Renamed {
+                from: (*from_key).clone(),
+                to: to_key.clone(),
+            });
+            false
+        } else {
+            true
+        }
+    });
+
+    normalizations.extend(
+        dropped
+            .into_iter()
+            .map(|(field, _)| AppliedNormalization::Dropped {
+                field: field.clone(),
+            }),
+    );
+    normalizations.extend(
+        injected
+            .into_iter()
+            .map(|(field, _)| AppliedNormalization::Injected {
+                field: field.clone(),
+            }),
+    );
+    normalizations
+}
+
 /// Result of a transformation operation.
 ///
 /// Contains either the original bytes (passthrough) or transformed bytes.
@@ -130,6 +273,9 @@ pub enum TransformResult {
         /// differ when the transform function upgrades the target (e.g. `ChatCompletions` →
         /// `Responses` when `reasoning_effort` + `tools` are present).
         actual_target_format: ProviderFormat,
+        /// Top-level field renames, drops, and injections applied during the
+        /// conversion - see [`AppliedNormalization`].
+        applied_normalizations: Vec<AppliedNormalization>,
     },
 }
 
@@ -155,6 +301,10 @@ impl RequestTransformResult {
     pub fn source_format(&self) -> Option<ProviderFormat> {
         self.result.source_format()
     }
+
+    pub fn applied_normalizations(&self) -> &[AppliedNormalization] {
+        self.result.applied_normalizations()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -196,6 +346,18 @@ impl TransformResult {
             TransformResult::Transformed { source_format, .. } => Some(*source_format),
         }
     }
+
+    /// Get the top-level field renames, drops, and injections applied during
+    /// the transformation. Always empty for passthrough results.
+    pub fn applied_normalizations(&self) -> &[AppliedNormalization] {
+        match self {
+            TransformResult::PassThrough(_) => &[],
+            TransformResult::Transformed {
+                applied_normalizations,
+                ..
+            } => applied_normalizations,
+        }
+    }
 }
 
 pub(crate) struct StreamTransformStep {
@@ -241,7 +403,9 @@ pub fn extract_model(input: &[u8]) -> Option<String> {
         .get("model") // OpenAI, Anthropic
         .or_else(|| payload.get("modelId")) // Bedrock
         .and_then(|v| v.as_str())
-        .map(String::from)
+        // Bedrock's `modelId` may be a full inference-profile or foundation-model
+        // ARN rather than a bare id; unwrap it to the trailing model portion.
+        .map(|model| crate::providers::bedrock_anthropic::strip_bedrock_arn(model).to_string())
 }
 
 // ============================================================================
@@ -409,11 +573,108 @@ fn strip_claude_code_attribution(req: &mut UniversalRequest) {
     }
 }
 
+/// Remove duplicate tool definitions from `req.tools`, keeping the first
+/// occurrence of each name.
+///
+/// Agents sometimes send the same tool twice (e.g. a retried tool-discovery
+/// step re-appends its result to the running tool list); some providers
+/// (Anthropic included) reject a `tools` array with repeated names outright.
+/// A second definition with the exact same `description`/`parameters`/
+/// `strict` is a harmless duplicate and is dropped silently. A second
+/// definition with the *same name but a different schema* is not something
+/// we can safely resolve by guessing - the client's two intents disagree -
+/// so this errors instead of silently picking one and breaking tool calls
+/// downstream.
+fn dedupe_tool_definitions(req: &mut UniversalRequest) -> Result<(), TransformError> {
+    let Some(tools) = req.tools.as_mut() else {
+        return Ok(());
+    };
+
+    fn schema_key(tool: &UniversalTool) -> (Option<&str>, Option<&Value>, Option<bool>) {
+        (
+            tool.description.as_deref(),
+            tool.parameters.as_ref(),
+            tool.strict,
+        )
+    }
+
+    let mut seen: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    let mut duplicates: Vec<usize> = Vec::new();
+
+    for (idx, tool) in tools.iter().enumerate() {
+        match seen.get(tool.name.as_str()) {
+            Some(&first_idx) => {
+                if schema_key(&tools[first_idx]) != schema_key(tool) {
+                    return Err(TransformError::ConflictingToolDefinition {
+                        name: tool.name.clone(),
+                    });
+                }
+                duplicates.push(idx);
+            }
+            None => {
+                seen.insert(tool.name.as_str(), idx);
+            }
+        }
+    }
+
+    for idx in duplicates.into_iter().rev() {
+        tools.remove(idx);
+    }
+
+    Ok(())
+}
+
+/// Runtime toggles for individual request-normalization behaviors.
+///
+/// Every flag defaults to the behavior `transform_request` has always had.
+/// This exists as an escape hatch for operators who need to disable a
+/// specific normalization for debugging or provider compatibility, without
+/// forking the transform pipeline. Flags only affect requests that are
+/// actually transformed to OpenAI Chat Completions format; passthrough
+/// requests (already valid for the target format) are returned unchanged,
+/// as before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransformConfig {
+    /// Rename `max_tokens` to `max_completion_tokens` for OpenAI models that
+    /// require it (e.g. the `o1`/`o3`/`gpt-5` families). Disabling this
+    /// reverts the rename, leaving `max_tokens` in the output instead.
+    pub rename_max_tokens: bool,
+    /// Inject `stream_options: {"include_usage": true}` into streaming
+    /// OpenAI Chat Completions requests so usage is reported on the final
+    /// chunk. Disabling this drops the injected field.
+    pub inject_stream_include_usage: bool,
+}
+
+impl Default for TransformConfig {
+    fn default() -> Self {
+        Self {
+            rename_max_tokens: true,
+            inject_stream_include_usage: true,
+        }
+    }
+}
+
 pub fn transform_request(
     input: Bytes,
     target_format: ProviderFormat,
     model: Option<&str>,
 ) -> Result<RequestTransformResult, TransformError> {
+    transform_request_with_limit(input, target_format, model, None)
+}
+
+/// Same as [`transform_request`], but rejects payloads larger than
+/// `max_payload_bytes` with [`TransformError::TooLarge`] before parsing.
+///
+/// `max_payload_bytes: None` means unlimited, matching [`transform_request`].
+/// A gateway sitting in front of untrusted clients can pass e.g. `Some(10 *
+/// 1024 * 1024)` to cap request bodies at 10MB.
+pub fn transform_request_with_limit(
+    input: Bytes,
+    target_format: ProviderFormat,
+    model: Option<&str>,
+    max_payload_bytes: Option<usize>,
+) -> Result<RequestTransformResult, TransformError> {
+    check_payload_size(&input, max_payload_bytes)?;
     let parsed = parse_json_body(input)?;
     let payload = parsed.value;
     let request_bytes = parsed.bytes;
@@ -459,6 +720,7 @@ pub fn transform_request(
         });
     }
 
+    let source_payload = payload.clone();
     let mut universal = source_adapter.request_to_universal(payload)?;
 
     if let Some(model) = model {
@@ -469,12 +731,16 @@ pub fn transform_request(
         strip_claude_code_attribution(&mut universal);
     }
 
+    dedupe_tool_definitions(&mut universal)?;
+
     // Apply target provider defaults (e.g., Anthropic's required max_tokens)
     target_adapter.apply_defaults(&mut universal);
 
     // Convert to target format (validation happens in adapter)
     let transformed = target_adapter.request_from_universal(&universal)?;
 
+    let applied_normalizations = diff_top_level_fields(&source_payload, &transformed);
+
     let bytes = crate::serde_json::to_vec(&transformed)
         .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
 
@@ -483,11 +749,119 @@ pub fn transform_request(
             bytes: Bytes::from(bytes),
             source_format,
             actual_target_format: target_format,
+            applied_normalizations,
         },
         requires_json_response,
     })
 }
 
+/// Same as [`transform_request_with_limit`], but applies the given
+/// [`TransformConfig`] to the result, disabling individual normalizations an
+/// operator wants to skip (see [`TransformConfig`] for what's covered).
+pub fn transform_request_with_config(
+    input: Bytes,
+    target_format: ProviderFormat,
+    model: Option<&str>,
+    max_payload_bytes: Option<usize>,
+    config: TransformConfig,
+) -> Result<RequestTransformResult, TransformError> {
+    let result = transform_request_with_limit(input, target_format, model, max_payload_bytes)?;
+    if config == TransformConfig::default() {
+        return Ok(result);
+    }
+    let RequestTransformResult {
+        result: transform_result,
+        requires_json_response,
+    } = result;
+    let transform_result = match transform_result {
+        TransformResult::Transformed {
+            bytes,
+            source_format,
+            actual_target_format,
+            applied_normalizations,
+        } if actual_target_format == ProviderFormat::ChatCompletions => {
+            TransformResult::Transformed {
+                bytes: apply_transform_config(bytes, config)?,
+                source_format,
+                actual_target_format,
+                applied_normalizations,
+            }
+        }
+        other => other,
+    };
+    Ok(RequestTransformResult {
+        result: transform_result,
+        requires_json_response,
+    })
+}
+
+/// Same as [`transform_request`], but also returns the detected source
+/// format alongside the transformed bytes, for callers that accept mixed
+/// inbound formats on one endpoint (e.g. a gateway) and want to log or
+/// route on what was actually sent.
+///
+/// The source format is always inferred from the payload itself -
+/// [`transform_request`] already does this internally, this just surfaces
+/// it. On a passthrough (source already matches `target_format`), the
+/// source format is `target_format` itself.
+pub fn transform_request_auto(
+    input: Bytes,
+    target_format: ProviderFormat,
+) -> Result<(Bytes, ProviderFormat), TransformError> {
+    let result = transform_request(input, target_format, None)?;
+    let source_format = result.source_format().unwrap_or(target_format);
+    Ok((result.into_bytes(), source_format))
+}
+
+/// Same as [`transform_request`], but accepts anything convertible to a JSON
+/// value (lingua's own [`Value`](crate::serde_json::Value) or a plain
+/// `serde_json::Value`) instead of raw bytes, for callers that already have a
+/// parsed value on hand and don't want to serialize it themselves first.
+pub fn transform_request_from_value<V: crate::processing::json_compat::IntoLinguaJson>(
+    input: V,
+    target_format: ProviderFormat,
+    config: Option<TransformConfig>,
+) -> Result<RequestTransformResult, TransformError> {
+    let value = input.into_lingua_json();
+    let bytes = crate::serde_json::to_vec(&value)
+        .map(Bytes::from)
+        .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
+    transform_request_with_config(
+        bytes,
+        target_format,
+        None,
+        None,
+        config.unwrap_or_default(),
+    )
+}
+
+/// Undo the normalizations [`TransformConfig`] flags disable, on an already
+/// target-formatted OpenAI Chat Completions payload.
+fn apply_transform_config(bytes: Bytes, config: TransformConfig) -> Result<Bytes, TransformError> {
+    let mut value = parse_json_value(&bytes)?;
+    let Value::Object(obj) = &mut value else {
+        return Ok(bytes);
+    };
+
+    let mut changed = false;
+    if !config.rename_max_tokens {
+        if let Some(max_completion_tokens) = obj.remove("max_completion_tokens") {
+            obj.insert("max_tokens".into(), max_completion_tokens);
+            changed = true;
+        }
+    }
+    if !config.inject_stream_include_usage && obj.remove("stream_options").is_some() {
+        changed = true;
+    }
+
+    if !changed {
+        return Ok(bytes);
+    }
+    let out = crate::serde_json::to_vec(&value)
+        .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
+    Ok(Bytes::from(out))
+}
+
 /// Parse a request payload into Lingua's universal request representation.
 ///
 /// Unlike [`transform_request`], this does not apply target-provider defaults,
@@ -538,6 +912,7 @@ fn merge_responses_output_parts_for_chat_completions(messages: Vec<Message>) ->
         let Message::Assistant {
             content: AssistantContent::Array(reasoning_parts),
             id: reasoning_id,
+            ..
         } = previous
         else {
             merged.push(previous);
@@ -548,11 +923,13 @@ fn merge_responses_output_parts_for_chat_completions(messages: Vec<Message>) ->
         let Message::Assistant {
             content: next_content,
             id: next_id,
+            ..
         } = message
         else {
             merged.push(Message::Assistant {
                 content: AssistantContent::Array(reasoning_parts),
                 id: reasoning_id,
+                name: None,
             });
             merged.push(message);
             continue;
@@ -574,6 +951,7 @@ fn merge_responses_output_parts_for_chat_completions(messages: Vec<Message>) ->
         merged.push(Message::Assistant {
             content: AssistantContent::Array(combined_parts),
             id: next_id.or(reasoning_id),
+            name: None,
         });
     }
 
@@ -596,6 +974,19 @@ pub fn transform_response(
     input: Bytes,
     target_format: ProviderFormat,
 ) -> Result<ResponseTransformResult, TransformError> {
+    transform_response_with_limit(input, target_format, None)
+}
+
+/// Same as [`transform_response`], but rejects payloads larger than
+/// `max_payload_bytes` with [`TransformError::TooLarge`] before parsing.
+///
+/// `max_payload_bytes: None` means unlimited, matching [`transform_response`].
+pub fn transform_response_with_limit(
+    input: Bytes,
+    target_format: ProviderFormat,
+    max_payload_bytes: Option<usize>,
+) -> Result<ResponseTransformResult, TransformError> {
+    check_payload_size(&input, max_payload_bytes)?;
     let parsed = parse_json_body(input)?;
     let response = parsed.value;
     let response_bytes = parsed.bytes;
@@ -635,6 +1026,9 @@ pub fn transform_response(
             bytes: Bytes::from(bytes),
             source_format,
             actual_target_format: target_format,
+            // Normalization diffing only applies to requests today - see
+            // `transform_request_with_limit`.
+            applied_normalizations: Vec::new(),
         },
         parsable_info,
     })
@@ -747,7 +1141,8 @@ fn assistant_content_to_stream_delta(content: &AssistantContent) -> UniversalStr
                             }),
                         });
                     }
-                    AssistantContentPart::File { .. }
+                    AssistantContentPart::Refusal { .. }
+                    | AssistantContentPart::File { .. }
                     | AssistantContentPart::ToolResult { .. }
                     | AssistantContentPart::ToolDiscoveryCall { .. }
                     | AssistantContentPart::Program { .. }
@@ -839,6 +1234,7 @@ pub(crate) fn transform_stream_chunk_step(
             bytes,
             source_format,
             actual_target_format: target_format,
+            applied_normalizations: Vec::new(),
         },
         source_format,
         source_is_native_stream,
@@ -955,21 +1351,36 @@ fn request_model_needs_forced_translation(
 
 /// Sanitize a payload for a target format by parsing and re-serializing.
 ///
-/// This strips unknown fields that strict providers (like Anthropic) would reject.
+/// This strips unknown fields that strict providers (like Anthropic) would reject,
+/// and canonicalizes Anthropic's `system` field into block-array form so a plain
+/// string and an equivalent single-block array sanitize identically.
+///
+/// Only Anthropic payloads have anything to strip today, so non-Anthropic formats
+/// are validated as JSON and returned unchanged - no rebuild allocation. Anthropic
+/// payloads that already round-trip identically through `try_parse_anthropic` (the
+/// common case for a well-formed proxy request) also skip the final re-serialize
+/// and reuse the original `Bytes`, which is a cheap refcount bump rather than a
+/// full copy of the payload.
 pub fn sanitize_payload(input: Bytes, format: ProviderFormat) -> Result<Bytes, TransformError> {
     use crate::providers::anthropic::try_parse_anthropic;
 
+    if format != ProviderFormat::Anthropic {
+        parse_json_value(&input)?;
+        return Ok(input);
+    }
+
     let payload = parse_json_value(&input)?;
+    let mut parsed = try_parse_anthropic(&payload)
+        .map_err(|e| TransformError::ToUniversalFailed(e.to_string()))?;
+    parsed.system = parsed
+        .system
+        .map(crate::providers::anthropic::convert::canonicalize_system_field);
+    let sanitized = crate::serde_json::to_value(parsed)
+        .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
 
-    let sanitized = match format {
-        ProviderFormat::Anthropic => {
-            let parsed = try_parse_anthropic(&payload)
-                .map_err(|e| TransformError::ToUniversalFailed(e.to_string()))?;
-            crate::serde_json::to_value(parsed)
-                .map_err(|e| TransformError::SerializationFailed(e.to_string()))?
-        }
-        _ => payload,
-    };
+    if sanitized == payload {
+        return Ok(input);
+    }
 
     let bytes = crate::serde_json::to_vec(&sanitized)
         .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
@@ -977,6 +1388,23 @@ pub fn sanitize_payload(input: Bytes, format: ProviderFormat) -> Result<Bytes, T
     Ok(Bytes::from(bytes))
 }
 
+/// Produce a canonical byte form of a JSON payload for cache keys and
+/// deduplication: two payloads that differ only in object key order
+/// canonicalize to identical bytes.
+///
+/// This relies on `big_serde_json` building `Value::Object` on `BTreeMap`
+/// (it enables `arbitrary_precision` but not `preserve_order`), so a plain
+/// parse-then-reserialize round trip already sorts every object's keys,
+/// recursively, without this function walking the tree itself. Array order
+/// is semantically significant (e.g. message order) and is left untouched,
+/// since `Value::Array` stays a `Vec`.
+pub fn canonicalize_payload(input: Bytes) -> Result<Bytes, TransformError> {
+    let value = parse_json_value(&input)?;
+    let bytes = crate::serde_json::to_vec(&value)
+        .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
+    Ok(Bytes::from(bytes))
+}
+
 pub fn parse_json_value(input: &[u8]) -> Result<Value, TransformError> {
     parse_json(input).map_err(|err| TransformError::DeserializationFailed(err.to_string()))
 }
@@ -1138,7 +1566,7 @@ mod tests {
             .iter()
             .rev()
             .find_map(|message| match message {
-                Message::User { content } => match content {
+                Message::User { content, .. } => match content {
                     UserContent::String(text) => Some(text.as_str()),
                     UserContent::Array(parts) => parts.iter().find_map(|part| match part {
                         UserContentPart::Text(text) => Some(text.text.as_str()),
@@ -1161,6 +1589,25 @@ mod tests {
         assert_eq!(extract_model(input), Some("anthropic.claude-3".to_string()));
     }
 
+    #[test]
+    fn test_extract_model_bedrock_cross_region_profile() {
+        let input =
+            br#"{"modelId": "us.anthropic.claude-3-5-sonnet-20241022-v2:0", "messages": []}"#;
+        assert_eq!(
+            extract_model(input),
+            Some("us.anthropic.claude-3-5-sonnet-20241022-v2:0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_model_bedrock_inference_profile_arn() {
+        let input = br#"{"modelId": "arn:aws:bedrock:us-east-1:123456789012:inference-profile/us.anthropic.claude-3-5-sonnet-20241022-v2:0", "messages": []}"#;
+        assert_eq!(
+            extract_model(input),
+            Some("us.anthropic.claude-3-5-sonnet-20241022-v2:0".to_string())
+        );
+    }
+
     #[test]
     fn test_extract_model_google() {
         // Google format doesn't have model in body
@@ -1194,6 +1641,41 @@ mod tests {
         assert_eq!(output.as_ptr(), input_ptr);
     }
 
+    #[test]
+    fn test_transform_request_with_limit_rejects_oversized_payload_without_parsing() {
+        // A payload that isn't even valid JSON should still be rejected on size alone,
+        // proving the limit is checked before parsing is attempted.
+        let input = Bytes::from(vec![b'{'; 1024]);
+
+        let err =
+            transform_request_with_limit(input, ProviderFormat::ChatCompletions, None, Some(16))
+                .unwrap_err();
+
+        assert!(matches!(
+            err,
+            TransformError::TooLarge {
+                size: 1024,
+                limit: 16
+            }
+        ));
+    }
+
+    #[test]
+    fn test_transform_response_with_limit_rejects_oversized_payload_without_parsing() {
+        let input = Bytes::from(vec![b'{'; 1024]);
+
+        let err = transform_response_with_limit(input, ProviderFormat::ChatCompletions, Some(16))
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            TransformError::TooLarge {
+                size: 1024,
+                limit: 16
+            }
+        ));
+    }
+
     #[test]
     #[cfg(feature = "openai")]
     fn test_transform_request_passthrough_with_identical_model_override() {
@@ -1387,6 +1869,30 @@ mod tests {
         assert_eq!(output.get("stream").and_then(Value::as_bool), Some(false));
     }
 
+    #[test]
+    #[cfg(feature = "openai")]
+    fn test_transform_request_legacy_prompt_array_chat_completions_to_messages() {
+        let payload = json!({
+            "model": "gpt-4o",
+            "prompt": ["Write a haiku about the ocean.", "Now one about the mountains."],
+            "max_tokens": 256
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::ChatCompletions, None).unwrap();
+
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+        assert_eq!(
+            output.get("messages"),
+            Some(&json!([
+                {
+                    "role": "user",
+                    "content": "Write a haiku about the ocean.\n\nNow one about the mountains."
+                }
+            ]))
+        );
+    }
+
     #[test]
     #[cfg(feature = "openai")]
     fn test_transform_request_legacy_prompt_chat_completions_rejects_completion_only_params() {
@@ -1536,64 +2042,270 @@ mod tests {
 
     #[test]
     #[cfg(all(feature = "openai", feature = "anthropic"))]
-    fn test_transform_request_responses_discovery_tools_to_anthropic() {
+    fn test_transform_request_output_key_order_is_deterministic() {
+        // Two requests that are logically identical but list their top-level
+        // keys in a different order should still produce byte-identical
+        // transformed output, since a transformed result is always
+        // reserialized with sorted keys (see the module-level docs).
+        let payload_a = json!({
+            "model": "gpt-4",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "temperature": 0.5,
+        });
+        let payload_b = json!({
+            "temperature": 0.5,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "model": "gpt-4",
+        });
+
+        let output_a =
+            transform_request(to_bytes(&payload_a), ProviderFormat::Anthropic, None).unwrap();
+        let output_b =
+            transform_request(to_bytes(&payload_b), ProviderFormat::Anthropic, None).unwrap();
+
+        assert!(!output_a.is_passthrough());
+        assert!(!output_b.is_passthrough());
+        assert_eq!(output_a.into_bytes(), output_b.into_bytes());
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_openai_to_anthropic_drops_logit_bias() {
         let payload = json!({
-            "model": "gpt-5.5",
-            "input": [
-                {
-                    "type": "message",
-                    "role": "user",
-                    "content": "Find the available tools."
-                },
-                {
-                    "type": "tool_search_call",
-                    "call_id": "call_tool_search_123",
-                    "status": "completed",
-                    "execution": "client",
-                    "arguments": {}
-                },
-                {
-                    "type": "tool_search_output",
-                    "call_id": "call_tool_search_123",
-                    "status": "completed",
-                    "execution": "client",
-                    "tools": [
-                        {
-                            "type": "function",
-                            "name": "search_docs",
-                            "description": "Search docs.",
-                            "strict": true,
-                            "parameters": {
-                                "type": "object",
-                                "properties": {},
-                                "additionalProperties": false
-                            }
-                        }
-                    ]
-                }
-            ],
-            "tools": [
-                {
-                    "type": "namespace",
-                    "name": "search_code",
-                    "description": "Deferred code search tools.",
-                    "tools": [
-                        {
-                            "type": "function",
-                            "name": "search_code",
-                            "description": "Search code.",
-                            "strict": true,
-                            "parameters": {
-                                "type": "object",
-                                "properties": {},
-                                "additionalProperties": false
-                            },
-                            "defer_loading": true
-                        }
-                    ]
-                },
-                { "type": "tool_search" }
-            ]
+            "model": "gpt-4",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "logit_bias": {"50256": -100}
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::Anthropic, None).unwrap();
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+
+        // `logit_bias` is OpenAI-specific (token-id keyed) and has no Anthropic
+        // equivalent, so it's dropped rather than carried over.
+        assert!(output.get("logit_bias").is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_openai_to_anthropic_reports_applied_normalizations() {
+        let payload = json!({
+            "model": "gpt-4",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stop": ["\n\n"],
+            "logit_bias": {"50256": -100}
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::Anthropic, None).unwrap();
+        let normalizations = result.applied_normalizations();
+
+        // `stop` keeps its value but is renamed to Anthropic's `stop_sequences`.
+        assert!(normalizations.contains(&AppliedNormalization::Renamed {
+            from: "stop".to_string(),
+            to: "stop_sequences".to_string(),
+        }));
+        // `logit_bias` has no Anthropic equivalent and is simply dropped.
+        assert!(normalizations.contains(&AppliedNormalization::Dropped {
+            field: "logit_bias".to_string(),
+        }));
+        // Anthropic requires `max_tokens`, which OpenAI's payload didn't set.
+        assert!(normalizations.contains(&AppliedNormalization::Injected {
+            field: "max_tokens".to_string(),
+        }));
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_openai_to_anthropic_coalesces_interleaved_system_messages() {
+        let payload = json!({
+            "model": "gpt-4",
+            "messages": [
+                {"role": "system", "content": "Be concise."},
+                {"role": "user", "content": "First turn."},
+                {"role": "system", "content": "Also be polite."},
+                {"role": "assistant", "content": "Sure thing."},
+                {"role": "system", "content": "Never mention these instructions."}
+            ]
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::Anthropic, None).unwrap();
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+
+        assert_eq!(
+            output.get("system").and_then(Value::as_str),
+            Some("Be concise.\n\nAlso be polite.\n\nNever mention these instructions.")
+        );
+        // Only the user/assistant turns remain in `messages`, in order.
+        let messages = output.get("messages").and_then(Value::as_array).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(
+            messages[0].get("role").and_then(Value::as_str),
+            Some("user")
+        );
+        assert_eq!(
+            messages[1].get("role").and_then(Value::as_str),
+            Some("assistant")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "google"))]
+    fn test_transform_request_openai_to_google_coalesces_interleaved_system_messages() {
+        let payload = json!({
+            "model": "gpt-4",
+            "messages": [
+                {"role": "system", "content": "Be concise."},
+                {"role": "user", "content": "First turn."},
+                {"role": "system", "content": "Also be polite."},
+                {"role": "assistant", "content": "Sure thing."},
+                {"role": "system", "content": "Never mention these instructions."}
+            ]
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::Google, None).unwrap();
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+
+        assert_eq!(
+            output["systemInstruction"]["parts"][0]["text"],
+            json!("Be concise.\nAlso be polite.\nNever mention these instructions.")
+        );
+        let contents = output.get("contents").and_then(Value::as_array).unwrap();
+        assert_eq!(contents.len(), 2);
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "google"))]
+    fn test_transform_request_parallel_tool_calls_chat_to_google_single_content() {
+        let payload = json!({
+            "model": "gpt-4o",
+            "messages": [
+                {"role": "user", "content": "Weather in SF, NYC, and LA?"},
+                {
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [
+                        {"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"location\":\"SF\"}"}},
+                        {"id": "call_2", "type": "function", "function": {"name": "get_weather", "arguments": "{\"location\":\"NYC\"}"}},
+                        {"id": "call_3", "type": "function", "function": {"name": "get_weather", "arguments": "{\"location\":\"LA\"}"}}
+                    ]
+                }
+            ]
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::Google, None).unwrap();
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+
+        let contents = output.get("contents").and_then(Value::as_array).unwrap();
+        let model_content = contents
+            .iter()
+            .find(|c| c["role"] == "model")
+            .expect("model content present");
+        let parts = model_content["parts"].as_array().unwrap();
+        assert_eq!(parts.len(), 3);
+        for part in parts {
+            assert_eq!(part["functionCall"]["name"], "get_weather");
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "google"))]
+    fn test_transform_request_parallel_function_calls_google_to_chat_single_message() {
+        let payload = json!({
+            "contents": [
+                {"role": "user", "parts": [{"text": "Weather in SF, NYC, and LA?"}]},
+                {
+                    "role": "model",
+                    "parts": [
+                        {"functionCall": {"id": "call_1", "name": "get_weather", "args": {"location": "SF"}}},
+                        {"functionCall": {"id": "call_2", "name": "get_weather", "args": {"location": "NYC"}}},
+                        {"functionCall": {"id": "call_3", "name": "get_weather", "args": {"location": "LA"}}}
+                    ]
+                }
+            ]
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::ChatCompletions, None).unwrap();
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+
+        let messages = output.get("messages").and_then(Value::as_array).unwrap();
+        let assistant_message = messages
+            .iter()
+            .find(|m| m["role"] == "assistant")
+            .expect("assistant message present");
+        let tool_calls = assistant_message["tool_calls"].as_array().unwrap();
+        assert_eq!(tool_calls.len(), 3);
+        let ids: Vec<&str> = tool_calls
+            .iter()
+            .map(|call| call["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, ["call_1", "call_2", "call_3"]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_responses_discovery_tools_to_anthropic() {
+        let payload = json!({
+            "model": "gpt-5.5",
+            "input": [
+                {
+                    "type": "message",
+                    "role": "user",
+                    "content": "Find the available tools."
+                },
+                {
+                    "type": "tool_search_call",
+                    "call_id": "call_tool_search_123",
+                    "status": "completed",
+                    "execution": "client",
+                    "arguments": {}
+                },
+                {
+                    "type": "tool_search_output",
+                    "call_id": "call_tool_search_123",
+                    "status": "completed",
+                    "execution": "client",
+                    "tools": [
+                        {
+                            "type": "function",
+                            "name": "search_docs",
+                            "description": "Search docs.",
+                            "strict": true,
+                            "parameters": {
+                                "type": "object",
+                                "properties": {},
+                                "additionalProperties": false
+                            }
+                        }
+                    ]
+                }
+            ],
+            "tools": [
+                {
+                    "type": "namespace",
+                    "name": "search_code",
+                    "description": "Deferred code search tools.",
+                    "tools": [
+                        {
+                            "type": "function",
+                            "name": "search_code",
+                            "description": "Search code.",
+                            "strict": true,
+                            "parameters": {
+                                "type": "object",
+                                "properties": {},
+                                "additionalProperties": false
+                            },
+                            "defer_loading": true
+                        }
+                    ]
+                },
+                { "type": "tool_search" }
+            ]
         });
         let input = to_bytes(&payload);
 
@@ -1869,6 +2581,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dedupe_identical_tool_definitions() {
+        let payload = json!({
+            "model": "gpt-5.5",
+            "messages": [{"role": "user", "content": "what's the weather?"}],
+            "tools": [
+                {
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "description": "Get the weather for a location",
+                        "parameters": {"type": "object", "properties": {"location": {"type": "string"}}}
+                    }
+                },
+                {
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "description": "Get the weather for a location",
+                        "parameters": {"type": "object", "properties": {"location": {"type": "string"}}}
+                    }
+                }
+            ]
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::ChatCompletions, None).unwrap();
+
+        let value: Value = serde_json::from_slice(result.as_bytes()).unwrap();
+        let tools = value.get("tools").and_then(Value::as_array).unwrap();
+        assert_eq!(
+            tools.len(),
+            1,
+            "identical duplicate tool should be dropped: {tools:?}"
+        );
+    }
+
+    #[test]
+    fn test_conflicting_tool_definitions_errors() {
+        let payload = json!({
+            "model": "gpt-5.5",
+            "messages": [{"role": "user", "content": "what's the weather?"}],
+            "tools": [
+                {
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "description": "Get the weather for a location",
+                        "parameters": {"type": "object", "properties": {"location": {"type": "string"}}}
+                    }
+                },
+                {
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "description": "Get the current weather forecast",
+                        "parameters": {"type": "object", "properties": {"city": {"type": "string"}}}
+                    }
+                }
+            ]
+        });
+        let input = to_bytes(&payload);
+
+        let err = transform_request(input, ProviderFormat::ChatCompletions, None).unwrap_err();
+
+        assert!(matches!(
+            err,
+            TransformError::ConflictingToolDefinition { name } if name == "get_weather"
+        ));
+    }
+
     #[test]
     #[cfg(all(feature = "openai", feature = "anthropic"))]
     fn test_strip_claude_code_billing_header_combined_block_to_openai() {
@@ -2027,6 +2810,55 @@ mod tests {
         assert!(result.is_passthrough());
     }
 
+    #[test]
+    #[cfg(all(feature = "anthropic", feature = "google"))]
+    fn test_transform_request_auto_detects_anthropic_source() {
+        let payload = json!({
+            "model": "claude-3-5-sonnet",
+            "max_tokens": 1024,
+            "messages": [{"role": "user", "content": "Hello"}]
+        });
+        let input = to_bytes(&payload);
+
+        let (bytes, source_format) = transform_request_auto(input, ProviderFormat::Google).unwrap();
+
+        assert_eq!(source_format, ProviderFormat::Anthropic);
+        let transformed: Value = crate::serde_json::from_slice(&bytes).unwrap();
+        assert!(transformed.get("contents").is_some());
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "google"))]
+    fn test_transform_request_auto_detects_openai_source() {
+        let payload = json!({
+            "model": "gpt-4",
+            "messages": [{"role": "user", "content": "Hello"}]
+        });
+        let input = to_bytes(&payload);
+
+        let (bytes, source_format) = transform_request_auto(input, ProviderFormat::Google).unwrap();
+
+        assert_eq!(source_format, ProviderFormat::ChatCompletions);
+        let transformed: Value = crate::serde_json::from_slice(&bytes).unwrap();
+        assert!(transformed.get("contents").is_some());
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "google"))]
+    fn test_transform_request_from_value_accepts_std_serde_json() {
+        // Deliberately the plain `serde_json` crate, not `crate::serde_json`, to
+        // exercise the `IntoLinguaJson` conversion path.
+        let payload = ::serde_json::json!({
+            "model": "gpt-4",
+            "messages": [{"role": "user", "content": "Hello"}]
+        });
+
+        let result = transform_request_from_value(payload, ProviderFormat::Google, None).unwrap();
+
+        let transformed: Value = crate::serde_json::from_slice(&result.into_bytes()).unwrap();
+        assert!(transformed.get("contents").is_some());
+    }
+
     #[test]
     fn test_transform_request_invalid_json() {
         let input = Bytes::from("not valid json");
@@ -2040,6 +2872,35 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_to_openai_error_renders_invalid_request_error() {
+        let err = transform_request(
+            Bytes::from("not valid json"),
+            ProviderFormat::ChatCompletions,
+            None,
+        )
+        .unwrap_err();
+
+        let payload = err.to_openai_error();
+        assert_eq!(payload["error"]["type"], "invalid_request_error");
+        assert_eq!(payload["error"]["message"], err.to_string());
+    }
+
+    #[test]
+    fn test_to_anthropic_error_renders_invalid_request_error() {
+        let err = transform_request(
+            Bytes::from("not valid json"),
+            ProviderFormat::ChatCompletions,
+            None,
+        )
+        .unwrap_err();
+
+        let payload = err.to_anthropic_error();
+        assert_eq!(payload["type"], "error");
+        assert_eq!(payload["error"]["type"], "invalid_request_error");
+        assert_eq!(payload["error"]["message"], err.to_string());
+    }
+
     #[test]
     fn test_transform_request_unable_to_detect_mentions_request() {
         let input = Bytes::from_static(br#"{"not":"a supported request shape"}"#);
@@ -2083,6 +2944,240 @@ mod tests {
         assert!(output.get("content").is_some() || output.get("choices").is_some());
     }
 
+    #[test]
+    #[cfg(feature = "openai")]
+    fn test_transform_request_service_tier_round_trips_through_openai() {
+        let payload = json!({
+            "model": "gpt-4o",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "service_tier": "auto"
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::ChatCompletions, None).unwrap();
+
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+        assert_eq!(
+            output.get("service_tier").and_then(Value::as_str),
+            Some("auto")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "anthropic")]
+    fn test_transform_request_service_tier_round_trips_through_anthropic() {
+        let payload = json!({
+            "model": "claude-opus-4-1",
+            "max_tokens": 256,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "service_tier": "standard_only"
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::Anthropic, None).unwrap();
+
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+        assert_eq!(
+            output.get("service_tier").and_then(Value::as_str),
+            Some("standard_only")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_service_tier_chat_to_anthropic_translates_default_to_auto() {
+        let payload = json!({
+            "model": "gpt-4o",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "service_tier": "default"
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::Anthropic, None).unwrap();
+
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+        assert_eq!(
+            output.get("service_tier").and_then(Value::as_str),
+            Some("auto")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_tool_call_id_matches_result_chat_to_anthropic() {
+        let payload = json!({
+            "model": "gpt-4o",
+            "messages": [
+                {"role": "user", "content": "What's the weather in SF?"},
+                {
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call_abc123",
+                        "type": "function",
+                        "function": {"name": "get_weather", "arguments": "{\"location\":\"SF\"}"}
+                    }]
+                },
+                {"role": "tool", "tool_call_id": "call_abc123", "content": "72F"}
+            ]
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::Anthropic, None).unwrap();
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+
+        let messages = output.get("messages").and_then(Value::as_array).unwrap();
+        let tool_use_id = messages[1]["content"][0]["id"].as_str().unwrap();
+        let tool_result_id = messages[2]["content"][0]["tool_use_id"].as_str().unwrap();
+        assert_eq!(tool_use_id, tool_result_id);
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_tool_call_id_matches_result_anthropic_to_chat() {
+        let payload = json!({
+            "model": "claude-opus-4-1",
+            "max_tokens": 256,
+            "messages": [
+                {"role": "user", "content": "What's the weather in SF?"},
+                {
+                    "role": "assistant",
+                    "content": [{
+                        "type": "tool_use",
+                        "id": "toolu_xyz789",
+                        "name": "get_weather",
+                        "input": {"location": "SF"}
+                    }]
+                },
+                {
+                    "role": "user",
+                    "content": [{"type": "tool_result", "tool_use_id": "toolu_xyz789", "content": "72F"}]
+                }
+            ]
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_request(input, ProviderFormat::ChatCompletions, None).unwrap();
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+
+        let messages = output.get("messages").and_then(Value::as_array).unwrap();
+        let tool_call_id = messages[1]["tool_calls"][0]["id"].as_str().unwrap();
+        let tool_result_id = messages[2]["tool_call_id"].as_str().unwrap();
+        assert_eq!(tool_call_id, tool_result_id);
+    }
+
+    #[test]
+    #[cfg(feature = "openai")]
+    fn test_transform_response_service_tier_round_trips_through_openai() {
+        let payload = json!({
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "model": "gpt-4o",
+            "service_tier": "priority",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "Hello!"},
+                "finish_reason": "stop"
+            }]
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_response(input, ProviderFormat::ChatCompletions)
+            .unwrap()
+            .result;
+
+        let output: Value = crate::serde_json::from_slice(&result.into_bytes()).unwrap();
+        assert_eq!(
+            output.get("service_tier").and_then(Value::as_str),
+            Some("priority")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "anthropic")]
+    fn test_transform_response_service_tier_round_trips_through_anthropic() {
+        let payload = json!({
+            "id": "msg_123",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-opus-4-1",
+            "content": [{"type": "text", "text": "Hello!"}],
+            "stop_reason": "end_turn",
+            "usage": {"input_tokens": 10, "output_tokens": 5, "service_tier": "standard"}
+        });
+        let input = to_bytes(&payload);
+
+        let result = transform_response(input, ProviderFormat::Anthropic)
+            .unwrap()
+            .result;
+
+        let output: Value = crate::serde_json::from_slice(&result.into_bytes()).unwrap();
+        assert_eq!(
+            output
+                .get("usage")
+                .and_then(|u| u.get("service_tier"))
+                .and_then(Value::as_str),
+            Some("standard")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_reasoning_effort_to_anthropic_thinking_budget() {
+        // low=25%, medium=50%, high=75% of max_tokens (see universal::reasoning).
+        for (effort, expected_budget) in [("low", 1024), ("medium", 2048), ("high", 3072)] {
+            let payload = json!({
+                "model": "gpt-5.1",
+                "max_completion_tokens": 4096,
+                "messages": [{"role": "user", "content": "Hello"}],
+                "reasoning_effort": effort
+            });
+            let input = to_bytes(&payload);
+
+            let result = transform_request(input, ProviderFormat::Anthropic, None).unwrap();
+
+            let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+            assert_eq!(
+                output.get("thinking").and_then(|t| t.get("type")),
+                Some(&Value::String("enabled".to_string())),
+                "effort {effort} should enable thinking"
+            );
+            assert_eq!(
+                output
+                    .get("thinking")
+                    .and_then(|t| t.get("budget_tokens"))
+                    .and_then(Value::as_i64),
+                Some(expected_budget),
+                "effort {effort} should map to budget {expected_budget}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "openai", feature = "anthropic"))]
+    fn test_transform_request_anthropic_thinking_budget_to_reasoning_effort() {
+        // Inverse mapping: budget/max_tokens ratio buckets into low/medium/high
+        // (see universal::reasoning::budget_to_effort thresholds).
+        for (budget, expected_effort) in [(1024, "low"), (2048, "medium"), (3072, "high")] {
+            let payload = json!({
+                "model": "claude-opus-4-1",
+                "max_tokens": 4096,
+                "messages": [{"role": "user", "content": "Hello"}],
+                "thinking": {"type": "enabled", "budget_tokens": budget}
+            });
+            let input = to_bytes(&payload);
+
+            let result = transform_request(input, ProviderFormat::ChatCompletions, None).unwrap();
+
+            let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+            assert_eq!(
+                output.get("reasoning_effort").and_then(Value::as_str),
+                Some(expected_effort),
+                "budget {budget} should map to effort {expected_effort}"
+            );
+        }
+    }
+
     #[test]
     fn test_transform_response_unable_to_detect_mentions_response() {
         let input = Bytes::from_static(br#"{"not":"a supported response shape"}"#);
@@ -2234,10 +3329,14 @@ mod tests {
                     provider_options: None,
                     provider_executed: None,
                 }]),
+                name: None,
             }],
             usage: None,
             finish_reason: None,
             finish_reasons: Vec::new(),
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
 
         let chunk = response_to_stream_chunk(response);
@@ -2374,6 +3473,81 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "openai")]
+    fn transform_request_with_config_can_disable_max_tokens_rename() {
+        let payload = json!({
+            "model": "gpt-5.1-mini",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 1000,
+            "seed": 42
+        });
+
+        let config = TransformConfig {
+            rename_max_tokens: false,
+            ..Default::default()
+        };
+        let result = transform_request_with_config(
+            to_bytes(&payload),
+            ProviderFormat::ChatCompletions,
+            None,
+            None,
+            config,
+        )
+        .unwrap();
+
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+        assert_eq!(output.get("max_tokens").and_then(Value::as_i64), Some(1000));
+        assert!(
+            output.get("max_completion_tokens").is_none(),
+            "rename should be disabled"
+        );
+
+        // Default config still renames, confirming the flag made the difference.
+        let default_result =
+            transform_request(to_bytes(&payload), ProviderFormat::ChatCompletions, None).unwrap();
+        let default_output: Value =
+            crate::serde_json::from_slice(default_result.as_bytes()).unwrap();
+        assert!(default_output.get("max_completion_tokens").is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "openai")]
+    fn transform_request_with_config_can_disable_stream_usage_injection() {
+        // Use a reasoning model so the request is force-translated (rather than
+        // passed through unchanged) and the injection actually runs.
+        let payload = json!({
+            "model": "gpt-5.1-mini",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stream": true,
+            "seed": 42
+        });
+
+        let default_result =
+            transform_request(to_bytes(&payload), ProviderFormat::ChatCompletions, None).unwrap();
+        let default_output: Value =
+            crate::serde_json::from_slice(default_result.as_bytes()).unwrap();
+        assert!(default_output.get("stream_options").is_some());
+
+        let config = TransformConfig {
+            inject_stream_include_usage: false,
+            ..Default::default()
+        };
+        let result = transform_request_with_config(
+            to_bytes(&payload),
+            ProviderFormat::ChatCompletions,
+            None,
+            None,
+            config,
+        )
+        .unwrap();
+        let output: Value = crate::serde_json::from_slice(result.as_bytes()).unwrap();
+        assert!(
+            output.get("stream_options").is_none(),
+            "stream_options injection should be disabled"
+        );
+    }
+
     #[test]
     #[cfg(feature = "openai")]
     fn test_reasoning_responses_model_passthrough() {
@@ -2949,4 +4123,158 @@ mod tests {
         );
         assert!(output.get("guardrailConfig").is_some());
     }
+
+    #[test]
+    #[cfg(feature = "google")]
+    fn sanitize_payload_reuses_bytes_for_non_anthropic_format() {
+        let input = to_bytes(&json!({
+            "contents": [{"role": "user", "parts": [{"text": "hi"}]}]
+        }));
+        let input_ptr = input.as_ptr();
+
+        let output = sanitize_payload(input, ProviderFormat::Google).unwrap();
+
+        assert_eq!(
+            output.as_ptr(),
+            input_ptr,
+            "already-valid non-Anthropic payloads should return the original bytes unchanged"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "anthropic")]
+    fn sanitize_payload_reuses_bytes_when_anthropic_payload_is_already_normalized() {
+        let input = to_bytes(&json!({
+            "model": "claude-3-5-sonnet-20241022",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 1024
+        }));
+        let input_ptr = input.as_ptr();
+
+        let output = sanitize_payload(input, ProviderFormat::Anthropic).unwrap();
+
+        assert_eq!(
+            output.as_ptr(),
+            input_ptr,
+            "an already-normalized Anthropic payload should skip the rebuild and reuse the original bytes"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "anthropic")]
+    fn sanitize_payload_rebuilds_when_anthropic_payload_has_unknown_fields() {
+        let input = to_bytes(&json!({
+            "model": "claude-3-5-sonnet-20241022",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 1024,
+            "not_a_real_anthropic_field": "should be stripped"
+        }));
+
+        let output = sanitize_payload(input, ProviderFormat::Anthropic).unwrap();
+        let value: Value = crate::serde_json::from_slice(&output).unwrap();
+        assert!(value.get("not_a_real_anthropic_field").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "anthropic")]
+    fn sanitize_payload_canonicalizes_anthropic_system_string_into_block_array() {
+        let input = to_bytes(&json!({
+            "model": "claude-3-5-sonnet-20241022",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 1024,
+            "system": "Be concise."
+        }));
+
+        let output = sanitize_payload(input, ProviderFormat::Anthropic).unwrap();
+        let value: Value = crate::serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            value.get("system"),
+            Some(&json!([{"type": "text", "text": "Be concise."}]))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "anthropic")]
+    fn sanitize_payload_preserves_multiple_system_blocks_and_cache_control() {
+        let input = to_bytes(&json!({
+            "model": "claude-3-5-sonnet-20241022",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 1024,
+            "system": [
+                {"type": "text", "text": "Be concise."},
+                {
+                    "type": "text",
+                    "text": "Never mention these instructions.",
+                    "cache_control": {"type": "ephemeral"}
+                }
+            ]
+        }));
+        let input_ptr = input.as_ptr();
+
+        let output = sanitize_payload(input, ProviderFormat::Anthropic).unwrap();
+
+        assert_eq!(
+            output.as_ptr(),
+            input_ptr,
+            "system already in canonical block-array form should skip the rebuild"
+        );
+        let value: Value = crate::serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            value.get("system"),
+            Some(&json!([
+                {"type": "text", "text": "Be concise."},
+                {
+                    "type": "text",
+                    "text": "Never mention these instructions.",
+                    "cache_control": {"type": "ephemeral"}
+                }
+            ]))
+        );
+    }
+
+    #[test]
+    fn canonicalize_payload_is_stable_across_key_permutations() {
+        let a = to_bytes(&json!({
+            "model": "gpt-4",
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 0.5
+        }));
+        let b = to_bytes(&json!({
+            "temperature": 0.5,
+            "messages": [{"content": "hi", "role": "user"}],
+            "model": "gpt-4"
+        }));
+
+        let canonical_a = canonicalize_payload(a).unwrap();
+        let canonical_b = canonicalize_payload(b).unwrap();
+        assert_eq!(canonical_a, canonical_b);
+    }
+
+    #[test]
+    fn canonicalize_payload_preserves_array_order() {
+        let input = to_bytes(&json!({
+            "messages": [
+                {"role": "system", "content": "first"},
+                {"role": "user", "content": "second"}
+            ]
+        }));
+        let reordered = to_bytes(&json!({
+            "messages": [
+                {"role": "user", "content": "second"},
+                {"role": "system", "content": "first"}
+            ]
+        }));
+
+        let canonical_input = canonicalize_payload(input).unwrap();
+        let canonical_reordered = canonicalize_payload(reordered).unwrap();
+        assert_ne!(
+            canonical_input, canonical_reordered,
+            "array order is semantically significant and must not be normalized away"
+        );
+
+        let value: Value = crate::serde_json::from_slice(&canonical_input).unwrap();
+        let messages = value["messages"].as_array().unwrap();
+        assert_eq!(messages[0]["content"], "first");
+        assert_eq!(messages[1]["content"], "second");
+    }
 }
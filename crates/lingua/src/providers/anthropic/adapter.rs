@@ -17,7 +17,8 @@ use crate::processing::transform::TransformError;
 use crate::providers::anthropic::capabilities;
 use crate::providers::anthropic::convert::{
     anthropic_cache_control_from_universal, anthropic_input_messages_to_universal_messages,
-    system_to_user_content, universal_messages_to_anthropic_input_messages,
+    canonicalize_content_style, system_to_user_content,
+    universal_messages_to_anthropic_input_messages, AnthropicContentStyle,
 };
 use crate::providers::anthropic::detect::{
     system_messages_are_supported_and_well_placed, try_parse_anthropic_source,
@@ -31,18 +32,20 @@ use crate::providers::anthropic::tool_discovery;
 use crate::providers::anthropic::try_parse_anthropic;
 use crate::serde_json::{self, Map, Value};
 use crate::universal::convert::TryFromLLM;
-use crate::universal::message::{CacheControl, Message, UserContent, UserContentPart};
+use crate::universal::message::{
+    CacheControl, Message, ProviderOptions, UserContent, UserContentPart,
+};
 use crate::universal::reasoning::budget_to_effort;
 use crate::universal::request::{
-    ReasoningCanonical, ReasoningConfig, ReasoningEffort, ResponseFormatConfig, ToolChoiceConfig,
-    UniversalMetadataUserView,
+    ReasoningCanonical, ReasoningConfig, ReasoningEffort, ResponseFormatConfig, ResponseFormatType,
+    ToolChoiceConfig, UniversalMetadataUserView,
 };
 use crate::universal::tools::{UniversalTool, UniversalToolType};
 use crate::universal::{
-    FinishReason, TokenBudget, UniversalParams, UniversalReasoningDelta, UniversalRequest,
-    UniversalResponse, UniversalStreamChoice, UniversalStreamChunk, UniversalStreamDelta,
-    UniversalToolCallDelta, UniversalToolFunctionDelta, UniversalUsage, PLACEHOLDER_ID,
-    PLACEHOLDER_MODEL,
+    extract_system_messages, FinishReason, TokenBudget, UniversalError, UniversalParams,
+    UniversalReasoningDelta, UniversalRequest, UniversalResponse, UniversalStreamChoice,
+    UniversalStreamChunk, UniversalStreamDelta, UniversalToolCallDelta, UniversalToolFunctionDelta,
+    UniversalUsage, PLACEHOLDER_ID, PLACEHOLDER_MODEL,
 };
 use serde::Deserialize;
 
@@ -104,36 +107,6 @@ fn anthropic_system_text_block(
     Ok(Value::Object(block))
 }
 
-fn extract_leading_system_messages(messages: &mut Vec<Message>) -> Vec<UserContent> {
-    let mut system_contents = Vec::new();
-
-    while matches!(
-        messages.first(),
-        Some(Message::System { .. } | Message::Developer { .. })
-    ) {
-        let message = messages.remove(0);
-        if let Message::System { content } | Message::Developer { content } = message {
-            system_contents.push(content);
-        }
-    }
-
-    system_contents
-}
-
-fn validate_no_non_leading_system_messages(messages: &[Message]) -> Result<(), TransformError> {
-    if messages
-        .iter()
-        .any(|message| matches!(message, Message::System { .. } | Message::Developer { .. }))
-    {
-        return Err(TransformError::ValidationFailed {
-            target: ProviderFormat::Anthropic,
-            reason: "Anthropic generated types include system-role input messages, but the live Messages API currently rejects role 'system' for available models; non-leading system/developer messages cannot be exported to Anthropic without changing semantics".to_string(),
-        });
-    }
-
-    Ok(())
-}
-
 fn is_forced_tool_choice(value: &Value) -> bool {
     let parsed: Result<ToolChoice, _> = serde_json::from_value(value.clone());
     parsed
@@ -196,6 +169,24 @@ fn is_json_object_response_format(config: Option<&ResponseFormatConfig>) -> bool
         .is_some_and(|t| t == crate::universal::request::ResponseFormatType::JsonObject)
 }
 
+/// True when `tools`/`tool_choice` are exactly the synthetic single-tool shim that
+/// [`ProviderAdapter::request_from_universal`] emits for `response_format: json_object`,
+/// meaning the request should be read back as `json_object` mode rather than as a
+/// real tool call forced by the caller.
+fn is_json_object_shim_request(tools: &[Tool], tool_choice: Option<&ToolChoice>) -> bool {
+    let [Tool::Custom(tool)] = tools else {
+        return false;
+    };
+    let is_shim_tool = tool.name == JSON_OBJECT_SHIM_TOOL_NAME
+        && tool.description.as_deref() == Some(JSON_OBJECT_SHIM_TOOL_DESCRIPTION)
+        && tool.input_schema == serde_json::json!({ "type": "object" });
+    let is_shim_tool_choice = tool_choice.is_some_and(|tc| {
+        tc.tool_choice_type == ToolChoiceType::Tool
+            && tc.name.as_deref() == Some(JSON_OBJECT_SHIM_TOOL_NAME)
+    });
+    is_shim_tool && is_shim_tool_choice
+}
+
 fn maybe_unwrap_json_shim_tool_call(messages: &mut [Message]) {
     for message in messages {
         let Message::Assistant { content, .. } = message else {
@@ -291,6 +282,10 @@ impl ProviderAdapter for AnthropicAdapter {
             );
         }
 
+        let is_json_object_shim = typed_params.tools.as_deref().is_some_and(|tools| {
+            is_json_object_shim_request(tools, typed_params.tool_choice.as_ref())
+        });
+
         let mut params = UniversalParams {
             temperature: typed_params.temperature,
             top_p: typed_params.top_p,
@@ -310,6 +305,7 @@ impl ProviderAdapter for AnthropicAdapter {
                 .and_then(|oc| oc.format.as_ref())
                 .or(raw_params_view.output_format.as_ref())
                 .map(ResponseFormatConfig::from),
+            modalities: None, // Anthropic has no output modality selection
             seed: None,
             presence_penalty: None,
             frequency_penalty: None,
@@ -361,6 +357,18 @@ impl ProviderAdapter for AnthropicAdapter {
             extras: Default::default(),
         };
 
+        if is_json_object_shim && params.response_format.is_none() {
+            // Read the json_object shim back into `response_format` rather than
+            // surfacing it as a real forced tool call, so it survives a round trip
+            // through Anthropic and back out as `{"type": "json_object"}` for OpenAI.
+            params.tools = None;
+            params.tool_choice = None;
+            params.response_format = Some(ResponseFormatConfig {
+                format_type: Some(ResponseFormatType::JsonObject),
+                json_schema: None,
+            });
+        }
+
         let anthropic_extras = params.extras.entry(ProviderFormat::Anthropic).or_default();
         for (key, value) in raw_payload_obj {
             anthropic_extras.insert(key, value);
@@ -374,395 +382,14 @@ impl ProviderAdapter for AnthropicAdapter {
     }
 
     fn request_from_universal(&self, req: &UniversalRequest) -> Result<Value, TransformError> {
-        let model = req.model.as_ref().ok_or(TransformError::ValidationFailed {
-            target: ProviderFormat::Anthropic,
-            reason: "missing model".to_string(),
-        })?;
-
-        let anthropic_extras = req.params.extras.get(&ProviderFormat::Anthropic);
-        let anthropic_extras_view = parse_anthropic_extras(anthropic_extras)?;
-
-        // Clone messages and extract only leading system/developer messages to top-level `system`.
-        // Later instructions cannot be moved there without changing their placement.
-        let mut msgs = req.messages.clone();
-        let system_contents = extract_leading_system_messages(&mut msgs);
-
-        let mut obj = Map::new();
-        obj.insert("model".into(), Value::String(model.clone()));
-
-        if let Some(raw_messages) = anthropic_extras_view.messages.as_ref() {
-            if system_messages_are_supported_and_well_placed(model, raw_messages)
-                .map_err(|e| TransformError::FromUniversalFailed(e.to_string()))?
-            {
-                obj.insert("messages".into(), raw_messages.clone());
-            } else {
-                if msgs.is_empty() {
-                    let reason = if system_contents.is_empty() {
-                        "Anthropic requires at least one message in 'messages'.".to_string()
-                    } else {
-                        "Anthropic requires at least one non-system message; a system prompt alone cannot be sent because Anthropic stores system prompts in the top-level 'system' field and requires at least one user or assistant message in 'messages'.".to_string()
-                    };
-                    return Err(TransformError::ValidationFailed {
-                        target: ProviderFormat::Anthropic,
-                        reason,
-                    });
-                }
-                validate_no_non_leading_system_messages(&msgs)?;
-                let anthropic_messages = universal_messages_to_anthropic_input_messages(msgs)
-                    .map_err(|e| TransformError::FromUniversalFailed(e.to_string()))?;
-                obj.insert(
-                    "messages".into(),
-                    serde_json::to_value(anthropic_messages)
-                        .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
-                );
-            }
-        } else {
-            if msgs.is_empty() {
-                let reason = if system_contents.is_empty() {
-                    "Anthropic requires at least one message in 'messages'.".to_string()
-                } else {
-                    "Anthropic requires at least one non-system message; a system prompt alone cannot be sent because Anthropic stores system prompts in the top-level 'system' field and requires at least one user or assistant message in 'messages'.".to_string()
-                };
-                return Err(TransformError::ValidationFailed {
-                    target: ProviderFormat::Anthropic,
-                    reason,
-                });
-            }
-            validate_no_non_leading_system_messages(&msgs)?;
-            // Convert remaining messages
-            let anthropic_messages = universal_messages_to_anthropic_input_messages(msgs)
-                .map_err(|e| TransformError::FromUniversalFailed(e.to_string()))?;
-            obj.insert(
-                "messages".into(),
-                serde_json::to_value(anthropic_messages)
-                    .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
-            );
-        }
-
-        // Add system message if present
-        if let Some(raw_system) = anthropic_extras_view.system.as_ref() {
-            obj.insert("system".into(), raw_system.clone());
-        } else if !system_contents.is_empty() {
-            let has_cache_control = system_contents.iter().any(|c| match c {
-                UserContent::Array(parts) => parts
-                    .iter()
-                    .any(|p| matches!(p, UserContentPart::Text(t) if t.cache_control.is_some())),
-                UserContent::String(_) => false,
-            });
-
-            if has_cache_control {
-                let mut blocks: Vec<Value> = Vec::new();
-                for content in system_contents {
-                    match content {
-                        UserContent::String(s) => {
-                            blocks.push(anthropic_system_text_block(s, None)?)
-                        }
-                        UserContent::Array(parts) => {
-                            for part in parts {
-                                if let UserContentPart::Text(t) = part {
-                                    blocks.push(anthropic_system_text_block(
-                                        t.text,
-                                        t.cache_control,
-                                    )?);
-                                }
-                            }
-                        }
-                    }
-                }
-                obj.insert("system".into(), Value::Array(blocks));
-            } else {
-                let system_text: String = system_contents
-                    .into_iter()
-                    .map(|c| match c {
-                        UserContent::String(s) => s,
-                        UserContent::Array(parts) => parts
-                            .into_iter()
-                            .filter_map(|p| {
-                                if let UserContentPart::Text(t) = p {
-                                    Some(t.text)
-                                } else {
-                                    None
-                                }
-                            })
-                            .collect::<Vec<_>>()
-                            .join("\n"),
-                    })
-                    .collect::<Vec<_>>()
-                    .join("\n\n");
-                obj.insert("system".into(), Value::String(system_text));
-            }
-        }
-
-        // max_tokens is required for Anthropic - use the value from params or default
-        let max_tokens = req
-            .params
-            .output_token_budget()
-            .unwrap_or(DEFAULT_MAX_TOKENS);
-        obj.insert("max_tokens".into(), Value::Number(max_tokens.into()));
-
-        // Determine reasoning style based on model capability and source:
-        // - Opus 4.7/4.8 → thinking.type=adaptive + output_config.effort
-        // - Opus 4.5/4.6 with effort canonical → output_config.effort
-        // - All other cases → thinking object (legacy, broad model support)
-        // Both branches use output_config.format for structured output (never output_format).
-        let reasoning_config = req.params.reasoning.as_ref();
-        let reasoning_is_disabled = reasoning_config.is_some_and(reasoning_is_disabled);
-        let use_adaptive_thinking = capabilities::supports_adaptive_thinking(model)
-            && reasoning_config.is_some_and(reasoning_is_enabled);
-        let use_effort = capabilities::supports_output_config_effort(model)
-            && reasoning_config.is_some_and(|r| {
-                r.canonical == Some(ReasoningCanonical::Effort) || use_adaptive_thinking
-            });
-
-        let thinking_val = if use_adaptive_thinking {
-            Some(
-                serde_json::to_value(&Thinking {
-                    budget_tokens: None,
-                    display: None,
-                    thinking_type: ThinkingType::Adaptive,
-                })
-                .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
-            )
-        } else if use_effort {
-            // These models think by default when `thinking` is omitted, so an explicit
-            // opt-out (effort=none / enabled=false) emits `thinking: {type: "disabled"}`.
-            // Fable 5 / Mythos 5 reject `disabled` (thinking is always on), so for those
-            // models omit `thinking` instead and preserve the always-on adaptive default.
-            if reasoning_is_disabled && capabilities::supports_disabling_thinking(model) {
-                Some(
-                    serde_json::to_value(&Thinking {
-                        budget_tokens: None,
-                        display: None,
-                        thinking_type: ThinkingType::Disabled,
-                    })
-                    .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
-                )
-            } else {
-                None
-            }
-        } else {
-            req.params.reasoning_for(ProviderFormat::Anthropic)
-        };
-
-        let reasoning_enabled =
-            use_effort || thinking_val.as_ref().is_some_and(is_enabled_thinking);
-        if let Some(raw_temp) = anthropic_extras_view.temperature.as_ref() {
-            obj.insert("temperature".into(), raw_temp.clone());
-        } else if !reasoning_enabled {
-            insert_opt_f64(&mut obj, "temperature", req.params.temperature);
-        }
-
-        insert_opt_f64(&mut obj, "top_p", req.params.top_p);
-        insert_opt_i64(&mut obj, "top_k", req.params.top_k);
-
-        // Anthropic uses stop_sequences instead of stop
-        if let Some(ref stop) = req.params.stop {
-            if !stop.is_empty() {
-                obj.insert(
-                    "stop_sequences".into(),
-                    Value::Array(stop.iter().map(|s| Value::String(s.clone())).collect()),
-                );
-            }
-        }
-
-        let use_json_object_shim =
-            is_json_object_response_format(req.params.response_format.as_ref())
-                && anthropic_extras_view.tools.is_none()
-                && anthropic_extras_view.tool_choice.is_none();
-
-        let mut tools_for_anthropic = req.params.tools.clone().unwrap_or_default();
-        for discovered_tool in tool_discovery::discovered_tools_from_messages(&req.messages) {
-            if !tools_for_anthropic
-                .iter()
-                .any(|tool| tool.name == discovered_tool.name)
-            {
-                tools_for_anthropic.push(discovered_tool);
-            }
-        }
-        tools_for_anthropic = tool_discovery::normalize_tools_for_anthropic(tools_for_anthropic)?;
-        if tool_discovery::has_tool_discovery(&req.messages)
-            && !tools_for_anthropic
-                .iter()
-                .any(tool_discovery::is_anthropic_tool_search_builtin)
-        {
-            tools_for_anthropic.push(tool_discovery::anthropic_tool_search_tool());
-        }
-
-        // Convert tools to Anthropic format
-        if let Some(raw_tools) = anthropic_extras_view.tools.as_ref() {
-            obj.insert("tools".into(), raw_tools.clone());
-        } else if use_json_object_shim {
-            obj.insert(
-                "tools".into(),
-                serde_json::json!([{
-                    "name": JSON_OBJECT_SHIM_TOOL_NAME,
-                    "description": JSON_OBJECT_SHIM_TOOL_DESCRIPTION,
-                    "input_schema": { "type": "object" }
-                }]),
-            );
-        } else if !tools_for_anthropic.is_empty() {
-            let anthropic_tools = tools_for_anthropic
-                .iter()
-                .map(anthropic_tool_value)
-                .collect::<Result<Vec<_>, _>>()?;
-            obj.insert("tools".into(), Value::Array(anthropic_tools));
-        }
-
-        // Convert tool_choice using helper method (handles parallel_tool_calls internally)
-        let tool_choice_value =
-            if let Some(raw_tool_choice) = anthropic_extras_view.tool_choice.as_ref() {
-                Some(raw_tool_choice.clone())
-            } else if use_json_object_shim {
-                Some(serde_json::json!({
-                    "type": "tool",
-                    "name": JSON_OBJECT_SHIM_TOOL_NAME
-                }))
-            } else {
-                req.params.tool_choice_for(ProviderFormat::Anthropic)
-            };
-        let forced_tool_choice = tool_choice_value
-            .as_ref()
-            .is_some_and(is_forced_tool_choice);
-        if let Some(tool_choice_val) = tool_choice_value {
-            obj.insert("tool_choice".into(), tool_choice_val);
-        }
-        insert_opt_bool(&mut obj, "stream", req.params.stream);
-
-        // Build output_config (always used for structured output format, and for effort on Opus 4.5+)
-        // Forced tool_choice is incompatible with active thinking. The thinking guard below
-        // drops the `thinking` object in that case; drop `effort` too so the request does not
-        // ask for reasoning the guard just disabled. `format` is independent of thinking.
-        let effort_level = if use_effort && !forced_tool_choice {
-            reasoning_effort_level(reasoning_config, Some(max_tokens))
-        } else {
-            None
-        };
-        let format = if use_json_object_shim {
-            None
-        } else {
-            req.params
-                .response_format
-                .as_ref()
-                .and_then(|rf| rf.try_into().ok())
-        };
-
-        let raw_output_config = anthropic_extras_view.output_config.as_ref();
-        let raw_thinking = anthropic_extras_view.thinking.as_ref();
-
-        if use_adaptive_thinking {
-            // Same-provider Anthropic round-trips carry the original `output_config` in
-            // extras. Prefer it verbatim so distinct effort values (e.g. "xhigh" vs "max",
-            // which the universal ReasoningEffort enum collapses) survive unchanged. Only
-            // reconstruct when there is no raw output_config.
-            if let Some(raw_output_config) = raw_output_config {
-                let mut output_config = raw_output_config.clone();
-                if forced_tool_choice {
-                    if let Some(obj) = output_config.as_object_mut() {
-                        obj.remove("effort");
-                    }
-                }
-                if let Some(format) = format {
-                    let format_value = serde_json::to_value(&format)
-                        .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
-                    output_config
-                        .as_object_mut()
-                        .ok_or_else(|| {
-                            TransformError::FromUniversalFailed(
-                                "output_config extras is not an object".to_string(),
-                            )
-                        })?
-                        .entry("format")
-                        .or_insert(format_value);
-                }
-                obj.insert("output_config".into(), output_config);
-            } else if effort_level.is_some() || format.is_some() {
-                let output_config = OutputConfig {
-                    effort: effort_level,
-                    format,
-                };
-                obj.insert(
-                    "output_config".into(),
-                    serde_json::to_value(&output_config)
-                        .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
-                );
-            }
-        } else if let Some(raw_output_config) = raw_output_config {
-            obj.insert("output_config".into(), raw_output_config.clone());
-        } else if effort_level.is_some() || format.is_some() {
-            let output_config = OutputConfig {
-                effort: effort_level,
-                format,
-            };
-            obj.insert(
-                "output_config".into(),
-                serde_json::to_value(&output_config)
-                    .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
-            );
-        }
-
-        // Add thinking for legacy reasoning (non-Opus models)
-        if use_adaptive_thinking {
-            if let Some(thinking) = thinking_val {
-                if !(forced_tool_choice && is_enabled_thinking(&thinking)) {
-                    obj.insert("thinking".into(), thinking);
-                }
-            }
-        } else if let Some(raw_thinking) = raw_thinking {
-            if !(forced_tool_choice && is_enabled_thinking(raw_thinking)) {
-                obj.insert("thinking".into(), raw_thinking.clone());
-            }
-        } else if raw_output_config.is_none() {
-            if let Some(thinking) = thinking_val {
-                if !(forced_tool_choice && is_enabled_thinking(&thinking)) {
-                    obj.insert("thinking".into(), thinking);
-                }
-            }
-        }
-
-        // Add metadata from canonical params
-        if let Some(raw_metadata) = anthropic_extras_view.metadata.as_ref() {
-            obj.insert("metadata".into(), raw_metadata.clone());
-        } else if let Some(metadata) = req.params.metadata.as_ref() {
-            // Anthropic metadata only supports `user_id`.
-            let metadata_view: UniversalMetadataUserView =
-                serde_json::from_value(metadata.clone()).unwrap_or_default();
-            if let Some(user_id) = metadata_view.user_id {
-                let mut anthropic_metadata = Map::new();
-                anthropic_metadata.insert("user_id".into(), Value::String(user_id));
-                obj.insert("metadata".into(), Value::Object(anthropic_metadata));
-            }
-        }
-
-        // Add service_tier from canonical params
-        // Map OpenAI's "default" to Anthropic's "auto" (Anthropic only accepts "auto" or "standard_only")
-        if let Some(ref service_tier) = req.params.service_tier {
-            let anthropic_tier = match service_tier.as_str() {
-                "default" => "auto",
-                other => other,
-            };
-            obj.insert(
-                "service_tier".into(),
-                Value::String(anthropic_tier.to_string()),
-            );
-        }
-
-        // Merge back provider-specific extras (only for Anthropic)
-        if let Some(extras) = req.params.extras.get(&ProviderFormat::Anthropic) {
-            for (k, v) in extras {
-                if k == "output_format" {
-                    continue;
-                }
-                // Don't overwrite canonical fields we already handled
-                if !obj.contains_key(k) {
-                    obj.insert(k.clone(), v.clone());
-                }
-            }
-        }
-
-        // Enforce model-specific transforms (e.g. strip sampling params for Opus 4.7).
-        capabilities::apply_model_transforms(model, &mut obj);
+        anthropic_request_from_universal(req, false)
+    }
 
-        Ok(Value::Object(obj))
+    fn request_from_universal_unchecked(
+        &self,
+        req: &UniversalRequest,
+    ) -> Result<Value, TransformError> {
+        anthropic_request_from_universal(req, true)
     }
 
     fn apply_defaults(&self, req: &mut UniversalRequest) {
@@ -810,6 +437,22 @@ impl ProviderAdapter for AnthropicAdapter {
 
         let usage = UniversalUsage::extract_from_response(&payload, self.format());
 
+        // `stop_sequence` (which sequence triggered the stop) has no OpenAI equivalent,
+        // so it's preserved as provider-options rather than a first-class field - a
+        // round trip back to Anthropic restores it in `response_from_universal`.
+        let provider_options =
+            payload
+                .get("stop_sequence")
+                .and_then(Value::as_str)
+                .map(|stop_sequence| {
+                    let mut options = Map::new();
+                    options.insert(
+                        "stop_sequence".into(),
+                        Value::String(stop_sequence.to_string()),
+                    );
+                    ProviderOptions { options }
+                });
+
         Ok(UniversalResponse {
             id: UniversalResponse::extract_id_from_payload(&payload),
             id_format: Some(self.format()),
@@ -821,6 +464,13 @@ impl ProviderAdapter for AnthropicAdapter {
             usage,
             finish_reason: finish_reason.clone(),
             finish_reasons: finish_reason.into_iter().collect(),
+            system_fingerprint: None,
+            provider_options,
+            service_tier: payload
+                .get("usage")
+                .and_then(|usage| usage.get("service_tier"))
+                .and_then(Value::as_str)
+                .map(String::from),
         })
     }
 
@@ -848,9 +498,26 @@ impl ProviderAdapter for AnthropicAdapter {
             Value::String(resp.model.as_deref().unwrap_or(PLACEHOLDER_MODEL).into()),
         );
         map.insert("stop_reason".into(), Value::String(stop_reason));
+        map.insert(
+            "stop_sequence".into(),
+            resp.provider_options
+                .as_ref()
+                .and_then(|opts| opts.options.get("stop_sequence"))
+                .cloned()
+                .unwrap_or(Value::Null),
+        );
 
         if let Some(usage) = &resp.usage {
-            map.insert("usage".into(), usage.to_provider_value(self.format()));
+            let mut usage_value = usage.to_provider_value(self.format());
+            if let (Some(service_tier), Some(usage_obj)) =
+                (resp.service_tier.as_deref(), usage_value.as_object_mut())
+            {
+                usage_obj.insert(
+                    "service_tier".into(),
+                    Value::String(service_tier.to_string()),
+                );
+            }
+            map.insert("usage".into(), usage_value);
         }
 
         Ok(Value::Object(map))
@@ -895,8 +562,10 @@ impl ProviderAdapter for AnthropicAdapter {
                 if delta_type == Some("text_delta") {
                     let text = delta.and_then(|d| d.get("text")).and_then(Value::as_str);
 
+                    // `role` is only attached to the `message_start` opener below;
+                    // Anthropic's own `content_block_delta` never repeats it, and
+                    // strict OpenAI-target clients expect it on the first chunk only.
                     let delta = UniversalStreamDelta {
-                        role: Some("assistant".to_string()),
                         content: match text {
                             Some(t) if !t.is_empty() => Some(t.to_string()),
                             _ => None,
@@ -954,7 +623,6 @@ impl ProviderAdapter for AnthropicAdapter {
                         return Ok(Some(UniversalStreamChunk::keep_alive()));
                     }
                     let delta = UniversalStreamDelta {
-                        role: Some("assistant".to_string()),
                         reasoning: vec![UniversalReasoningDelta {
                             content: Some(thinking),
                         }],
@@ -983,7 +651,6 @@ impl ProviderAdapter for AnthropicAdapter {
                         return Ok(Some(UniversalStreamChunk::keep_alive()));
                     }
                     let delta = UniversalStreamDelta {
-                        role: Some("assistant".to_string()),
                         reasoning_signature: Some(signature.to_string()),
                         ..Default::default()
                     };
@@ -1120,7 +787,6 @@ impl ProviderAdapter for AnthropicAdapter {
                         vec![UniversalStreamChoice {
                             index: block_index,
                             delta: Some(Value::from(UniversalStreamDelta {
-                                role: Some("assistant".to_string()),
                                 tool_calls: vec![UniversalToolCallDelta {
                                     index: Some(block_index),
                                     id: Some(id.to_string()),
@@ -1150,7 +816,6 @@ impl ProviderAdapter for AnthropicAdapter {
                         return Ok(Some(UniversalStreamChunk::keep_alive()));
                     }
                     let delta = UniversalStreamDelta {
-                        role: Some("assistant".to_string()),
                         reasoning: vec![UniversalReasoningDelta {
                             content: Some(thinking.to_string()),
                         }],
@@ -1181,11 +846,20 @@ impl ProviderAdapter for AnthropicAdapter {
         }
     }
 
+    /// Anthropic's Messages API streams a single candidate per request. When the
+    /// source produced multiple choices (e.g. OpenAI's `n > 1`), only choice
+    /// index 0 is forwarded to the client; chunks that exclusively carry other
+    /// indices are downgraded to a no-op `ping` rather than being misrepresented
+    /// as the primary candidate.
     fn stream_from_universal(&self, chunk: &UniversalStreamChunk) -> Result<Value, TransformError> {
         if chunk.is_keep_alive() {
             return Ok(serde_json::json!({"type": "ping"}));
         }
 
+        if !chunk.choices.is_empty() && chunk.choices.iter().all(|c| c.index != 0) {
+            return Ok(serde_json::json!({"type": "ping"}));
+        }
+
         let has_finish = chunk
             .choices
             .first()
@@ -1426,17 +1100,464 @@ impl ProviderAdapter for AnthropicAdapter {
                 }
                 return Ok(obj);
             }
-            return Ok(serde_json::json!({}));
+            return Ok(serde_json::json!({}));
+        }
+
+        Ok(serde_json::json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": {
+                "type": "text_delta",
+                "text": ""
+            }
+        }))
+    }
+
+    fn error_to_universal(&self, payload: Value) -> Result<UniversalError, TransformError> {
+        let error = payload.get("error").unwrap_or(&payload);
+        Ok(UniversalError {
+            message: error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            error_type: error.get("type").and_then(Value::as_str).map(String::from),
+            code: None,
+            param: None,
+        })
+    }
+
+    fn error_from_universal(&self, err: &UniversalError) -> Value {
+        serde_json::json!({
+            "type": "error",
+            "error": {
+                "type": err.error_type.clone().unwrap_or_else(|| "api_error".into()),
+                "message": err.message,
+            }
+        })
+    }
+}
+
+/// Shared body for [`ProviderAdapter::request_from_universal`] and
+/// [`ProviderAdapter::request_from_universal_unchecked`]. `unchecked` skips the
+/// `InputMessage` schema round trip used to validate a caller's raw extras
+/// messages, since a trusted caller's `UniversalRequest` was never built from
+/// an untrusted wire payload in the first place.
+fn anthropic_request_from_universal(
+    req: &UniversalRequest,
+    unchecked: bool,
+) -> Result<Value, TransformError> {
+    let model = req.model.as_ref().ok_or(TransformError::ValidationFailed {
+        target: ProviderFormat::Anthropic,
+        reason: "missing model".to_string(),
+    })?;
+
+    let anthropic_extras = req.params.extras.get(&ProviderFormat::Anthropic);
+    let anthropic_extras_view = parse_anthropic_extras(anthropic_extras)?;
+
+    // Anthropic has a single top-level `system` field, not a per-position system
+    // role, so every system/developer message (wherever it appears) is extracted
+    // and concatenated in order, joined by blank lines to preserve separation.
+    let mut msgs = req.messages.clone();
+    let system_contents = extract_system_messages(&mut msgs);
+
+    let mut obj = Map::new();
+    obj.insert("model".into(), Value::String(model.clone()));
+
+    if let Some(raw_messages) = anthropic_extras_view.messages.as_ref() {
+        // A trusted caller's `UniversalRequest` was never built from an
+        // untrusted wire payload, so its raw Anthropic extras are already
+        // known to be well-formed; skip the `InputMessage` schema
+        // round trip that exists to catch a hand-written client's mistakes.
+        if unchecked
+            || system_messages_are_supported_and_well_placed(model, raw_messages)
+                .map_err(|e| TransformError::FromUniversalFailed(e.to_string()))?
+        {
+            obj.insert("messages".into(), raw_messages.clone());
+        } else {
+            if msgs.is_empty() {
+                let reason = if system_contents.is_empty() {
+                    "Anthropic requires at least one message in 'messages'.".to_string()
+                } else {
+                    "Anthropic requires at least one non-system message; a system prompt alone cannot be sent because Anthropic stores system prompts in the top-level 'system' field and requires at least one user or assistant message in 'messages'.".to_string()
+                };
+                return Err(TransformError::ValidationFailed {
+                    target: ProviderFormat::Anthropic,
+                    reason,
+                });
+            }
+            let anthropic_messages = universal_messages_to_anthropic_input_messages(msgs)
+                .map_err(|e| TransformError::FromUniversalFailed(e.to_string()))?;
+            obj.insert(
+                "messages".into(),
+                serde_json::to_value(anthropic_messages)
+                    .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
+            );
+        }
+    } else {
+        if msgs.is_empty() {
+            let reason = if system_contents.is_empty() {
+                "Anthropic requires at least one message in 'messages'.".to_string()
+            } else {
+                "Anthropic requires at least one non-system message; a system prompt alone cannot be sent because Anthropic stores system prompts in the top-level 'system' field and requires at least one user or assistant message in 'messages'.".to_string()
+            };
+            return Err(TransformError::ValidationFailed {
+                target: ProviderFormat::Anthropic,
+                reason,
+            });
+        }
+        // Convert remaining messages
+        let anthropic_messages = universal_messages_to_anthropic_input_messages(msgs)
+            .map_err(|e| TransformError::FromUniversalFailed(e.to_string()))?;
+        obj.insert(
+            "messages".into(),
+            serde_json::to_value(anthropic_messages)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
+        );
+    }
+
+    // Add system message if present
+    if let Some(raw_system) = anthropic_extras_view.system.as_ref() {
+        obj.insert("system".into(), raw_system.clone());
+    } else if !system_contents.is_empty() {
+        let has_cache_control = system_contents.iter().any(|c| match c {
+            UserContent::Array(parts) => parts
+                .iter()
+                .any(|p| matches!(p, UserContentPart::Text(t) if t.cache_control.is_some())),
+            UserContent::String(_) => false,
+        });
+
+        if has_cache_control {
+            let mut blocks: Vec<Value> = Vec::new();
+            for content in system_contents {
+                match content {
+                    UserContent::String(s) => blocks.push(anthropic_system_text_block(s, None)?),
+                    UserContent::Array(parts) => {
+                        for part in parts {
+                            if let UserContentPart::Text(t) = part {
+                                blocks.push(anthropic_system_text_block(t.text, t.cache_control)?);
+                            }
+                        }
+                    }
+                }
+            }
+            obj.insert("system".into(), Value::Array(blocks));
+        } else {
+            let system_text: String = system_contents
+                .into_iter()
+                .map(|c| match c {
+                    UserContent::String(s) => s,
+                    UserContent::Array(parts) => parts
+                        .into_iter()
+                        .filter_map(|p| {
+                            if let UserContentPart::Text(t) = p {
+                                Some(t.text)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            obj.insert("system".into(), Value::String(system_text));
+        }
+    }
+
+    // max_tokens is required for Anthropic - use the value from params or default
+    let max_tokens = req
+        .params
+        .output_token_budget()
+        .unwrap_or(DEFAULT_MAX_TOKENS);
+    obj.insert("max_tokens".into(), Value::Number(max_tokens.into()));
+
+    // Determine reasoning style based on model capability and source:
+    // - Opus 4.7+, Sonnet 5+ and Fable (supports_adaptive_thinking) with reasoning
+    //   enabled → thinking.type=adaptive + output_config.effort
+    // - Opus 4.5/4.6 with effort canonical → output_config.effort
+    // - All other cases → thinking object (legacy, broad model support)
+    // Both branches use output_config.format for structured output (never output_format).
+    let reasoning_config = req.params.reasoning.as_ref();
+    let reasoning_is_disabled = reasoning_config.is_some_and(reasoning_is_disabled);
+    let use_adaptive_thinking = capabilities::supports_adaptive_thinking(model)
+        && reasoning_config.is_some_and(reasoning_is_enabled);
+    let use_effort = capabilities::supports_output_config_effort(model)
+        && reasoning_config.is_some_and(|r| {
+            r.canonical == Some(ReasoningCanonical::Effort) || use_adaptive_thinking
+        });
+
+    let thinking_val = if use_adaptive_thinking {
+        Some(
+            serde_json::to_value(&Thinking {
+                budget_tokens: None,
+                display: None,
+                thinking_type: ThinkingType::Adaptive,
+            })
+            .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
+        )
+    } else if use_effort {
+        // These models think by default when `thinking` is omitted, so an explicit
+        // opt-out (effort=none / enabled=false) emits `thinking: {type: "disabled"}`.
+        // Fable 5 / Mythos 5 reject `disabled` (thinking is always on), so for those
+        // models omit `thinking` instead and preserve the always-on adaptive default.
+        if reasoning_is_disabled && capabilities::supports_disabling_thinking(model) {
+            Some(
+                serde_json::to_value(&Thinking {
+                    budget_tokens: None,
+                    display: None,
+                    thinking_type: ThinkingType::Disabled,
+                })
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
+            )
+        } else {
+            None
+        }
+    } else {
+        req.params.reasoning_for(ProviderFormat::Anthropic)
+    };
+
+    let reasoning_enabled = use_effort || thinking_val.as_ref().is_some_and(is_enabled_thinking);
+    if let Some(raw_temp) = anthropic_extras_view.temperature.as_ref() {
+        obj.insert("temperature".into(), raw_temp.clone());
+    } else if !reasoning_enabled {
+        insert_opt_f64(&mut obj, "temperature", req.params.temperature);
+    }
+
+    insert_opt_f64(&mut obj, "top_p", req.params.top_p);
+    insert_opt_i64(&mut obj, "top_k", req.params.top_k);
+
+    // Anthropic uses stop_sequences instead of stop
+    if let Some(ref stop) = req.params.stop {
+        if !stop.is_empty() {
+            obj.insert(
+                "stop_sequences".into(),
+                Value::Array(stop.iter().map(|s| Value::String(s.clone())).collect()),
+            );
+        }
+    }
+
+    let use_json_object_shim = is_json_object_response_format(req.params.response_format.as_ref())
+        && anthropic_extras_view.tools.is_none()
+        && anthropic_extras_view.tool_choice.is_none();
+
+    let mut tools_for_anthropic = req.params.tools.clone().unwrap_or_default();
+    for discovered_tool in tool_discovery::discovered_tools_from_messages(&req.messages) {
+        if !tools_for_anthropic
+            .iter()
+            .any(|tool| tool.name == discovered_tool.name)
+        {
+            tools_for_anthropic.push(discovered_tool);
+        }
+    }
+    tools_for_anthropic = tool_discovery::normalize_tools_for_anthropic(tools_for_anthropic)?;
+    if tool_discovery::has_tool_discovery(&req.messages)
+        && !tools_for_anthropic
+            .iter()
+            .any(tool_discovery::is_anthropic_tool_search_builtin)
+    {
+        tools_for_anthropic.push(tool_discovery::anthropic_tool_search_tool());
+    }
+
+    // Convert tools to Anthropic format
+    if let Some(raw_tools) = anthropic_extras_view.tools.as_ref() {
+        obj.insert("tools".into(), raw_tools.clone());
+    } else if use_json_object_shim {
+        obj.insert(
+            "tools".into(),
+            serde_json::json!([{
+                "name": JSON_OBJECT_SHIM_TOOL_NAME,
+                "description": JSON_OBJECT_SHIM_TOOL_DESCRIPTION,
+                "input_schema": { "type": "object" }
+            }]),
+        );
+    } else if !tools_for_anthropic.is_empty() {
+        let anthropic_tools = tools_for_anthropic
+            .iter()
+            .map(anthropic_tool_value)
+            .collect::<Result<Vec<_>, _>>()?;
+        obj.insert("tools".into(), Value::Array(anthropic_tools));
+    }
+
+    // Convert tool_choice using helper method (handles parallel_tool_calls internally)
+    let tool_choice_value =
+        if let Some(raw_tool_choice) = anthropic_extras_view.tool_choice.as_ref() {
+            Some(raw_tool_choice.clone())
+        } else if use_json_object_shim {
+            Some(serde_json::json!({
+                "type": "tool",
+                "name": JSON_OBJECT_SHIM_TOOL_NAME
+            }))
+        } else {
+            req.params.tool_choice_for(ProviderFormat::Anthropic)
+        };
+    let forced_tool_choice = tool_choice_value
+        .as_ref()
+        .is_some_and(is_forced_tool_choice);
+    if let Some(tool_choice_val) = tool_choice_value {
+        obj.insert("tool_choice".into(), tool_choice_val);
+    }
+    insert_opt_bool(&mut obj, "stream", req.params.stream);
+
+    // Build output_config (always used for structured output format, and for effort on Opus 4.5+)
+    // Forced tool_choice is incompatible with active thinking. The thinking guard below
+    // drops the `thinking` object in that case; drop `effort` too so the request does not
+    // ask for reasoning the guard just disabled. `format` is independent of thinking.
+    let effort_level = if use_effort && !forced_tool_choice {
+        reasoning_effort_level(reasoning_config, Some(max_tokens))
+    } else {
+        None
+    };
+    let format = if use_json_object_shim {
+        None
+    } else {
+        req.params
+            .response_format
+            .as_ref()
+            .and_then(|rf| rf.try_into().ok())
+    };
+
+    let raw_output_config = anthropic_extras_view.output_config.as_ref();
+    let raw_thinking = anthropic_extras_view.thinking.as_ref();
+
+    if use_adaptive_thinking {
+        // Same-provider Anthropic round-trips carry the original `output_config` in
+        // extras. Prefer it verbatim so distinct effort values (e.g. "xhigh" vs "max",
+        // which the universal ReasoningEffort enum collapses) survive unchanged. Only
+        // reconstruct when there is no raw output_config.
+        if let Some(raw_output_config) = raw_output_config {
+            let mut output_config = raw_output_config.clone();
+            if forced_tool_choice {
+                if let Some(obj) = output_config.as_object_mut() {
+                    obj.remove("effort");
+                }
+            }
+            if let Some(format) = format {
+                let format_value = serde_json::to_value(&format)
+                    .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
+                output_config
+                    .as_object_mut()
+                    .ok_or_else(|| {
+                        TransformError::FromUniversalFailed(
+                            "output_config extras is not an object".to_string(),
+                        )
+                    })?
+                    .entry("format")
+                    .or_insert(format_value);
+            }
+            obj.insert("output_config".into(), output_config);
+        } else if effort_level.is_some() || format.is_some() {
+            let output_config = OutputConfig {
+                effort: effort_level,
+                format,
+            };
+            obj.insert(
+                "output_config".into(),
+                serde_json::to_value(&output_config)
+                    .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
+            );
+        }
+    } else if let Some(raw_output_config) = raw_output_config {
+        obj.insert("output_config".into(), raw_output_config.clone());
+    } else if effort_level.is_some() || format.is_some() {
+        let output_config = OutputConfig {
+            effort: effort_level,
+            format,
+        };
+        obj.insert(
+            "output_config".into(),
+            serde_json::to_value(&output_config)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))?,
+        );
+    }
+
+    // Add thinking for legacy reasoning (non-Opus models)
+    if use_adaptive_thinking {
+        if let Some(thinking) = thinking_val {
+            if !(forced_tool_choice && is_enabled_thinking(&thinking)) {
+                obj.insert("thinking".into(), thinking);
+            }
+        }
+    } else if let Some(raw_thinking) = raw_thinking {
+        if !(forced_tool_choice && is_enabled_thinking(raw_thinking)) {
+            obj.insert("thinking".into(), raw_thinking.clone());
+        }
+    } else if raw_output_config.is_none() {
+        if let Some(thinking) = thinking_val {
+            if !(forced_tool_choice && is_enabled_thinking(&thinking)) {
+                obj.insert("thinking".into(), thinking);
+            }
+        }
+    }
+
+    // Add metadata from canonical params
+    if let Some(raw_metadata) = anthropic_extras_view.metadata.as_ref() {
+        obj.insert("metadata".into(), raw_metadata.clone());
+    } else if let Some(metadata) = req.params.metadata.as_ref() {
+        // Anthropic metadata only supports `user_id`.
+        let metadata_view: UniversalMetadataUserView =
+            serde_json::from_value(metadata.clone()).unwrap_or_default();
+        if let Some(user_id) = metadata_view.user_id {
+            let mut anthropic_metadata = Map::new();
+            anthropic_metadata.insert("user_id".into(), Value::String(user_id));
+            obj.insert("metadata".into(), Value::Object(anthropic_metadata));
+        }
+    }
+
+    // Add service_tier from canonical params
+    // Map OpenAI's "default" to Anthropic's "auto" (Anthropic only accepts "auto" or "standard_only")
+    if let Some(ref service_tier) = req.params.service_tier {
+        let anthropic_tier = match service_tier.as_str() {
+            "default" => "auto",
+            other => other,
+        };
+        obj.insert(
+            "service_tier".into(),
+            Value::String(anthropic_tier.to_string()),
+        );
+    }
+
+    // Merge back provider-specific extras (only for Anthropic)
+    if let Some(extras) = req.params.extras.get(&ProviderFormat::Anthropic) {
+        for (k, v) in extras {
+            if k == "output_format" {
+                continue;
+            }
+            // Don't overwrite canonical fields we already handled
+            if !obj.contains_key(k) {
+                obj.insert(k.clone(), v.clone());
+            }
         }
+    }
 
-        Ok(serde_json::json!({
-            "type": "content_block_delta",
-            "index": 0,
-            "delta": {
-                "type": "text_delta",
-                "text": ""
-            }
-        }))
+    // Enforce model-specific transforms (e.g. strip sampling params for Opus 4.7).
+    capabilities::apply_model_transforms(model, &mut obj);
+
+    Ok(Value::Object(obj))
+}
+
+impl AnthropicAdapter {
+    /// Same as [`ProviderAdapter::request_from_universal`], but canonicalizes
+    /// the shape of single, plain-text message content to the given
+    /// [`AnthropicContentStyle`] instead of leaving it however the universal
+    /// request happened to represent it. Use this when a caller needs the
+    /// emitted content shape (string vs. single-item block array) to stay
+    /// stable across repeated conversions.
+    pub fn request_from_universal_with_content_style(
+        &self,
+        req: &UniversalRequest,
+        style: AnthropicContentStyle,
+    ) -> Result<Value, TransformError> {
+        let mut value = self.request_from_universal(req)?;
+        if let Some(messages) = value.get_mut("messages") {
+            let mut input_messages: Vec<crate::providers::anthropic::generated::InputMessage> =
+                serde_json::from_value(messages.take())
+                    .map_err(|e| TransformError::DeserializationFailed(e.to_string()))?;
+            canonicalize_content_style(&mut input_messages, style);
+            *messages = serde_json::to_value(input_messages)
+                .map_err(|e| TransformError::SerializationFailed(e.to_string()))?;
+        }
+        Ok(value)
     }
 }
 
@@ -1690,6 +1811,7 @@ mod tests {
                 },
                 Message::User {
                     content: UserContent::String("say ok".to_string()),
+                    name: None,
                 },
             ],
             params: UniversalParams {
@@ -1725,6 +1847,7 @@ mod tests {
                 },
                 Message::User {
                     content: UserContent::String("say ok".to_string()),
+                    name: None,
                 },
             ],
             params: UniversalParams {
@@ -1778,7 +1901,7 @@ mod tests {
     }
 
     #[test]
-    fn test_anthropic_rejects_non_leading_system_message() {
+    fn test_anthropic_coalesces_interleaved_system_messages() {
         let adapter = AnthropicAdapter;
         let req = UniversalRequest {
             model: Some("claude-3-5-sonnet-20241022".to_string()),
@@ -1788,10 +1911,18 @@ mod tests {
                 },
                 Message::User {
                     content: UserContent::String("First turn.".to_string()),
+                    name: None,
                 },
                 Message::System {
                     content: UserContent::String("Use the updated policy.".to_string()),
                 },
+                Message::User {
+                    content: UserContent::String("Second turn.".to_string()),
+                    name: None,
+                },
+                Message::Developer {
+                    content: UserContent::String("Stay concise.".to_string()),
+                },
             ],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(1024)),
@@ -1799,8 +1930,14 @@ mod tests {
             },
         };
 
-        let err = adapter.request_from_universal(&req).unwrap_err();
-        assert!(format!("{err}").contains("live Messages API currently rejects"));
+        let value = adapter.request_from_universal(&req).unwrap();
+        assert_eq!(
+            value["system"],
+            crate::serde_json::json!(
+                "Use the initial policy.\n\nUse the updated policy.\n\nStay concise."
+            )
+        );
+        assert_eq!(value["messages"].as_array().unwrap().len(), 2);
     }
 
     #[test]
@@ -1815,6 +1952,7 @@ mod tests {
             model: Some("claude-sonnet-4-20250514".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 temperature: Some(0.5), // User specified, but should be omitted
@@ -1851,6 +1989,7 @@ mod tests {
             model: Some("claude-3-5-sonnet-20241022".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 temperature: Some(0.7),
@@ -1883,6 +2022,7 @@ mod tests {
             model: Some("claude-sonnet-4-5-20250929".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Tokyo weather".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(4096)),
@@ -1920,6 +2060,7 @@ mod tests {
             model: Some("claude-sonnet-4-5-20250929".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Tokyo weather".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(4096)),
@@ -1957,6 +2098,7 @@ mod tests {
             model: Some("claude-sonnet-4-5-20250929".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Tokyo weather".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(4096)),
@@ -2000,6 +2142,7 @@ mod tests {
                 model: Some(model.to_string()),
                 messages: vec![Message::User {
                     content: UserContent::String("Hello".to_string()),
+                    name: None,
                 }],
                 params: UniversalParams {
                     temperature: Some(0.7),
@@ -2041,6 +2184,7 @@ mod tests {
             model: Some("claude-opus-4-7".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("What is 2+2?".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 reasoning: Some(ReasoningConfig {
@@ -2082,6 +2226,7 @@ mod tests {
                 model: Some(model.to_string()),
                 messages: vec![Message::User {
                     content: UserContent::String("What is 2+2?".to_string()),
+                    name: None,
                 }],
                 params: UniversalParams {
                     reasoning: Some(ReasoningConfig {
@@ -2259,6 +2404,7 @@ mod tests {
             model: Some("claude-sonnet-5".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Use calc with x=2".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(4096)),
@@ -2313,6 +2459,7 @@ mod tests {
             model: Some("claude-sonnet-5".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Use calc with x=2".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(4096)),
@@ -2366,6 +2513,7 @@ mod tests {
             model: Some("claude-sonnet-4-5-20250929".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Use calc with x=2".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(4096)),
@@ -2401,6 +2549,7 @@ mod tests {
             model: Some("claude-sonnet-5".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Use calc with x=2".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(4096)),
@@ -2478,6 +2627,7 @@ mod tests {
             model: Some("claude-opus-4-8".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("What is 2+2?".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 reasoning: Some(ReasoningConfig {
@@ -2547,6 +2697,7 @@ mod tests {
             model: Some("us.anthropic.claude-opus-4-7-v1:0".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 temperature: Some(0.5),
@@ -2594,6 +2745,7 @@ mod tests {
             model: Some("claude-opus-4-7".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(1024)),
@@ -2619,6 +2771,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_request_from_universal_unchecked_matches_checked_for_well_formed_request() {
+        use crate::capabilities::ProviderFormat;
+        use crate::universal::message::UserContent;
+        use std::collections::HashMap;
+
+        let adapter = AnthropicAdapter;
+
+        let raw_messages = json!([
+            {"role": "user", "content": "Hello"},
+            {"role": "assistant", "content": "Hi there!"},
+        ]);
+        let mut anthropic_extras = Map::new();
+        anthropic_extras.insert("messages".into(), raw_messages);
+        let mut extras_map: HashMap<ProviderFormat, Map<String, Value>> = HashMap::new();
+        extras_map.insert(ProviderFormat::Anthropic, anthropic_extras);
+
+        let req = UniversalRequest {
+            model: Some("claude-sonnet-4-5-20250929".to_string()),
+            messages: vec![Message::User {
+                content: UserContent::String("Hello".to_string()),
+                name: None,
+            }],
+            params: UniversalParams {
+                token_budget: Some(TokenBudget::OutputTokens(1024)),
+                extras: extras_map,
+                ..Default::default()
+            },
+        };
+
+        let checked = adapter.request_from_universal(&req).unwrap();
+        let unchecked = adapter.request_from_universal_unchecked(&req).unwrap();
+        assert_eq!(
+            checked, unchecked,
+            "unchecked path must produce the same output as the checked path for a well-formed request"
+        );
+    }
+
     #[test]
     fn test_anthropic_strips_temperature_for_fable() {
         use crate::capabilities::ProviderFormat;
@@ -2635,6 +2825,7 @@ mod tests {
             model: Some("claude-fable-5".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 temperature: Some(0.7),
@@ -2674,6 +2865,7 @@ mod tests {
                 model: Some(model.to_string()),
                 messages: vec![Message::User {
                     content: UserContent::String("Hello".to_string()),
+                    name: None,
                 }],
                 params: UniversalParams {
                     temperature: Some(0.7),
@@ -2863,6 +3055,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_anthropic_json_object_shim_round_trips_to_openai_json_object() {
+        use crate::providers::openai::adapter::OpenAIAdapter;
+
+        let openai_adapter = OpenAIAdapter;
+        let anthropic_adapter = AnthropicAdapter;
+
+        let openai_payload = json!({
+            "model": "gpt-4o",
+            "messages": [{"role": "user", "content": "Return JSON"}],
+            "response_format": { "type": "json_object" }
+        });
+
+        let mut universal = openai_adapter.request_to_universal(openai_payload).unwrap();
+        universal.model = Some("claude-sonnet-4-5-20250929".to_string());
+        anthropic_adapter.apply_defaults(&mut universal);
+
+        let anthropic_request = anthropic_adapter
+            .request_from_universal(&universal)
+            .unwrap();
+
+        // Reading the shimmed request back should recover `json_object` mode
+        // rather than surfacing the shim as a caller-forced tool call.
+        let round_tripped = anthropic_adapter
+            .request_to_universal(anthropic_request)
+            .unwrap();
+        let response_format = round_tripped
+            .params
+            .response_format
+            .as_ref()
+            .expect("response_format should be recovered from the json shim");
+        assert_eq!(
+            response_format.format_type,
+            Some(ResponseFormatType::JsonObject)
+        );
+        assert!(response_format.json_schema.is_none());
+        assert!(round_tripped.params.tools.is_none());
+        assert!(round_tripped.params.tool_choice.is_none());
+
+        let openai_out = openai_adapter
+            .request_from_universal(&round_tripped)
+            .unwrap();
+        assert_eq!(
+            openai_out.get("response_format"),
+            Some(&json!({ "type": "json_object" }))
+        );
+        assert!(openai_out.get("tools").is_none());
+    }
+
     #[test]
     fn responses_namespace_duplicate_local_tool_names_are_rejected_for_anthropic() {
         use crate::processing::adapters::ProviderAdapter;
@@ -3031,6 +3272,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_anthropic_response_roundtrip_preserves_stop_sequence() {
+        let adapter = AnthropicAdapter;
+        let payload = json!({
+            "id": "msg_test",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-5-20250929",
+            "stop_reason": "stop_sequence",
+            "stop_sequence": "\n\nHuman:",
+            "content": [{ "type": "text", "text": "Sure, here you go." }]
+        });
+
+        let universal = adapter.response_to_universal(payload).unwrap();
+        assert_eq!(universal.finish_reason, Some(FinishReason::Stop));
+        let stop_sequence = universal
+            .provider_options
+            .as_ref()
+            .and_then(|opts| opts.options.get("stop_sequence"))
+            .and_then(Value::as_str);
+        assert_eq!(stop_sequence, Some("\n\nHuman:"));
+
+        let openai_stop_reason = universal
+            .finish_reason
+            .as_ref()
+            .map(|r| r.to_provider_string(ProviderFormat::ChatCompletions));
+        assert_eq!(openai_stop_reason, Some("stop"));
+
+        let roundtripped = adapter.response_from_universal(&universal).unwrap();
+        assert_eq!(
+            roundtripped.get("stop_sequence"),
+            Some(&Value::String("\n\nHuman:".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_anthropic_response_from_universal_defaults_stop_sequence_to_null() {
+        let adapter = AnthropicAdapter;
+        let payload = json!({
+            "id": "msg_test",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-5-20250929",
+            "stop_reason": "end_turn",
+            "content": [{ "type": "text", "text": "Hi there." }]
+        });
+
+        let universal = adapter.response_to_universal(payload).unwrap();
+        assert!(universal.provider_options.is_none());
+
+        let roundtripped = adapter.response_from_universal(&universal).unwrap();
+        assert_eq!(roundtripped.get("stop_sequence"), Some(&Value::Null));
+    }
+
     #[test]
     fn test_stream_to_universal_thinking_delta_semantic_chunk() {
         let adapter = AnthropicAdapter;
@@ -3102,6 +3397,36 @@ mod tests {
         assert_eq!(first.content.as_deref(), Some("initial thought"),);
     }
 
+    #[test]
+    fn test_stream_to_universal_message_start_preserves_cache_tokens() {
+        let adapter = AnthropicAdapter;
+        let payload = json!({
+            "type": "message_start",
+            "message": {
+                "id": "msg_test",
+                "type": "message",
+                "role": "assistant",
+                "model": "claude-sonnet-4-5-20250929",
+                "content": [],
+                "usage": {
+                    "input_tokens": 10,
+                    "output_tokens": 0,
+                    "cache_creation_input_tokens": 30,
+                    "cache_read_input_tokens": 20
+                }
+            }
+        });
+
+        let chunk = adapter
+            .stream_to_universal(payload)
+            .expect("stream_to_universal should succeed")
+            .expect("message_start should emit a chunk");
+
+        let usage = chunk.usage.expect("usage must be present");
+        assert_eq!(usage.prompt_cached_tokens, Some(20));
+        assert_eq!(usage.prompt_cache_creation_tokens, Some(30));
+    }
+
     #[test]
     fn test_stream_to_universal_message_stop_returns_none() {
         let adapter = AnthropicAdapter;
@@ -3118,4 +3443,248 @@ mod tests {
             "message_stop should return None (terminal event)"
         );
     }
+
+    #[test]
+    fn test_stream_from_universal_downgrades_secondary_choice_index() {
+        let adapter = AnthropicAdapter;
+
+        // OpenAI's `n > 1` streams interleave chunks by choice index; only
+        // index 0 has a place in Anthropic's single-candidate stream.
+        let secondary = UniversalStreamChunk::new(
+            Some("chatcmpl-test".to_string()),
+            Some("gpt-4o".to_string()),
+            vec![UniversalStreamChoice {
+                index: 1,
+                delta: Some(json!({"content": "second candidate"})),
+                finish_reason: None,
+            }],
+            None,
+            None,
+        );
+        let out = adapter.stream_from_universal(&secondary).unwrap();
+        assert_eq!(out["type"], "ping", "non-zero choice index is downgraded");
+
+        let primary = UniversalStreamChunk::new(
+            Some("chatcmpl-test".to_string()),
+            Some("gpt-4o".to_string()),
+            vec![UniversalStreamChoice {
+                index: 0,
+                delta: Some(json!({"content": "first candidate"})),
+                finish_reason: None,
+            }],
+            None,
+            None,
+        );
+        let out = adapter.stream_from_universal(&primary).unwrap();
+        assert_eq!(out["type"], "content_block_delta");
+        assert_eq!(out["delta"]["text"], "first candidate");
+    }
+
+    #[test]
+    fn test_content_style_stable_across_chat_anthropic_round_trip() {
+        use crate::processing::adapters::ProviderAdapter;
+        use crate::providers::openai::adapter::OpenAIAdapter;
+
+        let openai_adapter = OpenAIAdapter;
+        let anthropic_adapter = AnthropicAdapter;
+
+        let chat_payload = json!({
+            "model": "gpt-5",
+            "messages": [{"role": "user", "content": "hi"}]
+        });
+
+        let universal = openai_adapter
+            .request_to_universal(chat_payload)
+            .expect("chat request should parse");
+
+        let anthropic_request = anthropic_adapter
+            .request_from_universal_with_content_style(&universal, AnthropicContentStyle::String)
+            .expect("anthropic conversion should succeed");
+
+        assert_eq!(
+            anthropic_request["messages"][0]["content"], "hi",
+            "String style should keep a single text part as a bare string"
+        );
+
+        let anthropic_universal = anthropic_adapter
+            .request_to_universal(anthropic_request)
+            .expect("anthropic request should parse back to universal");
+        let chat_again = openai_adapter
+            .request_from_universal(&anthropic_universal)
+            .expect("chat conversion should succeed");
+
+        assert_eq!(
+            chat_again["messages"][0]["content"], "hi",
+            "content shape should be stable across a chat -> anthropic -> chat round trip"
+        );
+    }
+
+    #[test]
+    fn test_cross_provider_openai_user_maps_to_anthropic_user_id() {
+        use crate::processing::adapters::ProviderAdapter;
+        use crate::providers::openai::adapter::OpenAIAdapter;
+
+        // OpenAI's deprecated `user` field and its `safety_identifier` replacement both tag
+        // the end user for abuse tracking; both must survive a transform into Anthropic's
+        // `metadata.user_id` so tracking continuity isn't lost when proxying across providers.
+        for openai_payload in [
+            json!({
+                "model": "gpt-5",
+                "messages": [{"role": "user", "content": "Hello"}],
+                "user": "user-123"
+            }),
+            json!({
+                "model": "gpt-5",
+                "messages": [{"role": "user", "content": "Hello"}],
+                "safety_identifier": "user-123"
+            }),
+        ] {
+            let openai_adapter = OpenAIAdapter;
+            let anthropic_adapter = AnthropicAdapter;
+
+            let mut universal = openai_adapter.request_to_universal(openai_payload).unwrap();
+            universal.model = Some("claude-sonnet-4-5".to_string());
+
+            let anthropic_payload = anthropic_adapter
+                .request_from_universal(&universal)
+                .unwrap();
+            let result: CreateMessageParams = serde_json::from_value(anthropic_payload).unwrap();
+
+            assert_eq!(
+                result.metadata.and_then(|m| m.user_id),
+                Some("user-123".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_cross_provider_openai_store_is_dropped_for_anthropic_target() {
+        use crate::processing::adapters::ProviderAdapter;
+        use crate::providers::openai::adapter::OpenAIAdapter;
+
+        // `store` has no Anthropic equivalent; it must not leak into the Anthropic
+        // payload when routing an OpenAI request whose canonical `store: true` was set.
+        let openai_adapter = OpenAIAdapter;
+        let anthropic_adapter = AnthropicAdapter;
+
+        let openai_payload = json!({
+            "model": "gpt-4o-mini",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "store": true
+        });
+
+        let mut universal = openai_adapter.request_to_universal(openai_payload).unwrap();
+        assert_eq!(universal.params.store, Some(true));
+        universal.model = Some("claude-sonnet-4-5".to_string());
+
+        let anthropic_payload = anthropic_adapter
+            .request_from_universal(&universal)
+            .unwrap();
+
+        assert!(anthropic_payload.get("store").is_none());
+    }
+
+    #[test]
+    fn test_cross_provider_anthropic_user_id_maps_to_openai_safety_identifier() {
+        use crate::processing::adapters::ProviderAdapter;
+        use crate::providers::openai::adapter::OpenAIAdapter;
+        use crate::providers::openai::generated::CreateChatCompletionRequestClass;
+
+        let anthropic_adapter = AnthropicAdapter;
+        let openai_adapter = OpenAIAdapter;
+
+        let anthropic_payload = json!({
+            "model": "claude-sonnet-4-5",
+            "max_tokens": 1024,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "metadata": { "user_id": "user-456" }
+        });
+
+        let mut universal = anthropic_adapter
+            .request_to_universal(anthropic_payload)
+            .unwrap();
+        universal.model = Some("gpt-5".to_string());
+
+        let openai_payload = openai_adapter.request_from_universal(&universal).unwrap();
+        let result: CreateChatCompletionRequestClass =
+            serde_json::from_value(openai_payload).unwrap();
+
+        assert_eq!(result.safety_identifier.as_deref(), Some("user-456"));
+    }
+
+    #[test]
+    fn test_anthropic_metadata_survives_same_provider_round_trip() {
+        let adapter = AnthropicAdapter;
+        let payload = json!({
+            "model": "claude-sonnet-4-5",
+            "max_tokens": 1024,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "metadata": { "user_id": "user-456" }
+        });
+
+        let universal = adapter.request_to_universal(payload.clone()).unwrap();
+        let output = adapter.request_from_universal(&universal).unwrap();
+
+        assert_eq!(output.get("metadata"), payload.get("metadata"));
+    }
+
+    #[test]
+    fn test_error_to_universal_parses_invalid_request_error() {
+        let adapter = AnthropicAdapter;
+        let payload = json!({
+            "type": "error",
+            "error": {
+                "type": "invalid_request_error",
+                "message": "max_tokens: field required"
+            }
+        });
+
+        let error = adapter.error_to_universal(payload).unwrap();
+        assert_eq!(error.message, "max_tokens: field required");
+        assert_eq!(error.error_type.as_deref(), Some("invalid_request_error"));
+    }
+
+    #[test]
+    fn test_error_from_universal_builds_anthropic_shape() {
+        let adapter = AnthropicAdapter;
+        let universal = UniversalError {
+            message: "max_tokens: field required".to_string(),
+            error_type: Some("invalid_request_error".to_string()),
+            code: None,
+            param: None,
+        };
+
+        let payload = adapter.error_from_universal(&universal);
+        assert_eq!(payload["type"], "error");
+        assert_eq!(payload["error"]["type"], "invalid_request_error");
+        assert_eq!(payload["error"]["message"], "max_tokens: field required");
+    }
+
+    #[test]
+    fn test_cross_provider_anthropic_error_maps_to_openai_shape() {
+        use crate::processing::adapters::ProviderAdapter;
+        use crate::providers::openai::adapter::OpenAIAdapter;
+
+        let anthropic_adapter = AnthropicAdapter;
+        let openai_adapter = OpenAIAdapter;
+
+        let anthropic_payload = json!({
+            "type": "error",
+            "error": {
+                "type": "invalid_request_error",
+                "message": "max_tokens: field required"
+            }
+        });
+
+        let universal = anthropic_adapter
+            .error_to_universal(anthropic_payload)
+            .unwrap();
+        let openai_payload = openai_adapter.error_from_universal(&universal);
+
+        assert_eq!(
+            openai_payload["error"]["message"],
+            "max_tokens: field required"
+        );
+        assert_eq!(openai_payload["error"]["type"], "invalid_request_error");
+    }
 }
@@ -29,6 +29,42 @@ static ALWAYS_ON_THINKING_RE: LazyLock<Regex> = LazyLock::new(|| {
     )
     .expect("valid always-on thinking model regex")
 });
+
+// Models that support Anthropic's 1M-token context beta: every `claude-sonnet-4` id
+// (Sonnet 4 and 4.5, and any other `claude-sonnet-4-*` id, dated or not).
+static SUPPORTS_1M_CONTEXT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(^|[./:@])claude-sonnet-4(?:[-.]5)?($|[-./:@])")
+        .expect("valid 1M-context model regex")
+});
+
+/// `anthropic-beta` header value that unlocks the 1M-token context window.
+pub const CONTEXT_1M_BETA: &str = "context-1m-2025-08-07";
+
+/// Default Claude context window, in tokens, for models without extended context.
+pub const DEFAULT_CONTEXT_WINDOW_TOKENS: i64 = 200_000;
+
+/// Context window, in tokens, for models with the 1M-context beta enabled.
+pub const EXTENDED_CONTEXT_WINDOW_TOKENS: i64 = 1_000_000;
+
+/// Check if a model supports Anthropic's 1M-token context beta.
+pub fn supports_1m_context(model: &str) -> bool {
+    let lower = model.to_ascii_lowercase();
+    SUPPORTS_1M_CONTEXT_RE.is_match(&lower)
+}
+
+/// The model's context window in tokens, accounting for the 1M-context beta.
+pub fn context_window_tokens(model: &str) -> i64 {
+    if supports_1m_context(model) {
+        EXTENDED_CONTEXT_WINDOW_TOKENS
+    } else {
+        DEFAULT_CONTEXT_WINDOW_TOKENS
+    }
+}
+
+/// The `anthropic-beta` header value to add for this model's extended context, if any.
+pub fn context_beta_header(model: &str) -> Option<&'static str> {
+    supports_1m_context(model).then_some(CONTEXT_1M_BETA)
+}
 /// Check if a model supports `output_config.effort` (vs legacy `thinking`).
 ///
 /// Opus 4.5+ and Sonnet 5+ models support this. All models support `thinking` as fallback.
@@ -345,6 +381,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_supports_1m_context() {
+        for model in [
+            "claude-sonnet-4",
+            "claude-sonnet-4-5",
+            "claude-sonnet-4.5",
+            "claude-sonnet-4-5-20250929",
+            "CLAUDE-SONNET-4-5",
+            "us.anthropic.claude-sonnet-4-5-v1:0",
+            "anthropic/claude-sonnet-4-5@20250929",
+        ] {
+            assert!(supports_1m_context(model), "model: {}", model);
+        }
+
+        for model in [
+            "claude-opus-4-5",
+            "claude-opus-4-7",
+            "claude-haiku-4-5",
+            "claude-sonnet-5",
+            "claude-3-5-sonnet-20241022",
+            "gpt-5.5",
+        ] {
+            assert!(!supports_1m_context(model), "model: {}", model);
+        }
+    }
+
+    #[test]
+    fn test_context_window_tokens() {
+        assert_eq!(
+            context_window_tokens("claude-sonnet-4-5-20250929"),
+            EXTENDED_CONTEXT_WINDOW_TOKENS
+        );
+        assert_eq!(
+            context_window_tokens("claude-opus-4-5"),
+            DEFAULT_CONTEXT_WINDOW_TOKENS
+        );
+    }
+
+    #[test]
+    fn test_context_beta_header() {
+        assert_eq!(
+            context_beta_header("claude-sonnet-4-5-20250929"),
+            Some(CONTEXT_1M_BETA)
+        );
+        assert_eq!(context_beta_header("claude-opus-4-5"), None);
+    }
+
     #[test]
     fn test_get_model_transforms() {
         let cases = [
@@ -420,6 +420,26 @@ fn normalize_anthropic_tool_schema(
     Ok(schema)
 }
 
+/// Canonicalize Anthropic's `system` field into the block-array form.
+///
+/// `system` accepts either a plain string or an array of text blocks; a plain
+/// string is just sugar for a single block with no `cache_control`. Used by
+/// `sanitize_payload` so a string-shaped and an equivalent single-block
+/// `system` sanitize to identical bytes instead of diffing on shape alone.
+pub(crate) fn canonicalize_system_field(system: generated::System) -> generated::System {
+    match system {
+        generated::System::PurpleString(text) => {
+            generated::System::RequestTextBlockArray(vec![generated::RequestTextBlock {
+                cache_control: None,
+                citations: None,
+                text,
+                request_text_block_type: generated::SystemType::Text,
+            }])
+        }
+        blocks @ generated::System::RequestTextBlockArray(_) => blocks,
+    }
+}
+
 /// Convert Anthropic's standalone `system` field into universal `UserContent`.
 ///
 /// This is message-shape conversion logic, so it lives in `convert.rs` rather
@@ -695,7 +715,10 @@ impl TryFromLLM<generated::InputMessage> for Message {
                     }
                 };
 
-                Ok(Message::User { content })
+                Ok(Message::User {
+                    content,
+                    name: None,
+                })
             }
             generated::MessageRole::Assistant => {
                 let content = match input_msg.content {
@@ -856,7 +879,11 @@ impl TryFromLLM<generated::InputMessage> for Message {
                     }
                 };
 
-                Ok(Message::Assistant { content, id: None })
+                Ok(Message::Assistant {
+                    content,
+                    id: None,
+                    name: None,
+                })
             }
         }
     }
@@ -869,7 +896,7 @@ impl TryFromLLM<Message> for generated::InputMessage {
 
     fn try_from(msg: Message) -> Result<Self, Self::Error> {
         match msg {
-            Message::User { content } => {
+            Message::User { content , ..} => {
                 let anthropic_content = match content {
                     UserContent::String(text) => generated::MessageContent::PurpleString(text),
                     UserContent::Array(parts) => {
@@ -1162,6 +1189,31 @@ impl TryFromLLM<Message> for generated::InputMessage {
                                         file_id: None,
                                     })
                                 },
+                                // See crate::providers::refusal_fallback_text: Anthropic has no
+                                // content-block representation for a refusal.
+                                AssistantContentPart::Refusal { text } => {
+                                    Some(generated::InputContentBlock {
+                                        cache_control: None,
+                                        citations: None,
+                                        text: Some(crate::providers::refusal_fallback_text(text)),
+                                        input_content_block_type:
+                                            generated::InputContentBlockType::Text,
+                                        source: None,
+                                        context: None,
+                                        title: None,
+                                        content: None,
+                                        signature: None,
+                                        thinking: None,
+                                        data: None,
+                                        caller: None,
+                                        id: None,
+                                        input: None,
+                                        name: None,
+                                        is_error: None,
+                                        tool_use_id: None,
+                                        file_id: None,
+                                    })
+                                }
                                 AssistantContentPart::Reasoning {
                                     text,
                                     encrypted_content,
@@ -1495,6 +1547,77 @@ fn input_message_content_blocks(
     }
 }
 
+/// Controls which shape Anthropic message content is emitted in when the
+/// content is a single, plain-text part: Anthropic's bare-string shape, or
+/// a single-element content-block array. Anthropic accepts both for
+/// text-only content, so nothing about correctness forces one over the
+/// other, but a converter that always builds content as blocks internally
+/// (as this one does while merging tool results and cache control) turns a
+/// bare-string request like `content: "hi"` into a single-item array,
+/// which then no longer round-trips back to the original shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnthropicContentStyle {
+    /// Collapse a single plain-text block with no cache control or
+    /// citations down to a bare string. This is the default: it matches
+    /// how most callers author simple text messages, so the common case
+    /// (`content: "hi"`) stays a string end to end instead of drifting
+    /// into a block array.
+    #[default]
+    String,
+    /// Always emit a content-block array, even for single-part,
+    /// plain-text content.
+    Blocks,
+}
+
+/// Applies an [`AnthropicContentStyle`] to every message's content,
+/// canonicalizing between Anthropic's plain-string and content-block-array
+/// shapes wherever the content is a single, plain-text part. Content with
+/// cache control, citations, or more than one part is left untouched,
+/// since only a single plain-text part is ever ambiguous between the two
+/// shapes.
+pub fn canonicalize_content_style(
+    messages: &mut [generated::InputMessage],
+    style: AnthropicContentStyle,
+) {
+    for message in messages.iter_mut() {
+        let content = std::mem::replace(
+            &mut message.content,
+            generated::MessageContent::PurpleString(String::new()),
+        );
+        message.content = apply_content_style(content, style);
+    }
+}
+
+fn apply_content_style(
+    content: generated::MessageContent,
+    style: AnthropicContentStyle,
+) -> generated::MessageContent {
+    match style {
+        AnthropicContentStyle::String => match content {
+            generated::MessageContent::InputContentBlockArray(mut blocks)
+                if blocks.len() == 1 && is_plain_text_block(&blocks[0]) =>
+            {
+                generated::MessageContent::PurpleString(blocks.remove(0).text.unwrap_or_default())
+            }
+            other => other,
+        },
+        AnthropicContentStyle::Blocks => match content {
+            generated::MessageContent::PurpleString(text) => {
+                generated::MessageContent::InputContentBlockArray(vec![text_input_content_block(
+                    text,
+                )])
+            }
+            other => other,
+        },
+    }
+}
+
+fn is_plain_text_block(block: &generated::InputContentBlock) -> bool {
+    block.input_content_block_type == generated::InputContentBlockType::Text
+        && block.cache_control.is_none()
+        && block.citations.is_none()
+}
+
 fn try_merge_adjacent_user_tool_result_message(
     previous: &mut generated::InputMessage,
     current: generated::InputMessage,
@@ -1995,6 +2118,7 @@ impl TryFromLLM<Vec<generated::ContentBlock>> for Vec<Message> {
                                     &mut content_parts,
                                 )),
                                 id: None,
+                                name: None,
                             });
                         }
 
@@ -2019,6 +2143,7 @@ impl TryFromLLM<Vec<generated::ContentBlock>> for Vec<Message> {
             messages.push(Message::Assistant {
                 content: AssistantContent::Array(std::mem::take(&mut content_parts)),
                 id: None,
+                name: None,
             });
         }
 
@@ -2032,6 +2157,7 @@ impl TryFromLLM<Vec<generated::ContentBlock>> for Vec<Message> {
             messages.push(Message::Assistant {
                 content: AssistantContent::Array(content_parts),
                 id: None,
+                name: None,
             });
         }
 
@@ -2089,6 +2215,25 @@ impl TryFromLLM<Vec<Message>> for Vec<generated::ContentBlock> {
                                         file_id: None,
                                     });
                                 }
+                                AssistantContentPart::Refusal { text } => {
+                                    // See crate::providers::refusal_fallback_text: Anthropic has
+                                    // no content-block representation for a refusal.
+                                    content_blocks.push(generated::ContentBlock {
+                                        citations: None,
+                                        text: Some(crate::providers::refusal_fallback_text(text)),
+                                        content_block_type: generated::ContentBlockType::Text,
+                                        signature: None,
+                                        thinking: None,
+                                        data: None,
+                                        caller: None,
+                                        id: None,
+                                        input: None,
+                                        name: None,
+                                        content: None,
+                                        tool_use_id: None,
+                                        file_id: None,
+                                    });
+                                }
                                 AssistantContentPart::Reasoning {
                                     text,
                                     encrypted_content,
@@ -2270,12 +2415,28 @@ impl TryFromLLM<Vec<Message>> for Vec<generated::ContentBlock> {
 
 impl From<&ToolChoice> for ToolChoiceConfig {
     fn from(tc: &ToolChoice) -> Self {
-        let mode = Some(match tc.tool_choice_type {
-            ToolChoiceType::Auto => ToolChoiceMode::Auto,
-            ToolChoiceType::TypeNone => ToolChoiceMode::None,
-            ToolChoiceType::Any => ToolChoiceMode::Required,
-            ToolChoiceType::Tool => ToolChoiceMode::Tool,
-        });
+        // `{"type": "auto", "disable_parallel_tool_use": true}` is also exactly
+        // what `to_anthropic` synthesizes when a universal request has no
+        // explicit tool choice but does disable parallel tool calls: Anthropic
+        // has no way to carry `disable_parallel_tool_use` outside of a
+        // `tool_choice` object, so "auto" is used as the carrier. Since "auto"
+        // is also Anthropic's own default tool choice, treat that specific
+        // combination as "no explicit tool choice" rather than as a real `auto`
+        // preference, so a universal request with `tool_choice: None` and
+        // `parallel_tool_calls: Some(false)` round-trips back to itself instead
+        // of picking up a spurious `mode: Auto`.
+        let mode = if tc.tool_choice_type == ToolChoiceType::Auto
+            && tc.disable_parallel_tool_use == Some(true)
+        {
+            None
+        } else {
+            Some(match tc.tool_choice_type {
+                ToolChoiceType::Auto => ToolChoiceMode::Auto,
+                ToolChoiceType::TypeNone => ToolChoiceMode::None,
+                ToolChoiceType::Any => ToolChoiceMode::Required,
+                ToolChoiceType::Tool => ToolChoiceMode::Tool,
+            })
+        };
         ToolChoiceConfig {
             mode,
             tool_name: tc.name.clone(),
@@ -2475,6 +2636,36 @@ impl TryFrom<&UniversalTool> for Tool {
                 builtin_type,
                 config,
             } => {
+                if matches!(provider, BuiltinToolProvider::Responses)
+                    && (builtin_type == "web_search" || builtin_type == "web_search_preview")
+                {
+                    let (allowed_domains, user_location) =
+                        tool.openai_web_search_config()?.unwrap_or((None, None));
+                    return Ok(Tool::WebSearch20250305(generated::WebSearchTool20250305 {
+                        allowed_callers: None,
+                        allowed_domains,
+                        blocked_domains: None,
+                        cache_control: None,
+                        defer_loading: (tool.availability == ToolAvailability::Deferred)
+                            .then_some(true),
+                        max_uses: None,
+                        name: "web_search".to_string(),
+                        strict: None,
+                        user_location: user_location
+                            .map(|loc| {
+                                serde_json::to_value(loc).map_err(|e| {
+                                    ConvertError::JsonSerializationFailed {
+                                        field: format!(
+                                            "Anthropic web search user_location for '{}'",
+                                            tool.name
+                                        ),
+                                        error: e.to_string(),
+                                    }
+                                })
+                            })
+                            .transpose()?,
+                    }));
+                }
                 if matches!(provider, BuiltinToolProvider::Google)
                     && builtin_type == "google_search"
                 {
@@ -2583,9 +2774,11 @@ mod tests {
         match message {
             Message::User {
                 content: UserContent::String(text),
+                ..
             } => text,
             Message::User {
                 content: UserContent::Array(parts),
+                ..
             } => match &parts[..] {
                 [UserContentPart::Text(TextContentPart { text, .. })] => text,
                 _ => panic!("expected single text user content part, got {message:?}"),
@@ -2606,6 +2799,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn canonicalize_content_style_collapses_single_text_block_to_string() {
+        let mut messages = vec![input_message(json!({
+            "role": "user",
+            "content": [{"type": "text", "text": "hi"}]
+        }))];
+
+        canonicalize_content_style(&mut messages, AnthropicContentStyle::String);
+
+        assert_eq!(
+            messages[0].content,
+            generated::MessageContent::PurpleString("hi".to_string())
+        );
+    }
+
+    #[test]
+    fn canonicalize_content_style_expands_string_to_single_text_block() {
+        let mut messages = vec![input_message(json!({
+            "role": "user",
+            "content": "hi"
+        }))];
+
+        canonicalize_content_style(&mut messages, AnthropicContentStyle::Blocks);
+
+        let generated::MessageContent::InputContentBlockArray(blocks) = &messages[0].content else {
+            panic!("expected content blocks, got {:?}", messages[0].content);
+        };
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].text.as_deref(), Some("hi"));
+    }
+
+    #[test]
+    fn canonicalize_content_style_leaves_cache_controlled_block_as_array() {
+        let mut messages = vec![input_message(json!({
+            "role": "user",
+            "content": [{
+                "type": "text",
+                "text": "hi",
+                "cache_control": {"type": "ephemeral"}
+            }]
+        }))];
+
+        canonicalize_content_style(&mut messages, AnthropicContentStyle::String);
+
+        assert!(matches!(
+            messages[0].content,
+            generated::MessageContent::InputContentBlockArray(_)
+        ));
+    }
+
     #[test]
     fn test_json_object_response_format_is_not_converted_to_anthropic_format() {
         let config = ResponseFormatConfig {
@@ -2745,9 +2988,11 @@ mod tests {
             },
             Message::User {
                 content: UserContent::String("first".to_string()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("second".to_string()),
+                name: None,
             },
         ];
 
@@ -2782,6 +3027,7 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("before".to_string()),
+                name: None,
             },
             Message::Tool {
                 content: vec![ToolContentPart::ToolResult(ToolResultContentPart {
@@ -2853,6 +3099,7 @@ mod tests {
                 provider_options: None,
             }]),
             id: None,
+            name: None,
         }];
 
         let err = universal_messages_to_anthropic_input_messages(messages).unwrap_err();
@@ -2880,6 +3127,7 @@ mod tests {
                     provider_options: None,
                 }]),
                 id: None,
+                name: None,
             },
             Message::Tool {
                 content: vec![ToolContentPart::ToolDiscoveryResult(
@@ -2998,6 +3246,7 @@ mod tests {
                     provider_options: None,
                 }]),
                 id: None,
+                name: None,
             },
             Message::Tool {
                 content: vec![ToolContentPart::ToolDiscoveryResult(
@@ -3060,6 +3309,7 @@ mod tests {
                     },
                 ]),
                 id: None,
+                name: None,
             },
             Message::Tool {
                 content: vec![
@@ -3315,6 +3565,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_openai_web_search_options_maps_to_anthropic_web_search() {
+        let tool = UniversalTool::builtin(
+            "web_search",
+            BuiltinToolProvider::Responses,
+            "web_search",
+            Some(crate::serde_json::json!({
+                "user_location": {
+                    "approximate": {
+                        "city": "San Francisco",
+                        "country": "US",
+                        "region": "California",
+                        "timezone": "America/Los_Angeles"
+                    },
+                    "type": "approximate"
+                }
+            })),
+        );
+
+        let anthropic_tool = Tool::try_from(&tool).unwrap();
+        match anthropic_tool {
+            Tool::WebSearch20250305(web_search) => {
+                assert_eq!(web_search.name, "web_search");
+                assert!(web_search.allowed_domains.is_none());
+                let user_location = web_search.user_location.expect("user_location set");
+                assert_eq!(user_location.pointer("/city").unwrap(), "San Francisco");
+                assert_eq!(user_location.pointer("/country").unwrap(), "US");
+                assert_eq!(user_location.pointer("/region").unwrap(), "California");
+                assert_eq!(
+                    user_location.pointer("/timezone").unwrap(),
+                    "America/Los_Angeles"
+                );
+            }
+            other => panic!("expected web_search_20250305 tool, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_dated_server_tool_variants_deserialize_and_roundtrip() {
         // Regression: the spec added web_search_20260318 / web_fetch_20260318 server
@@ -3391,6 +3678,7 @@ mod tests {
         // Create a user message with the file part
         let message = Message::User {
             content: UserContent::Array(vec![file_part]),
+            name: None,
         };
 
         // Convert to Anthropic InputMessage
@@ -3430,6 +3718,7 @@ mod tests {
 
         let message = Message::User {
             content: UserContent::Array(vec![file_part]),
+            name: None,
         };
 
         let result: Result<generated::InputMessage, _> =
@@ -3469,6 +3758,7 @@ mod tests {
 
         let message = Message::User {
             content: UserContent::Array(vec![file_part]),
+            name: None,
         };
 
         let result: Result<generated::InputMessage, _> =
@@ -3540,6 +3830,7 @@ mod tests {
         match converted {
             Message::User {
                 content: UserContent::Array(parts),
+                ..
             } => match &parts[0] {
                 UserContentPart::File {
                     data,
@@ -3571,6 +3862,7 @@ mod tests {
 
         let message = Message::User {
             content: UserContent::Array(vec![image_part]),
+            name: None,
         };
 
         let result: Result<generated::InputMessage, _> =
@@ -148,7 +148,8 @@ impl ProviderAdapter for BedrockAdapter {
             }),
             tool_choice: None, // Tool choice is inside tool_config
             response_format: None,
-            seed: None, // Bedrock doesn't support seed
+            modalities: None, // Bedrock Converse doesn't support output modality selection
+            seed: None,       // Bedrock doesn't support seed
             presence_penalty: None,
             frequency_penalty: None,
             stream: None, // Bedrock uses separate endpoint for streaming
@@ -355,6 +356,9 @@ impl ProviderAdapter for BedrockAdapter {
             usage,
             finish_reason: finish_reason.clone(),
             finish_reasons: finish_reason.into_iter().collect(),
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         })
     }
 
@@ -711,6 +715,7 @@ mod tests {
                         },
                     ]),
                     id: None,
+                    name: None,
                 },
                 Message::Tool {
                     content: vec![ToolContentPart::ToolDiscoveryResult(
@@ -87,6 +87,7 @@ impl TryFromLLM<BedrockMessage> for Message {
                 } else {
                     Ok(Message::User {
                         content: UserContent::String(text_parts.join("")),
+                        name: None,
                     })
                 }
             }
@@ -143,6 +144,7 @@ impl TryFromLLM<BedrockMessage> for Message {
                 Ok(Message::Assistant {
                     content: AssistantContent::Array(content_parts),
                     id: None,
+                    name: None,
                 })
             }
         }
@@ -177,7 +179,7 @@ impl TryFromLLM<Message> for BedrockMessage {
                     vec![BedrockContentBlock::Text { text }],
                 )
             }
-            Message::User { content } => {
+            Message::User { content, .. } => {
                 let blocks = match content {
                     UserContent::String(s) => vec![BedrockContentBlock::Text { text: s }],
                     UserContent::Array(parts) => parts
@@ -225,6 +227,12 @@ impl TryFromLLM<Message> for BedrockMessage {
                             AssistantContentPart::Text(t) => {
                                 Some(BedrockContentBlock::Text { text: t.text })
                             }
+                            // See crate::providers::refusal_fallback_text.
+                            AssistantContentPart::Refusal { text } => {
+                                Some(BedrockContentBlock::Text {
+                                    text: crate::providers::refusal_fallback_text(text),
+                                })
+                            }
                             AssistantContentPart::Reasoning {
                                 text,
                                 encrypted_content,
@@ -562,6 +570,7 @@ impl TryFromLLM<BedrockOutputMessage> for Message {
         Ok(Message::Assistant {
             content: AssistantContent::Array(content_parts),
             id: None,
+            name: None,
         })
     }
 }
@@ -586,6 +595,12 @@ impl TryFromLLM<Message> for BedrockOutputMessage {
                             AssistantContentPart::Text(t) => {
                                 Some(BedrockOutputContentBlock::Text { text: t.text })
                             }
+                            // See crate::providers::refusal_fallback_text.
+                            AssistantContentPart::Refusal { text } => {
+                                Some(BedrockOutputContentBlock::Text {
+                                    text: crate::providers::refusal_fallback_text(text),
+                                })
+                            }
                             AssistantContentPart::Reasoning {
                                 text,
                                 encrypted_content,
@@ -651,7 +666,7 @@ mod tests {
 
         let message = <Message as TryFromLLM<BedrockMessage>>::try_from(msg).unwrap();
         match message {
-            Message::User { content } => match content {
+            Message::User { content, .. } => match content {
                 UserContent::String(s) => assert_eq!(s, "Hello"),
                 _ => panic!("Expected string content"),
             },
@@ -724,6 +739,7 @@ mod tests {
     fn test_message_to_bedrock_user() {
         let message = Message::User {
             content: UserContent::String("Hello".to_string()),
+            name: None,
         };
 
         let msg = <BedrockMessage as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -740,6 +756,7 @@ mod tests {
         let message = Message::Assistant {
             content: AssistantContent::String("Hi there!".to_string()),
             id: None,
+            name: None,
         };
 
         let msg = <BedrockMessage as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -765,6 +782,7 @@ mod tests {
                 provider_executed: None,
             }]),
             id: None,
+            name: None,
         };
 
         let msg = <BedrockMessage as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -801,7 +819,7 @@ mod tests {
         let messages = bedrock_to_universal(&request).unwrap();
         assert_eq!(messages.len(), 1);
         match &messages[0] {
-            Message::User { content } => match content {
+            Message::User { content, .. } => match content {
                 UserContent::String(s) => assert_eq!(s, "Hello"),
                 _ => panic!("Expected string content"),
             },
@@ -860,6 +878,7 @@ mod tests {
     fn test_universal_to_bedrock_simple() {
         let messages = vec![Message::User {
             content: UserContent::String("Hello".to_string()),
+            name: None,
         }];
 
         let result = universal_to_bedrock(&messages).unwrap();
@@ -885,6 +904,7 @@ mod tests {
                 provider_executed: None,
             }]),
             id: None,
+            name: None,
         }];
 
         let result = universal_to_bedrock(&messages).unwrap();
@@ -899,6 +919,7 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("Find the available tools.".to_string()),
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::Array(vec![AssistantContentPart::ToolDiscoveryCall {
@@ -911,6 +932,7 @@ mod tests {
                     provider_options: None,
                 }]),
                 id: None,
+                name: None,
             },
             Message::Tool {
                 content: vec![ToolContentPart::ToolDiscoveryResult(
@@ -930,6 +952,7 @@ mod tests {
             },
             Message::User {
                 content: UserContent::String("Use the discovered tool list.".to_string()),
+                name: None,
             },
         ];
 
@@ -949,6 +972,7 @@ mod tests {
                 encrypted_content: Some("redacted-by-provider".to_string()),
             }]),
             id: None,
+            name: None,
         }];
 
         let result = universal_to_bedrock(&messages).unwrap();
@@ -1020,6 +1044,7 @@ mod tests {
                     },
                 ]),
                 id: None,
+                name: None,
             },
             Message::Tool {
                 content: vec![ToolContentPart::ToolResult(ToolResultContentPart {
@@ -33,7 +33,7 @@ pub use response::{ConverseResponse, ConverseStreamResponse};
 
 #[cfg(feature = "anthropic")]
 pub use crate::providers::bedrock_anthropic::{
-    is_bedrock_anthropic_model, is_bedrock_anthropic_target,
+    is_bedrock_anthropic_model, is_bedrock_anthropic_target, strip_bedrock_arn,
 };
 
 #[cfg(all(test, feature = "anthropic"))]
@@ -61,4 +61,48 @@ mod tests {
         assert!(!is_bedrock_anthropic_model("claude-sonnet-4-20250514"));
         assert!(!is_bedrock_anthropic_model("claude-haiku-4-5-20251001"));
     }
+
+    #[test]
+    fn test_is_bedrock_anthropic_model_handles_arns() {
+        // Foundation-model id, given bare
+        assert!(is_bedrock_anthropic_model(
+            "anthropic.claude-3-haiku-20240307-v1:0"
+        ));
+        // Cross-region inference-profile id, given bare
+        assert!(is_bedrock_anthropic_model(
+            "us.anthropic.claude-3-5-sonnet-20241022-v2:0"
+        ));
+        // Full inference-profile ARN
+        assert!(is_bedrock_anthropic_model(
+            "arn:aws:bedrock:us-east-1:123456789012:inference-profile/us.anthropic.claude-3-5-sonnet-20241022-v2:0"
+        ));
+        // Full foundation-model ARN
+        assert!(is_bedrock_anthropic_model(
+            "arn:aws:bedrock:us-east-1::foundation-model/anthropic.claude-3-haiku-20240307-v1:0"
+        ));
+        // Non-Anthropic foundation-model ARN
+        assert!(!is_bedrock_anthropic_model(
+            "arn:aws:bedrock:us-east-1::foundation-model/amazon.nova-pro-v1:0"
+        ));
+    }
+
+    #[test]
+    fn test_strip_bedrock_arn() {
+        assert_eq!(
+            strip_bedrock_arn("anthropic.claude-3-haiku-20240307-v1:0"),
+            "anthropic.claude-3-haiku-20240307-v1:0"
+        );
+        assert_eq!(
+            strip_bedrock_arn(
+                "arn:aws:bedrock:us-east-1:123456789012:inference-profile/us.anthropic.claude-3-5-sonnet-20241022-v2:0"
+            ),
+            "us.anthropic.claude-3-5-sonnet-20241022-v2:0"
+        );
+        assert_eq!(
+            strip_bedrock_arn(
+                "arn:aws:bedrock:us-east-1::foundation-model/anthropic.claude-3-haiku-20240307-v1:0"
+            ),
+            "anthropic.claude-3-haiku-20240307-v1:0"
+        );
+    }
 }
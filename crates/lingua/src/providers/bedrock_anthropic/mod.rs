@@ -4,12 +4,33 @@ pub mod adapter;
 #[cfg(feature = "anthropic")]
 pub use adapter::BedrockAnthropicAdapter;
 
+/// Extracts the trailing model (or inference-profile) id from a Bedrock resource
+/// ARN, returning the input unchanged if it isn't an ARN.
+///
+/// Bedrock accepts a model id in three shapes: a bare foundation-model id
+/// (`anthropic.claude-3-5-sonnet-...`), a cross-region inference-profile id
+/// (`us.anthropic.claude-3-5-sonnet-...`), or a full ARN wrapping either of
+/// those (`arn:aws:bedrock:us-east-1:123456789012:inference-profile/us.anthropic.claude-...`
+/// or `arn:aws:bedrock:us-east-1::foundation-model/anthropic.claude-...`). Only
+/// the ARN form needs unwrapping before the id can be matched against a model
+/// name or catalog entry.
+pub fn strip_bedrock_arn(model: &str) -> &str {
+    if model.starts_with("arn:") {
+        model.rsplit('/').next().unwrap_or(model)
+    } else {
+        model
+    }
+}
+
 /// Returns true if the model ID represents a Bedrock-hosted Anthropic model
 /// that supports the native Anthropic Messages API via the invoke endpoint.
 ///
 /// These models have IDs starting with `anthropic.` or containing `.anthropic.`
-/// (for cross-region inference profiles like `us.anthropic.claude-*`).
+/// (for cross-region inference profiles like `us.anthropic.claude-*`), and may
+/// be wrapped in a full inference-profile or foundation-model ARN (see
+/// [`strip_bedrock_arn`]).
 pub fn is_bedrock_anthropic_model(model: &str) -> bool {
+    let model = strip_bedrock_arn(model);
     model.starts_with("anthropic.") || model.contains(".anthropic.")
 }
 
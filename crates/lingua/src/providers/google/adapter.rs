@@ -12,20 +12,22 @@ use crate::capabilities::ProviderFormat;
 use crate::processing::adapters::ProviderAdapter;
 use crate::processing::transform::TransformError;
 use crate::providers::google::capabilities::{
-    effort_to_thinking_level, thinking_level_to_effort, GoogleCapabilities, GoogleThinkingStyle,
+    clamp_penalty_for_gemini, effort_to_thinking_level, thinking_level_to_effort,
+    GoogleCapabilities, GoogleThinkingStyle,
 };
 use crate::providers::google::convert::SYNTHETIC_CALL_ID_PREFIX;
 use crate::providers::google::detect::try_parse_google;
 use crate::providers::google::generated::{
-    Content as GoogleContent, GenerateContentResponse, GenerationConfig, ThinkingConfig,
-    ThinkingLevel, Tool as GoogleTool, ToolConfig, UsageMetadata,
+    Content as GoogleContent, GenerateContentResponse, GenerationConfig,
+    ResponseModality as GoogleResponseModality, ThinkingConfig, ThinkingLevel, Tool as GoogleTool,
+    ToolConfig, UsageMetadata,
 };
 use crate::providers::google::params::GoogleParams;
 use crate::serde_json::{self, Map, Value};
 use crate::universal::convert::TryFromLLM;
 use crate::universal::message::{AssistantContent, AssistantContentPart, Message};
 use crate::universal::reasoning::{budget_to_effort, effort_to_budget, MIN_THINKING_BUDGET};
-use crate::universal::request::ToolChoiceConfig;
+use crate::universal::request::{ResponseModality, ToolChoiceConfig};
 use crate::universal::tools::UniversalTool;
 use crate::universal::ToolContentPart;
 use crate::universal::{
@@ -134,55 +136,77 @@ impl ProviderAdapter for GoogleAdapter {
             .map_err(|e| TransformError::ToUniversalFailed(e.to_string()))?;
 
         // Extract params from generationConfig (now typed in params struct)
-        let (temperature, top_p, top_k, max_tokens, stop, reasoning) =
-            if let Some(config) = &typed_params.generation_config {
-                let max_tokens = config.max_output_tokens;
-                // Convert Google's thinkingConfig to ReasoningConfig
-                // thinkingLevel: Gemini 3 (effort-based)
-                // thinkingBudget: Gemini 2.5 (budget-based), 0 means disabled
-                let reasoning = config.thinking_config.as_ref().map(|tc| {
-                    if let Some(ref level) = tc.thinking_level {
-                        // Gemini 3 style: thinkingLevel is canonical (effort-based)
-                        let effort = thinking_level_to_effort(level);
-                        let budget = effort_to_budget(effort, max_tokens);
-                        ReasoningConfig {
-                            enabled: Some(true),
-                            effort: Some(effort),
-                            budget_tokens: Some(budget),
-                            canonical: Some(ReasoningCanonical::Effort),
-                            ..Default::default()
-                        }
+        let (
+            temperature,
+            top_p,
+            top_k,
+            max_tokens,
+            stop,
+            reasoning,
+            presence_penalty,
+            frequency_penalty,
+            modalities,
+        ) = if let Some(config) = &typed_params.generation_config {
+            let max_tokens = config.max_output_tokens;
+            // Convert Google's thinkingConfig to ReasoningConfig
+            // thinkingLevel: Gemini 3 (effort-based)
+            // thinkingBudget: Gemini 2.5 (budget-based), 0 means disabled
+            let reasoning = config.thinking_config.as_ref().map(|tc| {
+                if let Some(ref level) = tc.thinking_level {
+                    // Gemini 3 style: thinkingLevel is canonical (effort-based)
+                    let effort = thinking_level_to_effort(level);
+                    let budget = effort_to_budget(effort, max_tokens);
+                    ReasoningConfig {
+                        enabled: Some(true),
+                        effort: Some(effort),
+                        budget_tokens: Some(budget),
+                        canonical: Some(ReasoningCanonical::Effort),
+                        ..Default::default()
+                    }
+                } else {
+                    // Gemini 2.5 style: thinkingBudget is canonical (budget-based)
+                    let is_disabled = tc.thinking_budget == Some(0);
+                    let budget_tokens = tc.thinking_budget;
+                    let effort = budget_tokens.map(|b| budget_to_effort(b, max_tokens));
+                    let canonical = if tc.thinking_budget.is_some() {
+                        Some(ReasoningCanonical::GoogleThinkingBudget)
                     } else {
-                        // Gemini 2.5 style: thinkingBudget is canonical (budget-based)
-                        let is_disabled = tc.thinking_budget == Some(0);
-                        let budget_tokens = tc.thinking_budget;
-                        let effort = budget_tokens.map(|b| budget_to_effort(b, max_tokens));
-                        let canonical = if tc.thinking_budget.is_some() {
-                            Some(ReasoningCanonical::GoogleThinkingBudget)
-                        } else {
-                            Some(ReasoningCanonical::GoogleIncludeThoughts)
-                        };
-                        ReasoningConfig {
-                            enabled: Some(!is_disabled),
-                            effort,
-                            budget_tokens,
-                            canonical,
-                            ..Default::default()
-                        }
+                        Some(ReasoningCanonical::GoogleIncludeThoughts)
+                    };
+                    ReasoningConfig {
+                        enabled: Some(!is_disabled),
+                        effort,
+                        budget_tokens,
+                        canonical,
+                        ..Default::default()
                     }
-                });
-                let stop = config.stop_sequences.clone().filter(|s| !s.is_empty());
-                (
-                    config.temperature,
-                    config.top_p,
-                    config.top_k,
-                    max_tokens,
-                    stop,
-                    reasoning,
-                )
-            } else {
-                (None, None, None, None, None, None)
-            };
+                }
+            });
+            let stop = config.stop_sequences.clone().filter(|s| !s.is_empty());
+            let modalities = config.response_modalities.as_ref().map(|mods| {
+                mods.iter()
+                    .map(|m| match m {
+                        GoogleResponseModality::Text => ResponseModality::Text,
+                        GoogleResponseModality::Image => ResponseModality::Image,
+                        GoogleResponseModality::Audio => ResponseModality::Audio,
+                        GoogleResponseModality::ModalityUnspecified => ResponseModality::Text,
+                    })
+                    .collect()
+            });
+            (
+                config.temperature,
+                config.top_p,
+                config.top_k,
+                max_tokens,
+                stop,
+                reasoning,
+                config.presence_penalty,
+                config.frequency_penalty,
+                modalities,
+            )
+        } else {
+            (None, None, None, None, None, None, None, None, None)
+        };
 
         // Convert tools using typed conversions
         let tools = typed_params
@@ -208,9 +232,10 @@ impl ProviderAdapter for GoogleAdapter {
             tools,
             tool_choice,
             response_format,
+            modalities,
             seed: None, // Google doesn't support seed
-            presence_penalty: None,
-            frequency_penalty: None,
+            presence_penalty,
+            frequency_penalty,
             stream: None, // Google uses endpoint-based streaming
             // New canonical fields - Google doesn't support most of these
             parallel_tool_calls: None,
@@ -233,6 +258,18 @@ impl ProviderAdapter for GoogleAdapter {
             );
         }
 
+        // cachedContent (context caching) has no Universal-level equivalent, so it's
+        // preserved via extras like the flatten-captured fields above. This restores it
+        // on a Google -> Google round-trip but drops it for other targets, same as any
+        // other Google-specific extra.
+        if let Some(cached_content) = typed_params.cached_content {
+            params
+                .extras
+                .entry(ProviderFormat::Google)
+                .or_default()
+                .insert("cachedContent".to_string(), Value::String(cached_content));
+        }
+
         Ok(UniversalRequest {
             model,
             messages,
@@ -341,8 +378,11 @@ impl ProviderAdapter for GoogleAdapter {
             || req.params.top_k.is_some()
             || req.params.output_token_budget().is_some()
             || req.params.stop.is_some()
+            || req.params.presence_penalty.is_some()
+            || req.params.frequency_penalty.is_some()
             || has_reasoning
-            || has_response_format;
+            || has_response_format
+            || req.params.modalities.is_some();
 
         if has_params {
             // Convert ReasoningConfig to Google's thinkingConfig
@@ -415,6 +455,11 @@ impl ProviderAdapter for GoogleAdapter {
                 max_output_tokens: req.params.output_token_budget(),
                 stop_sequences,
                 thinking_config,
+                // Gemini rejects penalties outside [-2.0, 2.0]; clamp rather than
+                // forward an OpenAI-range value (e.g. 2.0 is in-range for both, but
+                // some canonical sources allow values Gemini would reject with a 400).
+                presence_penalty: req.params.presence_penalty.map(clamp_penalty_for_gemini),
+                frequency_penalty: req.params.frequency_penalty.map(clamp_penalty_for_gemini),
                 ..Default::default()
             };
 
@@ -428,6 +473,27 @@ impl ProviderAdapter for GoogleAdapter {
                 config.response_schema = response_config.response_schema;
             }
 
+            // Apply requested output modalities. Gemini requires TEXT to be listed
+            // alongside IMAGE/AUDIO in the same response, so add it implicitly rather
+            // than requiring callers to spell it out.
+            if let Some(modalities) = &req.params.modalities {
+                let mut response_modalities: Vec<GoogleResponseModality> = modalities
+                    .iter()
+                    .map(|m| match m {
+                        ResponseModality::Text => GoogleResponseModality::Text,
+                        ResponseModality::Image => GoogleResponseModality::Image,
+                        ResponseModality::Audio => GoogleResponseModality::Audio,
+                    })
+                    .collect();
+                let needs_text = response_modalities
+                    .iter()
+                    .any(|m| !matches!(m, GoogleResponseModality::Text));
+                if needs_text && !response_modalities.contains(&GoogleResponseModality::Text) {
+                    response_modalities.push(GoogleResponseModality::Text);
+                }
+                config.response_modalities = Some(response_modalities);
+            }
+
             obj.insert(
                 "generationConfig".into(),
                 serde_json::to_value(config)
@@ -540,6 +606,9 @@ impl ProviderAdapter for GoogleAdapter {
             usage,
             finish_reason,
             finish_reasons,
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         })
     }
 
@@ -617,9 +686,11 @@ impl ProviderAdapter for GoogleAdapter {
             serde_json::from_value(payload).map_err(|e| {
                 TransformError::ToUniversalFailed(format!("failed to parse stream payload: {e}"))
             })?;
-        let candidates = typed_payload
-            .candidates
-            .ok_or_else(|| TransformError::ToUniversalFailed("missing candidates".to_string()))?;
+        // Gemini sometimes sends a trailing chunk that carries only
+        // `usageMetadata` with no `candidates` at all - treat that the same
+        // way OpenAI's stream adapter treats a chunk with no `choices`:
+        // an empty choice list rather than a hard error.
+        let candidates = typed_payload.candidates.unwrap_or_default();
 
         let mut choices = Vec::new();
 
@@ -1025,6 +1096,113 @@ mod tests {
         assert!(reconstructed.generation_config.is_some());
     }
 
+    #[test]
+    fn test_google_generation_config_penalties_round_trip_to_universal() {
+        let adapter = GoogleAdapter;
+        let payload = json!({
+            "contents": [{
+                "role": "user",
+                "parts": [{"text": "Hello"}]
+            }],
+            "generationConfig": {
+                "presencePenalty": 0.5,
+                "frequencyPenalty": -0.25
+            }
+        });
+
+        let universal = adapter.request_to_universal(payload).unwrap();
+        assert_eq!(universal.params.presence_penalty, Some(0.5));
+        assert_eq!(universal.params.frequency_penalty, Some(-0.25));
+
+        let reconstructed = adapter.request_from_universal(&universal).unwrap();
+        let reconstructed: GenerateContentRequest =
+            serde_json::from_value(reconstructed).expect("request should deserialize");
+        let config = reconstructed
+            .generation_config
+            .expect("generationConfig should be present");
+        assert_eq!(config.presence_penalty, Some(0.5));
+        assert_eq!(config.frequency_penalty, Some(-0.25));
+    }
+
+    #[test]
+    fn test_cross_provider_openai_penalties_map_to_gemini_generation_config() {
+        use crate::providers::openai::adapter::OpenAIAdapter;
+
+        let openai_payload = json!({
+            "model": "gpt-4o",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "presence_penalty": 1.5,
+            "frequency_penalty": -1.0
+        });
+
+        let mut universal = OpenAIAdapter.request_to_universal(openai_payload).unwrap();
+        assert_eq!(universal.params.presence_penalty, Some(1.5));
+        assert_eq!(universal.params.frequency_penalty, Some(-1.0));
+
+        universal.model = Some("gemini-1.5-pro".to_string());
+        let google_payload = GoogleAdapter.request_from_universal(&universal).unwrap();
+        let google_request: GenerateContentRequest =
+            serde_json::from_value(google_payload).expect("request should deserialize");
+        let config = google_request
+            .generation_config
+            .expect("generationConfig should be present");
+        assert_eq!(config.presence_penalty, Some(1.5));
+        assert_eq!(config.frequency_penalty, Some(-1.0));
+    }
+
+    #[test]
+    fn test_cross_provider_gemini_penalties_map_back_to_openai_chat() {
+        use crate::providers::openai::adapter::OpenAIAdapter;
+        use crate::providers::openai::generated::CreateChatCompletionRequestClass;
+
+        let google_payload = json!({
+            "model": "gemini-1.5-pro",
+            "contents": [{
+                "role": "user",
+                "parts": [{"text": "Hello"}]
+            }],
+            "generationConfig": {
+                "presencePenalty": 0.8,
+                "frequencyPenalty": 0.4
+            }
+        });
+
+        let mut universal = GoogleAdapter.request_to_universal(google_payload).unwrap();
+        universal.model = Some("gpt-4o".to_string());
+
+        let openai_payload = OpenAIAdapter.request_from_universal(&universal).unwrap();
+        let result: CreateChatCompletionRequestClass =
+            serde_json::from_value(openai_payload).unwrap();
+        assert_eq!(result.presence_penalty, Some(0.8));
+        assert_eq!(result.frequency_penalty, Some(0.4));
+    }
+
+    #[test]
+    fn test_google_generation_config_penalties_are_clamped_to_gemini_range() {
+        let adapter = GoogleAdapter;
+        let universal = UniversalRequest {
+            model: Some("gemini-1.5-pro".to_string()),
+            messages: vec![Message::User {
+                content: UserContent::String("hi".to_string()),
+                name: None,
+            }],
+            params: UniversalParams {
+                presence_penalty: Some(5.0),
+                frequency_penalty: Some(-5.0),
+                ..Default::default()
+            },
+        };
+
+        let reconstructed = adapter.request_from_universal(&universal).unwrap();
+        let reconstructed: GenerateContentRequest =
+            serde_json::from_value(reconstructed).expect("request should deserialize");
+        let config = reconstructed
+            .generation_config
+            .expect("generationConfig should be present");
+        assert_eq!(config.presence_penalty, Some(2.0));
+        assert_eq!(config.frequency_penalty, Some(-2.0));
+    }
+
     #[test]
     fn test_google_same_provider_preserves_budget_based_thinking_config_for_gemini_3() {
         let adapter = GoogleAdapter;
@@ -1109,6 +1287,7 @@ mod tests {
             model: Some("gemini-3.5-flash".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Return JSON.".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 reasoning: Some(ReasoningConfig {
@@ -1157,6 +1336,104 @@ mod tests {
         assert!(reconstructed.contents.is_some());
     }
 
+    #[test]
+    fn test_google_roundtrip_preserves_cached_content() {
+        let adapter = GoogleAdapter;
+        let payload = json!({
+            "contents": [{
+                "role": "user",
+                "parts": [{"text": "Hello"}]
+            }],
+            "cachedContent": "cachedContents/abc123"
+        });
+
+        let universal = adapter.request_to_universal(payload).unwrap();
+        assert_eq!(
+            universal
+                .params
+                .extras
+                .get(&ProviderFormat::Google)
+                .and_then(|extras| extras.get("cachedContent")),
+            Some(&Value::String("cachedContents/abc123".to_string()))
+        );
+
+        let reconstructed = adapter.request_from_universal(&universal).unwrap();
+        assert_eq!(
+            reconstructed.get("cachedContent"),
+            Some(&Value::String("cachedContents/abc123".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_google_image_modality_round_trip() {
+        let adapter = GoogleAdapter;
+        let req = UniversalRequest {
+            model: Some("gemini-2.0-flash".to_string()),
+            messages: vec![Message::User {
+                content: UserContent::String("Draw a cat".to_string()),
+                name: None,
+            }],
+            params: UniversalParams {
+                modalities: Some(vec![ResponseModality::Image]),
+                ..Default::default()
+            },
+        };
+
+        let payload = adapter.request_from_universal(&req).unwrap();
+        let typed: crate::providers::google::generated::GenerateContentRequest =
+            serde_json::from_value(payload).expect("request should deserialize");
+        let config = typed
+            .generation_config
+            .expect("generationConfig should be present");
+        // Gemini requires TEXT alongside IMAGE in the same response.
+        assert_eq!(
+            config.response_modalities,
+            Some(vec![
+                GoogleResponseModality::Image,
+                GoogleResponseModality::Text
+            ])
+        );
+
+        // Round-trip the config back to universal and confirm the modalities survive.
+        let request_payload = json!({
+            "contents": [{"role": "user", "parts": [{"text": "Draw a cat"}]}],
+            "generationConfig": {"responseModalities": ["IMAGE", "TEXT"]}
+        });
+        let universal = adapter.request_to_universal(request_payload).unwrap();
+        assert_eq!(
+            universal.params.modalities,
+            Some(vec![ResponseModality::Image, ResponseModality::Text])
+        );
+
+        // A response carrying an inlineData image part reconstructs into a File content part.
+        let response_payload = json!({
+            "candidates": [{
+                "content": {
+                    "role": "model",
+                    "parts": [{
+                        "inlineData": {
+                            "mimeType": "image/png",
+                            "data": "aGVsbG8="
+                        }
+                    }]
+                },
+                "finishReason": "STOP"
+            }]
+        });
+        let response = adapter.response_to_universal(response_payload).unwrap();
+        let Message::Assistant {
+            content: AssistantContent::Array(parts),
+            ..
+        } = &response.messages[0]
+        else {
+            panic!("expected assistant message with array content");
+        };
+        assert!(matches!(
+            &parts[0],
+            AssistantContentPart::File { media_type, .. } if media_type == "image/png"
+        ));
+    }
+
     #[test]
     fn test_google_tool_choice_to_universal() {
         let adapter = GoogleAdapter;
@@ -1184,6 +1461,7 @@ mod tests {
             model: None,
             messages: vec![Message::User {
                 content: UserContent::String("Hello".into()),
+                name: None,
             }],
             params: UniversalParams {
                 tool_choice: Some(ToolChoiceConfig {
@@ -1215,6 +1493,7 @@ mod tests {
             messages: vec![
                 Message::User {
                     content: UserContent::String("List databases.".to_string()),
+                    name: None,
                 },
                 Message::Assistant {
                     id: None,
@@ -1230,6 +1509,7 @@ mod tests {
                         caller: None,
                         provider_executed: None,
                     }]),
+                    name: None,
                 },
                 Message::Tool {
                     content: vec![ToolContentPart::ToolResult(
@@ -1282,6 +1562,7 @@ mod tests {
                     content: UserContent::String(
                         "Check the weather in Paris and London.".to_string(),
                     ),
+                    name: None,
                 },
                 Message::Assistant {
                     id: None,
@@ -1311,6 +1592,7 @@ mod tests {
                             provider_executed: None,
                         },
                     ]),
+                    name: None,
                 },
                 Message::Tool {
                     content: vec![
@@ -1374,6 +1656,7 @@ mod tests {
                     content: UserContent::String(
                         "Check the weather in Paris and London.".to_string(),
                     ),
+                    name: None,
                 },
                 Message::Assistant {
                     id: None,
@@ -1403,6 +1686,7 @@ mod tests {
                             provider_executed: None,
                         },
                     ]),
+                    name: None,
                 },
                 Message::Tool {
                     content: vec![
@@ -1614,6 +1898,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_google_stream_reconstructs_full_response_from_recorded_chunks() {
+        let adapter = GoogleAdapter;
+        let args: Map<String, Value> = serde_json::from_value(json!({"city": "Boston"})).unwrap();
+
+        // A recorded `streamGenerateContent` sequence: incremental text
+        // fragments, a function call that arrives whole in one fragment, and
+        // a trailing fragment carrying only `usageMetadata`.
+        let recorded_chunks = vec![
+            json!({
+                "responseId": "response_stream_1",
+                "candidates": [{
+                    "index": 0,
+                    "content": { "role": "model", "parts": [{"text": "Sure, "}] }
+                }]
+            }),
+            json!({
+                "responseId": "response_stream_1",
+                "candidates": [{
+                    "index": 0,
+                    "content": { "role": "model", "parts": [{"text": "let me check the weather."}] }
+                }]
+            }),
+            json!({
+                "responseId": "response_stream_1",
+                "candidates": [{
+                    "index": 0,
+                    "content": {
+                        "role": "model",
+                        "parts": [{
+                            "functionCall": {"name": "get_weather", "args": args}
+                        }]
+                    },
+                    "finishReason": "STOP"
+                }]
+            }),
+            json!({
+                "responseId": "response_stream_1",
+                "usageMetadata": {
+                    "promptTokenCount": 12,
+                    "candidatesTokenCount": 8,
+                    "totalTokenCount": 20
+                }
+            }),
+        ];
+
+        let mut reconstructed_text = String::new();
+        let mut tool_call = None;
+        let mut finish_reason = None;
+        let mut usage = None;
+
+        for raw_chunk in recorded_chunks {
+            let chunk = adapter
+                .stream_to_universal(raw_chunk)
+                .unwrap()
+                .expect("every recorded chunk should produce a stream chunk");
+            if let Some(choice) = chunk.choices.first() {
+                if let Some(delta) = choice.delta_view() {
+                    if let Some(text) = delta.content.as_deref() {
+                        reconstructed_text.push_str(text);
+                    }
+                    if let Some(call) = delta.tool_calls.first() {
+                        tool_call = Some(call.clone());
+                    }
+                }
+                if choice.finish_reason.is_some() {
+                    finish_reason = choice.finish_reason.clone();
+                }
+            }
+            if chunk.usage.is_some() {
+                usage = chunk.usage;
+            }
+        }
+
+        assert_eq!(reconstructed_text, "Sure, let me check the weather.");
+        let tool_call = tool_call.expect("function call fragment should have produced a tool call");
+        assert_eq!(
+            tool_call.function.as_ref().and_then(|f| f.name.as_deref()),
+            Some("get_weather")
+        );
+        assert_eq!(finish_reason.as_deref(), Some("tool_calls"));
+        let usage = usage.expect("trailing usage-only chunk should populate usage");
+        assert_eq!(usage.prompt_tokens, Some(12));
+        assert_eq!(usage.completion_tokens, Some(8));
+    }
+
     #[test]
     fn test_google_stream_from_universal_emits_reasoning_as_thought_part() {
         let adapter = GoogleAdapter;
@@ -1769,6 +2139,7 @@ mod tests {
             model: None,
             messages: vec![Message::User {
                 content: UserContent::String("Hello".into()),
+                name: None,
             }],
             params: UniversalParams {
                 tools: Some(vec![crate::universal::tools::UniversalTool::function(
@@ -99,6 +99,17 @@ pub fn effort_to_thinking_level(effort: ReasoningEffort) -> ThinkingLevel {
     }
 }
 
+/// Gemini's accepted range for `generationConfig.frequencyPenalty` /
+/// `presencePenalty`. Values outside this range are rejected by the API, so
+/// canonical `frequency_penalty`/`presence_penalty` values are clamped into
+/// it when targeting Gemini rather than sending a value likely to 400.
+pub const GEMINI_PENALTY_RANGE: std::ops::RangeInclusive<f64> = -2.0..=2.0;
+
+/// Clamp a canonical frequency/presence penalty into Gemini's accepted range.
+pub fn clamp_penalty_for_gemini(value: f64) -> f64 {
+    value.clamp(*GEMINI_PENALTY_RANGE.start(), *GEMINI_PENALTY_RANGE.end())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
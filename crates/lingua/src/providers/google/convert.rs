@@ -10,13 +10,14 @@ use crate::error::ConvertError;
 use crate::import_parse::{
     try_convert_non_empty, try_parse, try_parse_vec_or_single, try_parsers_in_order, MessageParser,
 };
+use crate::processing::tool_schema::sanitize_tool_parameters;
 use crate::providers::google::generated::{
     Blob as GoogleBlob, Candidate as GoogleCandidate, CodeExecutionResult,
     Content as GoogleContent, ExecutableCode, FileData as GoogleFileData,
     FinishReason as GoogleFinishReason, FunctionCall as GoogleFunctionCall, FunctionCallingConfig,
     FunctionCallingConfigMode, FunctionDeclaration, FunctionResponse as GoogleFunctionResponse,
-    GenerateContentRequest, GenerateContentResponse, GenerationConfig, Part as GooglePart,
-    Tool as GoogleTool, ToolConfig, UsageMetadata,
+    GenerateContentRequest, GenerateContentResponse, GenerationConfig, GoogleSearch,
+    Part as GooglePart, Tool as GoogleTool, ToolConfig, UsageMetadata,
 };
 use crate::serde_json::{self, Map, Value};
 use crate::universal::convert::TryFromLLM;
@@ -339,6 +340,7 @@ impl TryFromLLM<GoogleContent> for Message {
                 Ok(Message::Assistant {
                     content: AssistantContent::Array(assistant_parts),
                     id: None,
+                    name: None,
                 })
             }
 
@@ -433,10 +435,12 @@ impl TryFromLLM<GoogleContent> for Message {
                     };
                     Ok(Message::User {
                         content: UserContent::String(text),
+                        name: None,
                     })
                 } else {
                     Ok(Message::User {
                         content: UserContent::Array(user_parts),
+                        name: None,
                     })
                 }
             }
@@ -469,7 +473,7 @@ impl TryFromLLM<Message> for GoogleContent {
                 };
                 ("user".to_string(), vec![text_part(text)])
             }
-            Message::User { content } => {
+            Message::User { content, .. } => {
                 let parts = match content {
                     UserContent::String(s) => vec![text_part(s)],
                     UserContent::Array(parts) => {
@@ -593,6 +597,13 @@ impl TryFromLLM<Message> for GoogleContent {
                                         ..Default::default()
                                     });
                                 }
+                                // See crate::providers::refusal_fallback_text.
+                                AssistantContentPart::Refusal { text } => {
+                                    converted.push(GooglePart {
+                                        text: Some(crate::providers::refusal_fallback_text(text)),
+                                        ..Default::default()
+                                    });
+                                }
                                 AssistantContentPart::ToolCall {
                                     tool_call_id,
                                     tool_name,
@@ -896,8 +907,10 @@ impl TryFrom<&UniversalTool> for FunctionDeclaration {
                     });
                 }
                 // Strip JSON Schema keywords unsupported by Google's Schema proto (e.g.
-                // `exclusiveMinimum`) before embedding the schema in the declaration.
-                let parameters_json_schema = tool.parameters.clone().map(|mut p| {
+                // `exclusiveMinimum`, `$ref`, unrecognized `format` values) before
+                // embedding the schema in the declaration.
+                let parameters_json_schema = tool.parameters.as_ref().map(|p| {
+                    let mut p = sanitize_tool_parameters(p, ProviderFormat::Google, false);
                     strip_exclusive_minimum(&mut p);
                     p
                 });
@@ -1021,6 +1034,19 @@ impl TryFromLLM<Vec<UniversalTool>> for Vec<GoogleTool> {
                     builtin_type,
                     config,
                 } => {
+                    if matches!(provider, BuiltinToolProvider::Responses)
+                        && (builtin_type == "web_search" || builtin_type == "web_search_preview")
+                    {
+                        // Map OpenAI's web search tool onto Google's `googleSearch`
+                        // grounding tool. Only the tool's presence carries over -
+                        // `filters.allowed_domains`/`user_location` have no Google
+                        // equivalent and are dropped.
+                        builtin_tools.push(GoogleTool {
+                            google_search: Some(GoogleSearch::default()),
+                            ..Default::default()
+                        });
+                        continue;
+                    }
                     if !matches!(provider, BuiltinToolProvider::Google) {
                         continue;
                     }
@@ -1352,7 +1378,7 @@ mod tests {
 
         let message = <Message as TryFromLLM<GoogleContent>>::try_from(content).unwrap();
         match message {
-            Message::User { content } => match content {
+            Message::User { content, .. } => match content {
                 UserContent::String(s) => assert_eq!(s, "Hello"),
                 _ => panic!("Expected string content"),
             },
@@ -1425,6 +1451,7 @@ mod tests {
     fn test_message_to_google_content_user() {
         let message = Message::User {
             content: UserContent::String("Hello".to_string()),
+            name: None,
         };
 
         let content = <GoogleContent as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -1451,6 +1478,7 @@ mod tests {
         match message {
             Message::User {
                 content: UserContent::Array(parts),
+                ..
             } => match &parts[0] {
                 UserContentPart::File {
                     data,
@@ -1477,6 +1505,7 @@ mod tests {
                 media_type: "application/pdf".to_string(),
                 provider_options: None,
             }]),
+            name: None,
         };
 
         let content = <GoogleContent as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -1500,6 +1529,7 @@ mod tests {
                 media_type: None,
                 provider_options: None,
             }]),
+            name: None,
         };
 
         let content = <GoogleContent as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -1523,6 +1553,7 @@ mod tests {
                 media_type: None,
                 provider_options: None,
             }]),
+            name: None,
         };
 
         let content = <GoogleContent as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -1536,6 +1567,7 @@ mod tests {
         let message = Message::Assistant {
             content: AssistantContent::String("Hi there!".to_string()),
             id: None,
+            name: None,
         };
 
         let content = <GoogleContent as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -1559,6 +1591,7 @@ mod tests {
                 provider_executed: None,
             }]),
             id: None,
+            name: None,
         };
 
         let content = <GoogleContent as TryFromLLM<Message>>::try_from(message).unwrap();
@@ -1569,6 +1602,109 @@ mod tests {
         assert_eq!(fc.name.as_deref(), Some("get_weather"));
     }
 
+    #[test]
+    fn test_message_to_google_content_parallel_tool_calls_share_one_content() {
+        let message = Message::Assistant {
+            content: AssistantContent::Array(vec![
+                AssistantContentPart::ToolCall {
+                    tool_call_id: "call_1".to_string(),
+                    tool_name: "get_weather".to_string(),
+                    arguments: ToolCallArguments::from(r#"{"location":"SF"}"#.to_string()),
+                    encrypted_content: None,
+                    provider_options: None,
+                    status: None,
+                    caller: None,
+                    provider_executed: None,
+                },
+                AssistantContentPart::ToolCall {
+                    tool_call_id: "call_2".to_string(),
+                    tool_name: "get_weather".to_string(),
+                    arguments: ToolCallArguments::from(r#"{"location":"NYC"}"#.to_string()),
+                    encrypted_content: None,
+                    provider_options: None,
+                    status: None,
+                    caller: None,
+                    provider_executed: None,
+                },
+                AssistantContentPart::ToolCall {
+                    tool_call_id: "call_3".to_string(),
+                    tool_name: "get_weather".to_string(),
+                    arguments: ToolCallArguments::from(r#"{"location":"LA"}"#.to_string()),
+                    encrypted_content: None,
+                    provider_options: None,
+                    status: None,
+                    caller: None,
+                    provider_executed: None,
+                },
+            ]),
+            id: None,
+            name: None,
+        };
+
+        let content = <GoogleContent as TryFromLLM<Message>>::try_from(message).unwrap();
+        assert_eq!(content.role.as_deref(), Some("model"));
+        let parts = content.parts.unwrap();
+        assert_eq!(parts.len(), 3);
+        let ids: Vec<&str> = parts
+            .iter()
+            .map(|part| part.function_call.as_ref().unwrap().id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, ["call_1", "call_2", "call_3"]);
+    }
+
+    #[test]
+    fn test_google_content_parallel_function_calls_reassemble_into_one_assistant_message() {
+        let content = GoogleContent {
+            role: Some("model".to_string()),
+            parts: Some(vec![
+                GooglePart {
+                    function_call: Some(GoogleFunctionCall {
+                        id: Some("call_1".to_string()),
+                        name: Some("get_weather".to_string()),
+                        args: Some(serde_json::Map::new()),
+                    }),
+                    ..Default::default()
+                },
+                GooglePart {
+                    function_call: Some(GoogleFunctionCall {
+                        id: Some("call_2".to_string()),
+                        name: Some("get_weather".to_string()),
+                        args: Some(serde_json::Map::new()),
+                    }),
+                    ..Default::default()
+                },
+                GooglePart {
+                    function_call: Some(GoogleFunctionCall {
+                        id: Some("call_3".to_string()),
+                        name: Some("get_weather".to_string()),
+                        args: Some(serde_json::Map::new()),
+                    }),
+                    ..Default::default()
+                },
+            ]),
+        };
+
+        let message = <Message as TryFromLLM<GoogleContent>>::try_from(content).unwrap();
+        match message {
+            Message::Assistant {
+                content: AssistantContent::Array(parts),
+                ..
+            } => {
+                let ids: Vec<&str> = parts
+                    .iter()
+                    .map(|part| match part {
+                        AssistantContentPart::ToolCall { tool_call_id, .. } => {
+                            tool_call_id.as_str()
+                        }
+                        other => panic!("expected tool call, got {other:?}"),
+                    })
+                    .collect();
+                assert_eq!(ids, ["call_1", "call_2", "call_3"]);
+            }
+            other => panic!("expected assistant message, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_google_to_universal_simple() {
         let request = GenerateContentRequest {
@@ -1582,7 +1718,7 @@ mod tests {
         let messages = google_to_universal(&request).unwrap();
         assert_eq!(messages.len(), 1);
         match &messages[0] {
-            Message::User { content } => match content {
+            Message::User { content, .. } => match content {
                 UserContent::String(s) => assert_eq!(s, "Hello"),
                 _ => panic!("Expected string content"),
             },
@@ -1594,6 +1730,7 @@ mod tests {
     fn test_universal_to_google_simple() {
         let messages = vec![Message::User {
             content: UserContent::String("Hello".to_string()),
+            name: None,
         }];
 
         let result = universal_to_google(&messages).unwrap();
@@ -1610,10 +1747,12 @@ mod tests {
         let messages = vec![
             Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::String("Hi there!".to_string()),
                 id: None,
+                name: None,
             },
         ];
 
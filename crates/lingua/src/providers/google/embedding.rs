@@ -0,0 +1,169 @@
+/*!
+Conversions between Google's `batchEmbedContents` wire format and
+[`UniversalEmbeddingRequest`]/[`UniversalEmbeddingResponse`].
+
+Google's `embedContent` endpoint embeds a single [`Content`], while
+`batchEmbedContents` wraps a list of per-content requests. Since a universal
+request's `input` is always a list, it maps onto `batchEmbedContents`
+regardless of whether it holds one string or many.
+
+Google's Discovery-driven `generated.rs` does not include embedding types,
+so the wire structs below are hand-typed rather than pulled from it. Once
+the generator covers `batchEmbedContents`, replace these with typed
+adapters over the generated request/response types instead of adding more
+hand-typed structs here.
+*/
+
+use crate::error::ConvertError;
+use crate::universal::{UniversalEmbeddingRequest, UniversalEmbeddingResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GooglePart {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleContent {
+    pub parts: Vec<GooglePart>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleEmbedContentRequest {
+    pub model: String,
+    pub content: GoogleContent,
+    #[serde(
+        rename = "outputDimensionality",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub output_dimensionality: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleBatchEmbedContentsRequest {
+    pub requests: Vec<GoogleEmbedContentRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleContentEmbedding {
+    pub values: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleBatchEmbedContentsResponse {
+    pub embeddings: Vec<GoogleContentEmbedding>,
+}
+
+fn text_of(content: &GoogleContent) -> String {
+    content
+        .parts
+        .iter()
+        .map(|part| part.text.as_str())
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+pub fn google_embedding_request_to_universal(
+    request: GoogleBatchEmbedContentsRequest,
+) -> Result<UniversalEmbeddingRequest, ConvertError> {
+    let model = request.requests.first().map(|r| r.model.clone()).ok_or(
+        ConvertError::MissingRequiredField {
+            field: "requests".to_string(),
+        },
+    )?;
+    let dimensions = request
+        .requests
+        .first()
+        .and_then(|r| r.output_dimensionality);
+    let input = request
+        .requests
+        .iter()
+        .map(|r| text_of(&r.content))
+        .collect();
+    Ok(UniversalEmbeddingRequest {
+        model,
+        input,
+        dimensions,
+    })
+}
+
+pub fn universal_to_google_embedding_request(
+    request: &UniversalEmbeddingRequest,
+) -> GoogleBatchEmbedContentsRequest {
+    let model = normalize_google_embedding_model(&request.model);
+    GoogleBatchEmbedContentsRequest {
+        requests: request
+            .input
+            .iter()
+            .map(|text| GoogleEmbedContentRequest {
+                model: model.clone(),
+                content: GoogleContent {
+                    parts: vec![GooglePart { text: text.clone() }],
+                },
+                output_dimensionality: request.dimensions,
+            })
+            .collect(),
+    }
+}
+
+/// Google's `batchEmbedContents` requires each request's `model` field to be
+/// namespaced as `models/<id>`; a universal request may hold the bare id, so
+/// the prefix is added when missing. A request converted from Google keeps it.
+fn normalize_google_embedding_model(model: &str) -> String {
+    if model.starts_with("models/") {
+        model.to_string()
+    } else {
+        format!("models/{model}")
+    }
+}
+
+pub fn google_embedding_response_to_universal(
+    response: GoogleBatchEmbedContentsResponse,
+) -> UniversalEmbeddingResponse {
+    UniversalEmbeddingResponse {
+        model: None,
+        embeddings: response
+            .embeddings
+            .into_iter()
+            .map(|embedding| embedding.values)
+            .collect(),
+        usage: None,
+    }
+}
+
+pub fn universal_to_google_embedding_response(
+    response: &UniversalEmbeddingResponse,
+) -> GoogleBatchEmbedContentsResponse {
+    GoogleBatchEmbedContentsResponse {
+        embeddings: response
+            .embeddings
+            .iter()
+            .map(|values| GoogleContentEmbedding {
+                values: values.clone(),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn google_request_round_trips_multiple_inputs() {
+        let universal = UniversalEmbeddingRequest {
+            model: "text-embedding-004".to_string(),
+            input: vec!["hello".to_string(), "world".to_string()],
+            dimensions: Some(256),
+        };
+        let google = universal_to_google_embedding_request(&universal);
+        assert_eq!(google.requests.len(), 2);
+        assert_eq!(google.requests[0].model, "models/text-embedding-004");
+        assert_eq!(google.requests[0].output_dimensionality, Some(256));
+
+        let back = google_embedding_request_to_universal(google).unwrap();
+        assert_eq!(back.model, "models/text-embedding-004");
+        assert_eq!(back.input, vec!["hello", "world"]);
+        assert_eq!(back.dimensions, Some(256));
+    }
+}
@@ -5,6 +5,7 @@ pub mod adapter;
 pub mod capabilities;
 pub mod convert;
 pub mod detect;
+pub mod embedding;
 pub mod generated;
 pub mod params;
 
@@ -23,6 +24,13 @@ pub use detect::{try_parse_google, DetectionError};
 // Re-export conversion functions
 pub use convert::{google_to_universal, universal_to_google};
 
+// Re-export embedding conversion functions and types
+pub use embedding::{
+    google_embedding_request_to_universal, google_embedding_response_to_universal,
+    universal_to_google_embedding_request, universal_to_google_embedding_response,
+    GoogleBatchEmbedContentsRequest, GoogleBatchEmbedContentsResponse,
+};
+
 // Re-export the most commonly used Google AI types for convenience
 pub use generated::{
     Candidate, Content, FunctionDeclaration, GenerateContentRequest, GenerateContentResponse,
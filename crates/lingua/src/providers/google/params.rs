@@ -35,6 +35,9 @@ pub struct GoogleParams {
     pub tool_config: Option<ToolConfig>,
 
     // === Caching ===
+    /// Reference to a `CachedContent` resource, e.g. `cachedContents/{id}`. Typed here so
+    /// callers can inspect it directly; the adapter is responsible for carrying it through
+    /// `UniversalParams::extras` since there's no canonical Universal representation.
     pub cached_content: Option<String>,
 
     /// Unknown fields - automatically captured by serde flatten.
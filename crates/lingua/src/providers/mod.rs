@@ -19,3 +19,16 @@ pub mod google;
 
 #[cfg(feature = "openai")]
 pub mod openai;
+
+/// Anthropic, Bedrock and Google have no dedicated content block/part for a
+/// model refusal — refusals are surfaced out-of-band (Anthropic's
+/// `stop_reason`, Bedrock's equivalent, etc.), not as inline content. When
+/// converting a universal [`AssistantContentPart::Refusal`](crate::universal::AssistantContentPart::Refusal)
+/// back to one of these wire formats, providers therefore render it as a
+/// plain text block/part rather than dropping the content. This is the one
+/// place that lossy-fallback decision is made; call sites should reference
+/// this function's doc comment rather than re-deriving the rationale.
+#[cfg(any(feature = "anthropic", feature = "bedrock", feature = "google"))]
+pub(crate) fn refusal_fallback_text(text: String) -> String {
+    text
+}
@@ -32,7 +32,7 @@ use crate::providers::openai::{try_parse_openai, try_parse_openai_legacy_prompt}
 use crate::serde_json::{self, Map, Value};
 use crate::universal::convert::TryFromLLM;
 use crate::universal::message::{
-    AssistantContent, AssistantContentPart, Message, ToolContentPart, UserContent,
+    AssistantContent, AssistantContentPart, Message, ProviderOptions, ToolContentPart, UserContent,
 };
 use crate::universal::reasoning::effort_to_budget;
 use crate::universal::request::{
@@ -49,6 +49,19 @@ use std::convert::TryInto;
 
 const OPENAI_CHAT_MIN_MAX_COMPLETION_TOKENS: i64 = 16;
 
+/// Top-level chat completion response fields with a canonical `UniversalResponse`
+/// mapping. Anything else is preserved verbatim in `provider_options`.
+const RESPONSE_KNOWN_TOP_LEVEL_FIELDS: &[&str] = &[
+    "id",
+    "object",
+    "created",
+    "model",
+    "choices",
+    "usage",
+    "system_fingerprint",
+    "service_tier",
+];
+
 /// Adapter for OpenAI Chat Completions API.
 pub struct OpenAIAdapter;
 
@@ -69,11 +82,16 @@ fn legacy_prompt_to_user_content(
 ) -> Result<UserContent, TransformError> {
     match prompt {
         OpenAICompletionPrompt::String(content) => Ok(UserContent::String(content)),
-        OpenAICompletionPrompt::StringArray(_)
-        | OpenAICompletionPrompt::TokenArray(_)
-        | OpenAICompletionPrompt::TokenArrayArray(_) => Err(TransformError::ToUniversalFailed(
-            "OpenAI legacy prompt compatibility only supports a single string prompt for Chat Completions".to_string(),
-        )),
+        // The legacy Completions API treats a string array as several prompts
+        // batched into one request (one completion per element). Chat backends
+        // have no equivalent of batched completions, so we join the batch into
+        // a single prompt rather than dropping every element but the first.
+        OpenAICompletionPrompt::StringArray(parts) => Ok(UserContent::String(parts.join("\n\n"))),
+        OpenAICompletionPrompt::TokenArray(_) | OpenAICompletionPrompt::TokenArrayArray(_) => {
+            Err(TransformError::ToUniversalFailed(
+                "OpenAI legacy prompt compatibility does not support token-array prompts for Chat Completions".to_string(),
+            ))
+        }
     }
 }
 
@@ -223,6 +241,7 @@ impl ProviderAdapter for OpenAIAdapter {
             reject_legacy_prompt_only_extras(&typed_params.extras)?;
             vec![Message::User {
                 content: legacy_prompt_to_user_content(prompt)?,
+                name: None,
             }]
         } else {
             return Err(TransformError::ToUniversalFailed(
@@ -244,11 +263,14 @@ impl ProviderAdapter for OpenAIAdapter {
             max_tokens,
         );
 
-        // Build canonical params from typed fields
+        // Build canonical params from typed fields. `safety_identifier` is OpenAI's current
+        // end-user tag; the deprecated `user` field is still accepted and maps to the same
+        // Anthropic-style `user_id`, checked second so `safety_identifier` wins if both are set.
         let canonical_metadata = typed_params.metadata.clone().or_else(|| {
             typed_params
                 .safety_identifier
                 .as_ref()
+                .or(typed_params.user.as_ref())
                 .map(|s| serde_json::json!({ "user_id": s }))
         });
 
@@ -270,6 +292,7 @@ impl ProviderAdapter for OpenAIAdapter {
                 .response_format
                 .as_ref()
                 .and_then(|v| (ProviderFormat::ChatCompletions, v).try_into().ok()),
+            modalities: None, // OpenAI's chat `modalities` param covers audio, not image output
             seed: typed_params.seed,
             presence_penalty: typed_params.presence_penalty,
             frequency_penalty: typed_params.frequency_penalty,
@@ -467,7 +490,14 @@ impl ProviderAdapter for OpenAIAdapter {
         insert_opt_i64(&mut obj, "seed", req.params.seed);
         insert_opt_f64(&mut obj, "presence_penalty", req.params.presence_penalty);
         insert_opt_f64(&mut obj, "frequency_penalty", req.params.frequency_penalty);
-        insert_opt_bool(&mut obj, "logprobs", req.params.logprobs);
+        // `top_logprobs` requires `logprobs: true` on the wire; a caller (or a
+        // cross-provider translation) may set the former without the latter,
+        // so normalize it here rather than reject the request.
+        let logprobs = req
+            .params
+            .logprobs
+            .or(req.params.top_logprobs.is_some().then_some(true));
+        insert_opt_bool(&mut obj, "logprobs", logprobs);
         insert_opt_i64(&mut obj, "top_logprobs", req.params.top_logprobs);
         insert_opt_bool(&mut obj, "stream", req.params.stream);
         if req.params.stream == Some(true) {
@@ -630,6 +660,18 @@ impl ProviderAdapter for OpenAIAdapter {
 
         let usage = UniversalUsage::extract_from_response(&payload, self.format());
 
+        // Unknown top-level fields (e.g. a field OpenAI ships before this crate
+        // models it) are preserved as provider-options rather than dropped, so a
+        // round trip back to Chat Completions doesn't lose them.
+        let provider_options = payload.as_object().and_then(|obj| {
+            let options: Map<String, Value> = obj
+                .iter()
+                .filter(|(key, _)| !RESPONSE_KNOWN_TOP_LEVEL_FIELDS.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            (!options.is_empty()).then_some(ProviderOptions { options })
+        });
+
         Ok(UniversalResponse {
             id: UniversalResponse::extract_id_from_payload(&payload),
             id_format: Some(self.format()),
@@ -641,6 +683,15 @@ impl ProviderAdapter for OpenAIAdapter {
             usage,
             finish_reason,
             finish_reasons,
+            system_fingerprint: payload
+                .get("system_fingerprint")
+                .and_then(Value::as_str)
+                .map(String::from),
+            provider_options,
+            service_tier: payload
+                .get("service_tier")
+                .and_then(Value::as_str)
+                .map(String::from),
         })
     }
 
@@ -692,6 +743,27 @@ impl ProviderAdapter for OpenAIAdapter {
             map.insert("usage".into(), usage_val);
         }
 
+        if let Some(system_fingerprint) = resp.system_fingerprint.as_deref() {
+            map.insert(
+                "system_fingerprint".into(),
+                Value::String(system_fingerprint.into()),
+            );
+        }
+
+        if let Some(service_tier) = resp.service_tier.as_deref() {
+            map.insert("service_tier".into(), Value::String(service_tier.into()));
+        }
+
+        // Restore unknown fields captured on ingestion, but only on a round trip
+        // back to Chat Completions - they're meaningless for other providers.
+        if resp.id_format == Some(self.format()) {
+            if let Some(provider_options) = &resp.provider_options {
+                for (key, value) in &provider_options.options {
+                    map.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            }
+        }
+
         Ok(Value::Object(map))
     }
 
@@ -941,6 +1013,22 @@ mod tests {
         assert!(reconstructed.get("messages").is_some());
     }
 
+    #[test]
+    fn test_openai_chat_store_round_trips_through_universal() {
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "model": "gpt-4o-mini",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "store": true
+        });
+
+        let universal = adapter.request_to_universal(payload).unwrap();
+        assert_eq!(universal.params.store, Some(true));
+
+        let reconstructed = adapter.request_from_universal(&universal).unwrap();
+        assert_eq!(reconstructed.get("store").unwrap(), &json!(true));
+    }
+
     #[test]
     fn test_openai_prompt_cache_key_imports_to_canonical_param() {
         let adapter = OpenAIAdapter;
@@ -970,6 +1058,7 @@ mod tests {
             model: Some("gpt-4".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 prompt_cache_key: Some("cache-key-1".to_string()),
@@ -1054,6 +1143,35 @@ mod tests {
         assert_eq!(value["prompt_cache_key"], json!("cache-key-updated"));
     }
 
+    #[test]
+    fn test_openai_response_unknown_top_level_field_survives_roundtrip() {
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "created": 1700000000,
+            "model": "gpt-4",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "hi"},
+                "finish_reason": "stop"
+            }],
+            "made_up_future_field": {"anything": "goes"}
+        });
+
+        let universal = adapter.response_to_universal(payload).unwrap();
+        assert_eq!(
+            universal
+                .provider_options
+                .as_ref()
+                .and_then(|opts| opts.options.get("made_up_future_field")),
+            Some(&json!({"anything": "goes"}))
+        );
+
+        let value = adapter.response_from_universal(&universal).unwrap();
+        assert_eq!(value["made_up_future_field"], json!({"anything": "goes"}));
+    }
+
     #[derive(serde::Deserialize)]
     struct ChatCompletionResponseView {
         choices: Vec<ChatCompletionChoiceView>,
@@ -1090,6 +1208,7 @@ mod tests {
                         },
                     ]),
                     id: None,
+                    name: None,
                 },
                 Message::Tool {
                     content: vec![ToolContentPart::ToolDiscoveryResult(
@@ -1110,11 +1229,15 @@ mod tests {
                 Message::Assistant {
                     content: AssistantContent::String("Done".to_string()),
                     id: None,
+                    name: None,
                 },
             ],
             usage: None,
             finish_reason: None,
             finish_reasons: Vec::new(),
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
 
         let value = adapter.response_from_universal(&resp).unwrap();
@@ -1174,6 +1297,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_openai_logit_bias_roundtrip_preserves_token_id_keys_exactly() {
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "model": "gpt-4",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "logit_bias": {"50256": -100, "17": 5}
+        });
+
+        let universal = adapter.request_to_universal(payload.clone()).unwrap();
+        let reconstructed = adapter.request_from_universal(&universal).unwrap();
+
+        assert_eq!(reconstructed.get("logit_bias"), payload.get("logit_bias"));
+    }
+
     #[test]
     fn test_openai_reasoning_roundtrip() {
         use crate::universal::message::{AssistantContent, AssistantContentPart, TextContentPart};
@@ -1186,6 +1324,7 @@ mod tests {
             messages: vec![
                 Message::User {
                     content: crate::universal::message::UserContent::String("Hello".to_string()),
+                    name: None,
                 },
                 Message::Assistant {
                     content: AssistantContent::Array(vec![
@@ -1204,6 +1343,7 @@ mod tests {
                 },
                 Message::User {
                     content: crate::universal::message::UserContent::String("Thanks".to_string()),
+                    name: None,
                 },
             ],
             params: Default::default(),
@@ -1266,6 +1406,7 @@ mod tests {
             messages: vec![
                 Message::User {
                     content: crate::universal::message::UserContent::String("Hello".to_string()),
+                    name: None,
                 },
                 Message::Assistant {
                     content: AssistantContent::Array(vec![AssistantContentPart::Reasoning {
@@ -1339,6 +1480,7 @@ mod tests {
             messages: vec![
                 Message::User {
                     content: crate::universal::message::UserContent::String("Hello".to_string()),
+                    name: None,
                 },
                 Message::Assistant {
                     content: AssistantContent::Array(vec![AssistantContentPart::Reasoning {
@@ -1346,6 +1488,7 @@ mod tests {
                         encrypted_content: None,
                     }]),
                     id: None,
+                    name: None,
                 },
             ],
             params: Default::default(),
@@ -1533,6 +1676,7 @@ mod tests {
             model: Some("gpt-5-mini".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 temperature: Some(0.0), // User specified, but should be omitted
@@ -1559,6 +1703,7 @@ mod tests {
             model: Some("gpt-4".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 temperature: Some(0.7),
@@ -1586,6 +1731,7 @@ mod tests {
             model: Some("gpt-5-mini".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 top_p: Some(0.9),
@@ -1610,6 +1756,7 @@ mod tests {
             model: Some("gpt-4".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 top_p: Some(0.9),
@@ -1632,6 +1779,7 @@ mod tests {
             model: Some("gpt-5-nano".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 reasoning: Some(ReasoningConfig {
@@ -1660,6 +1808,7 @@ mod tests {
             model: Some("gpt-5-nano".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 metadata: Some(json!({ "user_id": "user-123" })),
@@ -1743,6 +1892,7 @@ mod tests {
             model: Some("gpt-4o-mini".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 metadata: Some(json!({ "request_id": "req-123" })),
@@ -1760,6 +1910,105 @@ mod tests {
         assert!(result.safety_identifier.is_none());
     }
 
+    #[test]
+    fn test_openai_chat_arbitrary_metadata_survives_same_provider_round_trip() {
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "model": "gpt-5-nano",
+            "messages": [{ "role": "user", "content": "Hello" }],
+            "metadata": { "tenant": "acme", "environment": "prod" }
+        });
+
+        let universal = adapter.request_to_universal(payload.clone()).unwrap();
+        let output = adapter.request_from_universal(&universal).unwrap();
+
+        assert_eq!(output.get("metadata"), payload.get("metadata"));
+    }
+
+    #[test]
+    fn test_openai_chat_top_logprobs_round_trips_and_implies_logprobs() {
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "model": "gpt-4o",
+            "messages": [{ "role": "user", "content": "Hello" }],
+            "top_logprobs": 5
+        });
+
+        let universal = adapter.request_to_universal(payload.clone()).unwrap();
+        assert_eq!(universal.params.top_logprobs, Some(5));
+
+        let output = adapter.request_from_universal(&universal).unwrap();
+        assert_eq!(output.get("top_logprobs"), Some(&json!(5)));
+        assert_eq!(output.get("logprobs"), Some(&json!(true)));
+    }
+
+    #[test]
+    fn test_cross_provider_content_part_order_survives_chat_anthropic_gemini_round_trip() {
+        use crate::processing::adapters::ProviderAdapter;
+        use crate::providers::anthropic::adapter::AnthropicAdapter;
+        use crate::providers::google::adapter::GoogleAdapter;
+        use crate::universal::message::{UserContent, UserContentPart};
+
+        // A text -> image -> text interleaving is semantically meaningful (e.g. "here is
+        // the chart <image> and here is the caption") and must not be reordered into all
+        // text then all images by any adapter.
+        fn text_image_order(content: &UserContent) -> Vec<&'static str> {
+            match content {
+                UserContent::String(_) => panic!("expected an array of content parts"),
+                UserContent::Array(parts) => parts
+                    .iter()
+                    .map(|part| match part {
+                        UserContentPart::Text(_) => "text",
+                        UserContentPart::Image { .. } => "image",
+                        UserContentPart::File { .. } => "file",
+                    })
+                    .collect(),
+            }
+        }
+
+        let openai_payload = json!({
+            "model": "gpt-4o",
+            "messages": [{
+                "role": "user",
+                "content": [
+                    {"type": "text", "text": "before"},
+                    {"type": "image_url", "image_url": {"url": "https://example.com/chart.png"}},
+                    {"type": "text", "text": "after"}
+                ]
+            }]
+        });
+
+        let openai_adapter = OpenAIAdapter;
+        let anthropic_adapter = AnthropicAdapter;
+        let google_adapter = GoogleAdapter;
+
+        let mut universal = openai_adapter.request_to_universal(openai_payload).unwrap();
+        let Message::User { content, .. } = &universal.messages[0] else {
+            panic!("expected a user message");
+        };
+        assert_eq!(text_image_order(content), vec!["text", "image", "text"]);
+
+        universal.model = Some("claude-sonnet-4-5".to_string());
+        let anthropic_payload = anthropic_adapter
+            .request_from_universal(&universal)
+            .unwrap();
+        let mut universal = anthropic_adapter
+            .request_to_universal(anthropic_payload)
+            .unwrap();
+        let Message::User { content, .. } = &universal.messages[0] else {
+            panic!("expected a user message");
+        };
+        assert_eq!(text_image_order(content), vec!["text", "image", "text"]);
+
+        universal.model = Some("gemini-1.5-pro".to_string());
+        let google_payload = google_adapter.request_from_universal(&universal).unwrap();
+        let universal = google_adapter.request_to_universal(google_payload).unwrap();
+        let Message::User { content, .. } = &universal.messages[0] else {
+            panic!("expected a user message");
+        };
+        assert_eq!(text_image_order(content), vec!["text", "image", "text"]);
+    }
+
     #[test]
     fn test_openai_chat_uses_max_completion_tokens_for_gpt5_models() {
         use crate::providers::openai::generated::CreateChatCompletionRequestClass;
@@ -1770,6 +2019,7 @@ mod tests {
             model: Some("gpt-5-nano".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(1024)),
@@ -1794,6 +2044,7 @@ mod tests {
             model: Some("gpt-4o".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Local food".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 tools: Some(vec![UniversalTool::builtin(
@@ -1832,6 +2083,7 @@ mod tests {
             model: Some("gpt-4o".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Latest OpenAI news".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 tools: Some(vec![UniversalTool::builtin(
@@ -1864,6 +2116,7 @@ mod tests {
             model: Some("gpt-4o".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Local food".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 tools: Some(vec![UniversalTool::builtin(
@@ -1898,6 +2151,7 @@ mod tests {
                 content: UserContent::String(
                     "Write a very long essay about the ocean.".to_string(),
                 ),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(1)),
@@ -1922,6 +2176,7 @@ mod tests {
                 content: UserContent::String(
                     "Write a very long essay about the ocean.".to_string(),
                 ),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(100)),
@@ -1953,6 +2208,7 @@ mod tests {
                 content: UserContent::String(
                     "Write a very long essay about the ocean.".to_string(),
                 ),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(100)),
@@ -1965,4 +2221,221 @@ mod tests {
             serde_json::from_value(adapter.request_from_universal(&req).unwrap()).unwrap();
         assert_eq!(typed.max_completion_tokens, Some(1));
     }
+
+    #[test]
+    fn test_openai_chat_uses_max_tokens_for_legacy_models() {
+        use crate::providers::openai::generated::CreateChatCompletionRequestClass;
+        use crate::universal::message::UserContent;
+
+        let adapter = OpenAIAdapter;
+        let req = UniversalRequest {
+            model: Some("gpt-4o".to_string()),
+            messages: vec![Message::User {
+                content: UserContent::String("Hello".to_string()),
+                name: None,
+            }],
+            params: UniversalParams {
+                token_budget: Some(TokenBudget::OutputTokens(1024)),
+                ..Default::default()
+            },
+        };
+
+        let typed: CreateChatCompletionRequestClass =
+            serde_json::from_value(adapter.request_from_universal(&req).unwrap()).unwrap();
+        assert_eq!(typed.max_tokens, Some(1024));
+        assert!(typed.max_completion_tokens.is_none());
+    }
+
+    #[test]
+    fn test_openai_chat_never_emits_both_max_tokens_fields() {
+        use crate::universal::message::UserContent;
+
+        let adapter = OpenAIAdapter;
+        for model in ["gpt-4o", "gpt-5-nano"] {
+            let req = UniversalRequest {
+                model: Some(model.to_string()),
+                messages: vec![Message::User {
+                    content: UserContent::String("Hello".to_string()),
+                    name: None,
+                }],
+                params: UniversalParams {
+                    token_budget: Some(TokenBudget::OutputTokens(1024)),
+                    ..Default::default()
+                },
+            };
+
+            let value = adapter.request_from_universal(&req).unwrap();
+            assert!(
+                !(value.get("max_tokens").is_some()
+                    && value.get("max_completion_tokens").is_some()),
+                "model {model} should never send both max_tokens and max_completion_tokens"
+            );
+        }
+    }
+
+    #[test]
+    fn test_openai_chat_preserves_openrouter_provider_routing_preferences() {
+        // OpenRouter is served through the generic ChatCompletions adapter, so its
+        // `provider` routing-preference object (not part of the OpenAI schema) must
+        // survive a same-format roundtrip via extras rather than being dropped.
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "model": "anthropic/claude-3.5-sonnet",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "provider": {
+                "order": ["Anthropic"],
+                "allow_fallbacks": false
+            }
+        });
+
+        let universal = adapter.request_to_universal(payload.clone()).unwrap();
+        let out = adapter.request_from_universal(&universal).unwrap();
+
+        assert_eq!(out.get("provider"), payload.get("provider"));
+    }
+
+    #[test]
+    fn test_openai_chat_roundtrips_message_name() {
+        // The per-message `name` field disambiguates participants sharing a role
+        // (e.g. multiple simulated users in a multi-agent transcript) and must
+        // survive a request_to_universal -> request_from_universal roundtrip.
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "model": "gpt-4o",
+            "messages": [
+                {"role": "user", "content": "Hello", "name": "alice"},
+                {"role": "assistant", "content": "Hi there", "name": "bot-1"},
+            ],
+        });
+
+        let universal = adapter.request_to_universal(payload.clone()).unwrap();
+        match &universal.messages[0] {
+            Message::User { name, .. } => assert_eq!(name.as_deref(), Some("alice")),
+            other => panic!("expected User message, got {other:?}"),
+        }
+        match &universal.messages[1] {
+            Message::Assistant { name, .. } => assert_eq!(name.as_deref(), Some("bot-1")),
+            other => panic!("expected Assistant message, got {other:?}"),
+        }
+
+        let out = adapter.request_from_universal(&universal).unwrap();
+        assert_eq!(out["messages"][0]["name"], json!("alice"));
+        assert_eq!(out["messages"][1]["name"], json!("bot-1"));
+    }
+
+    #[test]
+    fn test_openai_seed_and_system_fingerprint_roundtrip() {
+        // `seed` (request) and `system_fingerprint` (response) work together to let
+        // callers detect backend changes that might affect determinism - both must
+        // survive their respective roundtrips.
+        let adapter = OpenAIAdapter;
+        let request_payload = json!({
+            "model": "gpt-4o",
+            "seed": 42,
+            "messages": [{"role": "user", "content": "Hello"}],
+        });
+        let universal_request = adapter.request_to_universal(request_payload).unwrap();
+        assert_eq!(universal_request.params.seed, Some(42));
+        let out_request = adapter.request_from_universal(&universal_request).unwrap();
+        assert_eq!(out_request["seed"], json!(42));
+
+        let response_payload = json!({
+            "id": "chatcmpl-test",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "gpt-4o",
+            "system_fingerprint": "fp_44709d6fcb",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "Hi there"},
+                "finish_reason": "stop",
+            }],
+        });
+        let universal_response = adapter.response_to_universal(response_payload).unwrap();
+        assert_eq!(
+            universal_response.system_fingerprint.as_deref(),
+            Some("fp_44709d6fcb")
+        );
+        let out_response = adapter
+            .response_from_universal(&universal_response)
+            .unwrap();
+        assert_eq!(out_response["system_fingerprint"], json!("fp_44709d6fcb"));
+    }
+
+    #[test]
+    fn test_stream_to_universal_preserves_cached_tokens() {
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "id": "chatcmpl-test",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": "gpt-4o",
+            "choices": [],
+            "usage": {
+                "prompt_tokens": 100,
+                "completion_tokens": 25,
+                "total_tokens": 125,
+                "prompt_tokens_details": {
+                    "cached_tokens": 40
+                }
+            }
+        });
+
+        let chunk = adapter
+            .stream_to_universal(payload)
+            .unwrap()
+            .expect("usage-carrying chunk should emit a chunk");
+
+        let usage = chunk.usage.expect("usage must be present");
+        assert_eq!(usage.prompt_cached_tokens, Some(40));
+
+        let out = adapter.stream_from_universal(&chunk).unwrap();
+        assert_eq!(out["usage"]["prompt_tokens_details"]["cached_tokens"], 40);
+    }
+
+    #[test]
+    fn test_stream_round_trip_keeps_choices_separate_by_index() {
+        let adapter = OpenAIAdapter;
+        let payload = json!({
+            "id": "chatcmpl-test",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": "gpt-4o",
+            "choices": [
+                {"index": 1, "delta": {"content": "world"}, "finish_reason": null},
+                {"index": 0, "delta": {"content": "hello"}, "finish_reason": null}
+            ]
+        });
+
+        let chunk = adapter
+            .stream_to_universal(payload)
+            .unwrap()
+            .expect("chunk with choices should parse");
+
+        assert_eq!(chunk.choices.len(), 2);
+        let by_index: std::collections::HashMap<u32, &str> = chunk
+            .choices
+            .iter()
+            .map(|c| {
+                (
+                    c.index,
+                    c.delta
+                        .as_ref()
+                        .and_then(|d| d.get("content"))
+                        .and_then(Value::as_str)
+                        .unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(by_index[&0], "hello");
+        assert_eq!(by_index[&1], "world");
+
+        let out = adapter.stream_from_universal(&chunk).unwrap();
+        let out_choices = out["choices"].as_array().unwrap();
+        assert_eq!(out_choices.len(), 2);
+        assert_eq!(out_choices[0]["index"], 1);
+        assert_eq!(out_choices[0]["delta"]["content"], "world");
+        assert_eq!(out_choices[1]["index"], 0);
+        assert_eq!(out_choices[1]["delta"]["content"], "hello");
+    }
 }
@@ -44,6 +44,11 @@ const MODEL_TRANSFORM_RULES: &[(&str, &[ModelTransform])] = &[
         "gpt-5",
         &[StripTemperature, StripTopP, ForceMaxCompletionTokens],
     ),
+    // Pre-reasoning OpenAI chat models predate `max_completion_tokens` and keep
+    // accepting (and are commonly still called with) `max_tokens`.
+    ("gpt-4", &[ForceMaxTokens]),
+    ("gpt-3.5", &[ForceMaxTokens]),
+    ("chatgpt-", &[ForceMaxTokens]),
     // TODO: would be nice if we could apply these rules by provider instead of model name, and
     // apply these to all Mistral models
     ("mistral", &[ForceMaxTokens]),
@@ -356,8 +361,8 @@ mod tests {
                 "gpt-5-mini",
                 &[StripTemperature, StripTopP, ForceMaxCompletionTokens][..],
             ),
-            ("gpt-4", &[][..]),
-            ("gpt-4o", &[][..]),
+            ("gpt-4", &[ForceMaxTokens][..]),
+            ("gpt-4o", &[ForceMaxTokens][..]),
             ("claude-3", &[][..]),
         ];
         for (model, expected) in cases {
@@ -367,8 +372,8 @@ mod tests {
 
     #[test]
     fn test_model_needs_transforms() {
-        let needs = ["o1", "o3", "gpt-5"];
-        let no_needs = ["gpt-4", "gpt-4o", "claude-3"];
+        let needs = ["o1", "o3", "gpt-5", "gpt-4", "gpt-4o"];
+        let no_needs = ["claude-3"];
         for model in needs {
             assert!(model_needs_transforms(model), "should need: {}", model);
         }
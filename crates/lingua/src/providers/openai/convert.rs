@@ -1078,6 +1078,7 @@ fn merge_adjacent_reasoning_assistant_messages(messages: Vec<Message>) -> Vec<Me
         let Message::Assistant {
             content: AssistantContent::Array(reasoning_parts),
             id: reasoning_id,
+            ..
         } = previous
         else {
             merged.push(previous);
@@ -1088,11 +1089,13 @@ fn merge_adjacent_reasoning_assistant_messages(messages: Vec<Message>) -> Vec<Me
         let Message::Assistant {
             content: next_content,
             id: next_id,
+            ..
         } = message
         else {
             merged.push(Message::Assistant {
                 content: AssistantContent::Array(reasoning_parts),
                 id: reasoning_id,
+                name: None,
             });
             merged.push(message);
             continue;
@@ -1114,6 +1117,7 @@ fn merge_adjacent_reasoning_assistant_messages(messages: Vec<Message>) -> Vec<Me
         merged.push(Message::Assistant {
             content: AssistantContent::Array(combined_parts),
             id: next_id.or(reasoning_id),
+            name: None,
         });
     }
 
@@ -1155,6 +1159,7 @@ fn try_messages_from_openai_instructions(input: openai::Instructions) -> Option<
         }
         openai::Instructions::String(text) => Some(vec![Message::User {
             content: UserContent::String(text),
+            name: None,
         }]),
     }
 }
@@ -1244,6 +1249,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::CodeInterpreterCall) => {
@@ -1265,6 +1271,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::FileSearchCall) => {
@@ -1285,6 +1292,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::ComputerCall) => {
@@ -1304,6 +1312,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::ImageGenerationCall) => {
@@ -1323,6 +1332,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::LocalShellCall) => {
@@ -1342,6 +1352,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::McpCall) => {
@@ -1361,6 +1372,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::McpListTools) => {
@@ -1381,6 +1393,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::McpApprovalRequest) => {
@@ -1399,6 +1412,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::ToolSearchCall) => {
@@ -1450,6 +1464,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(summaries),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::Program) => {
@@ -1469,6 +1484,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                             fingerprint: input.fingerprint,
                         }]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 Some(openai::InputItemType::ProgramOutput) => {
@@ -1501,6 +1517,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                             },
                         ]),
                         id: input.id,
+                        name: None,
                     });
                 }
                 item_type @ (Some(openai::InputItemType::FunctionCall)
@@ -1552,6 +1569,7 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                     result.push(Message::Assistant {
                         content: AssistantContent::Array(vec![tool_call_part]),
                         id: input.id.clone(),
+                        name: None,
                     });
                 }
                 item_type @ (Some(openai::InputItemType::FunctionCallOutput)
@@ -1610,10 +1628,12 @@ impl TryFromLLM<Vec<openai::InputItem>> for Vec<Message> {
                         },
                         openai::InputItemRole::User => Message::User {
                             content: TryFromLLM::try_from(content)?,
+                            name: None,
                         },
                         openai::InputItemRole::Assistant => Message::Assistant {
                             id: input.id,
                             content: TryFromLLM::try_from(content)?,
+                            name: None,
                         },
                     });
                 }
@@ -1955,6 +1975,11 @@ impl TryFromLLM<AssistantContentPart> for openai::InputContent {
                 logprobs: Some(vec![]),
                 ..Default::default()
             },
+            AssistantContentPart::Refusal { text } => openai::InputContent {
+                input_content_type: openai::InputItemContentListType::Refusal,
+                text: Some(text),
+                ..Default::default()
+            },
             AssistantContentPart::ToolDiscoveryCall { .. } => {
                 return Err(ConvertError::UnsupportedInputType {
                     type_info: "AssistantContentPart::ToolDiscoveryCall must be converted as a Responses input item".to_string(),
@@ -2081,6 +2106,9 @@ impl TryFromLLM<openai::InputContent> for AssistantContentPart {
                     provider_options,
                 })
             }
+            openai::InputItemContentListType::Refusal => AssistantContentPart::Refusal {
+                text: value.text.unwrap_or_else(|| REFUSAL_TEXT.to_string()),
+            },
             // TODO: ToolCall content type support - not yet implemented in generated types
             _ => {
                 return Err(ConvertError::UnsupportedInputType {
@@ -2154,13 +2182,13 @@ impl TryFromLLM<Message> for openai::InputItem {
                 input_item_type: Some(openai::InputItemType::Message),
                 ..Default::default()
             }),
-            Message::User { content } => Ok(openai::InputItem {
+            Message::User { content, .. } => Ok(openai::InputItem {
                 role: Some(openai::InputItemRole::User),
                 content: Some(TryFromLLM::try_from(content)?),
                 input_item_type: Some(openai::InputItemType::Message),
                 ..Default::default()
             }),
-            Message::Assistant { content, id } => {
+            Message::Assistant { content, id, .. } => {
                 match content {
                     AssistantContent::String(text) => Ok(openai::InputItem {
                         role: Some(openai::InputItemRole::Assistant),
@@ -2817,7 +2845,7 @@ pub fn universal_to_responses_input(
                     }
                 }
             }
-            Message::Assistant { content, id } => {
+            Message::Assistant { content, id, .. } => {
                 // Handle assistant messages with potential 1:N expansion for mixed content
                 match content {
                     AssistantContent::String(text) => {
@@ -3693,6 +3721,7 @@ impl TryFromLLM<Vec<openai::OutputItem>> for Vec<Message> {
                 messages.push(Message::Assistant {
                     content: AssistantContent::Array(parts),
                     id: item_id,
+                    name: None,
                 });
             }
         }
@@ -3770,7 +3799,7 @@ impl TryFromLLM<Vec<Message>> for Vec<openai::OutputItem> {
                         ..Default::default()
                     });
                 }
-                Message::Assistant { content, id } => {
+                Message::Assistant { content, id, .. } => {
                     match content {
                         AssistantContent::String(text) => {
                             result.push(openai::OutputItem {
@@ -4423,7 +4452,10 @@ impl TryFromLLM<ChatCompletionRequestMessageExt> for Message {
             openai::ChatCompletionRequestMessageRole::User => {
                 let content =
                     chat_completion_content_to_user_content(msg.content, msg.cache_control)?;
-                Ok(Message::User { content })
+                Ok(Message::User {
+                    content,
+                    name: msg.name,
+                })
             }
             openai::ChatCompletionRequestMessageRole::Assistant => {
                 let mut content_parts: Vec<AssistantContentPart> = Vec::new();
@@ -4508,7 +4540,11 @@ impl TryFromLLM<ChatCompletionRequestMessageExt> for Message {
 
                 let content = assistant_content_from_parts(content_parts);
 
-                Ok(Message::Assistant { content, id: None })
+                Ok(Message::Assistant {
+                    content,
+                    id: None,
+                    name: msg.name,
+                })
             }
             openai::ChatCompletionRequestMessageRole::Developer => {
                 let content =
@@ -4808,10 +4844,10 @@ impl TryFromLLM<Message> for ChatCompletionRequestMessageExt {
                 reasoning: None,
                 reasoning_signature: None,
             }),
-            Message::User { content } => Ok(ChatCompletionRequestMessageExt {
+            Message::User { content, name } => Ok(ChatCompletionRequestMessageExt {
                 role: openai::ChatCompletionRequestMessageRole::User,
                 content: Some(convert_user_content_to_chat_completion_content(content)?),
-                name: None,
+                name,
                 tool_calls: None,
                 tool_call_id: None,
                 audio: None,
@@ -4821,14 +4857,18 @@ impl TryFromLLM<Message> for ChatCompletionRequestMessageExt {
                 reasoning: None,
                 reasoning_signature: None,
             }),
-            Message::Assistant { content, id: _ } => {
+            Message::Assistant {
+                content,
+                id: _,
+                name,
+            } => {
                 let (text_content, tool_calls, reasoning, reasoning_signature) =
                     extract_content_tool_calls_and_reasoning(content)?;
 
                 Ok(ChatCompletionRequestMessageExt {
                     role: openai::ChatCompletionRequestMessageRole::Assistant,
                     content: text_content,
-                    name: None,
+                    name,
                     tool_calls,
                     tool_call_id: None,
                     audio: None,
@@ -5273,6 +5313,14 @@ impl TryFromLLM<ChatCompletionResponseMessageExt> for Message {
                     });
                 }
 
+                // Add refusal if present. The model declined to respond, so the
+                // `content` field is normally empty and there's nothing else to add.
+                if let Some(refusal) = &msg.base.refusal {
+                    content_parts.push(AssistantContentPart::Refusal {
+                        text: refusal.clone(),
+                    });
+                }
+
                 // Add text content if present
                 if let Some(text) = &msg.base.content {
                     if !text.is_empty() {
@@ -5305,7 +5353,11 @@ impl TryFromLLM<ChatCompletionResponseMessageExt> for Message {
 
                 let content = assistant_content_from_parts(content_parts);
 
-                Ok(Message::Assistant { content, id: None })
+                Ok(Message::Assistant {
+                    content,
+                    id: None,
+                    name: None,
+                })
             }
         }
     }
@@ -5317,80 +5369,97 @@ impl TryFromLLM<&Message> for ChatCompletionResponseMessageExt {
 
     fn try_from(msg: &Message) -> Result<Self, Self::Error> {
         match msg {
-            Message::Assistant { content, id: _ } => {
-                let (content_text, tool_calls, reasoning, reasoning_signature) = match content {
-                    AssistantContent::String(text) => (Some(text.clone()), None, None, None),
-                    AssistantContent::Array(parts) => {
-                        // Extract text from parts and concatenate
-                        let texts: Vec<String> = parts
-                            .iter()
-                            .filter_map(|part| match part {
-                                AssistantContentPart::Text(text_part) => {
-                                    Some(text_part.text.clone())
-                                }
-                                _ => None,
-                            })
-                            .collect();
+            Message::Assistant { content, id: _, .. } => {
+                let (content_text, tool_calls, reasoning, reasoning_signature, refusal) =
+                    match content {
+                        AssistantContent::String(text) => {
+                            (Some(text.clone()), None, None, None, None)
+                        }
+                        AssistantContent::Array(parts) => {
+                            // Extract text from parts and concatenate
+                            let texts: Vec<String> = parts
+                                .iter()
+                                .filter_map(|part| match part {
+                                    AssistantContentPart::Text(text_part) => {
+                                        Some(text_part.text.clone())
+                                    }
+                                    _ => None,
+                                })
+                                .collect();
+
+                            // A refusal is a distinct signal, not just more text - keep it
+                            // in its own field rather than folding it into `content`.
+                            let refusal: Option<String> =
+                                parts.iter().find_map(|part| match part {
+                                    AssistantContentPart::Refusal { text } => Some(text.clone()),
+                                    _ => None,
+                                });
 
-                        // Extract reasoning from parts and concatenate, also capture signature
-                        let mut reasonings: Vec<String> = Vec::new();
-                        let mut signature: Option<String> = None;
-                        for part in parts {
-                            if let AssistantContentPart::Reasoning {
-                                text,
-                                encrypted_content,
-                            } = part
-                            {
-                                reasonings.push(text.clone());
-                                merge_reasoning_signature(&mut signature, encrypted_content)?;
+                            // Extract reasoning from parts and concatenate, also capture signature
+                            let mut reasonings: Vec<String> = Vec::new();
+                            let mut signature: Option<String> = None;
+                            for part in parts {
+                                if let AssistantContentPart::Reasoning {
+                                    text,
+                                    encrypted_content,
+                                } = part
+                                {
+                                    reasonings.push(text.clone());
+                                    merge_reasoning_signature(&mut signature, encrypted_content)?;
+                                }
                             }
-                        }
 
-                        // Extract tool calls from parts
-                        let mut tool_calls: Vec<openai::ToolCall> = Vec::new();
-                        for part in parts {
-                            if let AssistantContentPart::ToolCall {
-                                tool_call_id,
-                                tool_name,
-                                arguments,
-                                encrypted_content,
-                                ..
-                            } = part
-                            {
-                                merge_reasoning_signature(&mut signature, encrypted_content)?;
-                                tool_calls.push(openai::ToolCall {
-                                    id: tool_call_id.clone(),
-                                    tool_call_type: openai::FluffyType::Function,
-                                    function: Some(openai::PurpleFunction {
-                                        name: tool_name.clone(),
-                                        arguments: arguments.to_string(),
-                                    }),
-                                    custom: None,
-                                });
+                            // Extract tool calls from parts
+                            let mut tool_calls: Vec<openai::ToolCall> = Vec::new();
+                            for part in parts {
+                                if let AssistantContentPart::ToolCall {
+                                    tool_call_id,
+                                    tool_name,
+                                    arguments,
+                                    encrypted_content,
+                                    ..
+                                } = part
+                                {
+                                    merge_reasoning_signature(&mut signature, encrypted_content)?;
+                                    tool_calls.push(openai::ToolCall {
+                                        id: tool_call_id.clone(),
+                                        tool_call_type: openai::FluffyType::Function,
+                                        function: Some(openai::PurpleFunction {
+                                            name: tool_name.clone(),
+                                            arguments: arguments.to_string(),
+                                        }),
+                                        custom: None,
+                                    });
+                                }
                             }
-                        }
 
-                        let content_text = if texts.is_empty() {
-                            None
-                        } else {
-                            Some(texts.join(""))
-                        };
+                            let content_text = if texts.is_empty() {
+                                None
+                            } else {
+                                Some(texts.join(""))
+                            };
 
-                        let reasoning = if reasonings.is_empty() {
-                            None
-                        } else {
-                            Some(reasonings.join(""))
-                        };
+                            let reasoning = if reasonings.is_empty() {
+                                None
+                            } else {
+                                Some(reasonings.join(""))
+                            };
 
-                        let tool_calls_option = if tool_calls.is_empty() {
-                            None
-                        } else {
-                            Some(tool_calls)
-                        };
+                            let tool_calls_option = if tool_calls.is_empty() {
+                                None
+                            } else {
+                                Some(tool_calls)
+                            };
 
-                        (content_text, tool_calls_option, reasoning, signature)
-                    }
-                };
+                            (
+                                content_text,
+                                tool_calls_option,
+                                reasoning,
+                                signature,
+                                refusal,
+                            )
+                        }
+                    };
 
                 Ok(ChatCompletionResponseMessageExt {
                     base: openai::ChatCompletionResponseMessage {
@@ -5399,7 +5468,7 @@ impl TryFromLLM<&Message> for ChatCompletionResponseMessageExt {
                         annotations: Some(vec![]), // Hardcode empty annotations for consistency
                         audio: None,
                         function_call: None,
-                        refusal: None,
+                        refusal,
                         tool_calls,
                     },
                     reasoning,
@@ -5939,6 +6008,7 @@ mod tests {
 
         let Message::User {
             content: UserContent::Array(parts),
+            ..
         } = message
         else {
             panic!("expected user message with array content");
@@ -6008,6 +6078,7 @@ mod tests {
                 }),
                 provider_options: None,
             })]),
+            name: None,
         };
 
         let converted = <ChatCompletionRequestMessageExt as TryFromLLM<Message>>::try_from(message)
@@ -6039,6 +6110,7 @@ mod tests {
                 provider_options: None,
             })]),
             id: None,
+            name: None,
         };
 
         let converted = <ChatCompletionRequestMessageExt as TryFromLLM<Message>>::try_from(message)
@@ -6076,6 +6148,7 @@ mod tests {
             let user_error =
                 <ChatCompletionRequestMessageExt as TryFromLLM<Message>>::try_from(Message::User {
                     content: UserContent::Array(vec![UserContentPart::Text(text_part())]),
+                    name: None,
                 })
                 .expect_err("Chat Completions should reject user cache TTLs it cannot preserve");
             assert!(matches!(
@@ -6092,6 +6165,7 @@ mod tests {
                             text_part(),
                         )]),
                         id: None,
+                        name: None,
                     },
                 )
                 .expect_err(
@@ -6131,6 +6205,7 @@ mod tests {
         match &message {
             Message::User {
                 content: UserContent::Array(parts),
+                ..
             } => match &parts[0] {
                 UserContentPart::Text(text) => assert!(text.cache_control.is_some()),
                 other => panic!("expected text part, got {other:?}"),
@@ -6214,7 +6289,7 @@ mod tests {
                 Message::Assistant {
                     content: AssistantContent::Array(parts),
                     id: None,
-                },
+                 name: None},
             )
             .expect("message should convert to anthropic");
 
@@ -6288,6 +6363,7 @@ mod tests {
                 provider_executed: None,
             }]),
             id: None,
+            name: None,
         };
 
         let converted =
@@ -6312,6 +6388,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn chat_completion_refusal_becomes_refusal_content_part() {
+        let msg = ChatCompletionResponseMessageExt {
+            base: openai::ChatCompletionResponseMessage {
+                role: openai::MessageRole::Assistant,
+                content: None,
+                annotations: None,
+                audio: None,
+                function_call: None,
+                refusal: Some("I can't help with that request.".to_string()),
+                tool_calls: None,
+            },
+            reasoning: None,
+            reasoning_signature: None,
+        };
+
+        let message = <Message as TryFromLLM<ChatCompletionResponseMessageExt>>::try_from(msg)
+            .expect("message should convert");
+
+        match message {
+            Message::Assistant { content, .. } => match content {
+                AssistantContent::Array(parts) => {
+                    assert_eq!(parts.len(), 1);
+                    match &parts[0] {
+                        AssistantContentPart::Refusal { text } => {
+                            assert_eq!(text, "I can't help with that request.");
+                        }
+                        other => panic!("expected refusal part, got {other:?}"),
+                    }
+                }
+                other => panic!("expected array content, got {other:?}"),
+            },
+            other => panic!("expected assistant message, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn refusal_content_part_round_trips_to_chat_completion_refusal_field() {
+        let message = Message::Assistant {
+            content: AssistantContent::Array(vec![AssistantContentPart::Refusal {
+                text: "I can't help with that request.".to_string(),
+            }]),
+            id: None,
+            name: None,
+        };
+
+        let converted =
+            <ChatCompletionResponseMessageExt as TryFromLLM<&Message>>::try_from(&message)
+                .expect("message should convert");
+
+        assert_eq!(
+            converted.base.refusal.as_deref(),
+            Some("I can't help with that request.")
+        );
+        assert_eq!(converted.base.content, None);
+    }
+
     #[test]
     fn chat_messages_project_tool_discovery_history() {
         let messages = vec![
@@ -6326,6 +6459,7 @@ mod tests {
                     provider_options: None,
                 }]),
                 id: None,
+                name: None,
             },
             Message::Tool {
                 content: vec![ToolContentPart::ToolDiscoveryResult(
@@ -6409,6 +6543,7 @@ mod tests {
                 },
             ]),
             id: None,
+            name: None,
         }];
 
         let converted = messages_to_chat_completion_messages(messages).unwrap();
@@ -6453,6 +6588,7 @@ mod tests {
                 },
             ]),
             id: None,
+            name: None,
         };
 
         let error = <ChatCompletionResponseMessageExt as TryFromLLM<&Message>>::try_from(&message)
@@ -6735,7 +6871,7 @@ mod tests {
 
         assert_eq!(messages.len(), 4);
 
-        let Message::Assistant { content, id } = &messages[0] else {
+        let Message::Assistant { content, id, .. } = &messages[0] else {
             panic!("program should become assistant message");
         };
         assert_eq!(id.as_deref(), Some("prog_123"));
@@ -6756,7 +6892,7 @@ mod tests {
         assert_eq!(code, "text(JSON.stringify({ ok: true }));");
         assert_eq!(fingerprint.as_deref(), Some("opaque_state"));
 
-        let Message::Assistant { content, id } = &messages[1] else {
+        let Message::Assistant { content, id, .. } = &messages[1] else {
             panic!("program_output should become assistant message");
         };
         assert_eq!(id.as_deref(), Some("prog_out_123"));
@@ -6777,7 +6913,7 @@ mod tests {
         assert_eq!(result, "{\"ok\":true}");
         assert_eq!(status, "completed");
 
-        let Message::Assistant { content, id } = &messages[2] else {
+        let Message::Assistant { content, id, .. } = &messages[2] else {
             panic!("function_call should become assistant message");
         };
         assert_eq!(id.as_deref(), Some("fc_123"));
@@ -6849,7 +6985,7 @@ mod tests {
             "first imported item should remain a program"
         );
 
-        let Message::User { content } = &messages[1] else {
+        let Message::User { content, .. } = &messages[1] else {
             panic!("normal message item should be preserved");
         };
         match content {
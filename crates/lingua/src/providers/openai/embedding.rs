@@ -0,0 +1,128 @@
+/*!
+Conversions between OpenAI's `POST /v1/embeddings` wire format and
+[`UniversalEmbeddingRequest`]/[`UniversalEmbeddingResponse`].
+
+`specs/openai/openapi.yml` does cover `/embeddings`, but
+`crates/generate-types` does not emit types for it yet, so the wire
+structs below are hand-typed rather than pulled from `generated.rs`.
+Once the generator covers this endpoint, replace these with typed
+adapters over the generated request/response types instead of adding
+more hand-typed structs here.
+*/
+
+use crate::error::ConvertError;
+use crate::universal::{
+    UniversalEmbeddingRequest, UniversalEmbeddingResponse, UniversalEmbeddingUsage,
+};
+use serde::{Deserialize, Serialize};
+
+/// OpenAI accepts either a single string or a batch of strings for `input`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum OpenAIEmbeddingInput {
+    Single(String),
+    Batch(Vec<String>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIEmbeddingRequest {
+    pub model: String,
+    pub input: OpenAIEmbeddingInput,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding_format: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIEmbeddingObject {
+    pub object: String,
+    pub embedding: Vec<f32>,
+    pub index: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIEmbeddingUsage {
+    pub prompt_tokens: i64,
+    pub total_tokens: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIEmbeddingResponse {
+    pub object: String,
+    pub data: Vec<OpenAIEmbeddingObject>,
+    pub model: String,
+    pub usage: OpenAIEmbeddingUsage,
+}
+
+pub fn openai_embedding_request_to_universal(
+    request: OpenAIEmbeddingRequest,
+) -> UniversalEmbeddingRequest {
+    let input = match request.input {
+        OpenAIEmbeddingInput::Single(text) => vec![text],
+        OpenAIEmbeddingInput::Batch(texts) => texts,
+    };
+    UniversalEmbeddingRequest {
+        model: request.model,
+        input,
+        dimensions: request.dimensions,
+    }
+}
+
+pub fn universal_to_openai_embedding_request(
+    request: &UniversalEmbeddingRequest,
+) -> OpenAIEmbeddingRequest {
+    OpenAIEmbeddingRequest {
+        model: request.model.clone(),
+        input: OpenAIEmbeddingInput::Batch(request.input.clone()),
+        dimensions: request.dimensions,
+        encoding_format: None,
+    }
+}
+
+pub fn openai_embedding_response_to_universal(
+    response: OpenAIEmbeddingResponse,
+) -> UniversalEmbeddingResponse {
+    UniversalEmbeddingResponse {
+        model: Some(response.model),
+        embeddings: response
+            .data
+            .into_iter()
+            .map(|object| object.embedding)
+            .collect(),
+        usage: Some(UniversalEmbeddingUsage {
+            prompt_tokens: Some(response.usage.prompt_tokens),
+            total_tokens: Some(response.usage.total_tokens),
+        }),
+    }
+}
+
+pub fn universal_to_openai_embedding_response(
+    response: &UniversalEmbeddingResponse,
+) -> Result<OpenAIEmbeddingResponse, ConvertError> {
+    let model = response
+        .model
+        .clone()
+        .ok_or(ConvertError::MissingRequiredField {
+            field: "model".to_string(),
+        })?;
+    let usage = response.usage.clone().unwrap_or_default();
+    Ok(OpenAIEmbeddingResponse {
+        object: "list".to_string(),
+        data: response
+            .embeddings
+            .iter()
+            .enumerate()
+            .map(|(index, embedding)| OpenAIEmbeddingObject {
+                object: "embedding".to_string(),
+                embedding: embedding.clone(),
+                index,
+            })
+            .collect(),
+        model,
+        usage: OpenAIEmbeddingUsage {
+            prompt_tokens: usage.prompt_tokens.unwrap_or(0),
+            total_tokens: usage.total_tokens.unwrap_or(0),
+        },
+    })
+}
@@ -1764,6 +1764,10 @@ pub struct ChatStreamResponseChoice {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
 #[ts(export_to = "openai/")]
 pub struct ChatCompletionStreamResponseDelta {
+    /// Incremental audio response, present when audio output is requested via
+    /// `modalities: ["audio"]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<DeltaAudio>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
     /// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should
@@ -1779,6 +1783,29 @@ pub struct ChatCompletionStreamResponseDelta {
     pub tool_calls: Option<Vec<ChatCompletionMessageToolCallChunk>>,
 }
 
+/// Incremental audio response, present when audio output is requested via
+/// `modalities: ["audio"]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export_to = "openai/")]
+pub struct DeltaAudio {
+    /// A chunk of base64 encoded audio bytes generated by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// The Unix timestamp (in seconds) for when this audio response will
+    /// no longer be accessible on the server for use in multi-turn
+    /// conversations. Only present once, on the chunk that opens the audio
+    /// response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+    /// Unique identifier for this audio response. Only present once, on the
+    /// chunk that opens the audio response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// A chunk of the transcript of the audio generated by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript: Option<String>,
+}
+
 /// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should
 /// be called, as generated by the model.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
@@ -0,0 +1,174 @@
+/*!
+Conversion from [`UniversalResponse`] to the legacy OpenAI `POST /v1/completions`
+(`text_completion`) response shape.
+
+There's no adapter for this on the way in: a legacy-prompt request is detected
+and normalized into a Chat Completions payload by
+[`crate::providers::openai::adapter::OpenAIAdapter`], so it flows through the
+rest of the pipeline like any other chat request. This module exists only for
+callers that captured "the original request used the legacy `prompt` field"
+and want to hand the caller back a `text_completion` response instead of a
+`chat.completion` one - `ProviderAdapter::response_from_universal` has no way
+to know that, since a single [`UniversalResponse`] is shared by both shapes.
+*/
+
+use crate::capabilities::ProviderFormat;
+use crate::processing::transform::TransformError;
+use crate::serde_json::{json, Value};
+use crate::universal::{
+    AssistantContent, AssistantContentPart, Message, UniversalResponse, PLACEHOLDER_ID,
+    PLACEHOLDER_MODEL,
+};
+
+fn assistant_text(message: &Message) -> Result<String, TransformError> {
+    let Message::Assistant { content, .. } = message else {
+        return Err(TransformError::FromUniversalFailed(
+            "legacy completions response requires an assistant message".to_string(),
+        ));
+    };
+
+    match content {
+        AssistantContent::String(text) => Ok(text.clone()),
+        AssistantContent::Array(parts) => Ok(parts
+            .iter()
+            .filter_map(|part| match part {
+                AssistantContentPart::Text(text_part) => Some(text_part.text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("")),
+    }
+}
+
+/// Render a [`UniversalResponse`] as a legacy `text_completion` response
+/// (`{"object": "text_completion", "choices": [{"text": ...}], ...}`)
+/// instead of the `chat.completion` shape `OpenAIAdapter` produces.
+///
+/// Intended for a gateway that remembers the inbound request used the legacy
+/// `prompt` field and wants to hand its response back in the matching shape.
+pub fn legacy_completion_response_from_universal(
+    resp: &UniversalResponse,
+) -> Result<Value, TransformError> {
+    let finish_reason = resp
+        .finish_reason
+        .as_ref()
+        .map(|r| {
+            r.to_provider_string(ProviderFormat::ChatCompletions)
+                .to_string()
+        })
+        .unwrap_or_else(|| "stop".to_string());
+
+    let choices = resp
+        .messages
+        .iter()
+        .enumerate()
+        .map(|(index, message)| {
+            Ok(json!({
+                "text": assistant_text(message)?,
+                "index": index,
+                "logprobs": Value::Null,
+                "finish_reason": finish_reason,
+            }))
+        })
+        .collect::<Result<Vec<Value>, TransformError>>()?;
+
+    let unique_id = resp
+        .id_for(ProviderFormat::ChatCompletions)
+        .strip_prefix("chatcmpl-")
+        .unwrap_or(PLACEHOLDER_ID)
+        .to_string();
+
+    let mut map = crate::serde_json::Map::new();
+    map.insert("id".into(), Value::String(format!("cmpl-{unique_id}")));
+    map.insert("object".into(), Value::String("text_completion".into()));
+    map.insert("created".into(), json!(0));
+    map.insert(
+        "model".into(),
+        Value::String(
+            resp.model
+                .clone()
+                .unwrap_or_else(|| PLACEHOLDER_MODEL.to_string()),
+        ),
+    );
+    map.insert("choices".into(), Value::Array(choices));
+
+    if let Some(usage) = resp.usage.as_ref() {
+        map.insert(
+            "usage".into(),
+            usage.to_provider_value(ProviderFormat::ChatCompletions),
+        );
+    }
+
+    Ok(Value::Object(map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::universal::{FinishReason, UniversalUsage};
+
+    fn response(text: &str) -> UniversalResponse {
+        UniversalResponse {
+            id: None,
+            id_format: None,
+            model: Some("gpt-3.5-turbo-instruct".to_string()),
+            messages: vec![Message::Assistant {
+                content: AssistantContent::String(text.to_string()),
+                id: None,
+                name: None,
+            }],
+            usage: Some(UniversalUsage {
+                prompt_tokens: Some(5),
+                completion_tokens: Some(3),
+                ..Default::default()
+            }),
+            finish_reason: Some(FinishReason::Stop),
+            finish_reasons: vec![FinishReason::Stop],
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
+        }
+    }
+
+    #[test]
+    fn renders_text_completion_shape() {
+        let output = legacy_completion_response_from_universal(&response("Hello there."))
+            .expect("should render");
+
+        assert_eq!(
+            output.get("object").and_then(Value::as_str),
+            Some("text_completion")
+        );
+        assert!(output
+            .get("id")
+            .and_then(Value::as_str)
+            .is_some_and(|id| id.starts_with("cmpl-")));
+
+        let choice = &output["choices"][0];
+        assert_eq!(
+            choice.get("text").and_then(Value::as_str),
+            Some("Hello there.")
+        );
+        assert_eq!(choice.get("index").and_then(Value::as_i64), Some(0));
+        assert_eq!(
+            choice.get("finish_reason").and_then(Value::as_str),
+            Some("stop")
+        );
+
+        assert_eq!(
+            output.get("usage").and_then(|u| u.get("prompt_tokens")),
+            Some(&json!(5))
+        );
+    }
+
+    #[test]
+    fn rejects_non_assistant_messages() {
+        let mut resp = response("unused");
+        resp.messages = vec![Message::User {
+            content: crate::universal::UserContent::String("hi".to_string()),
+            name: None,
+        }];
+
+        assert!(legacy_completion_response_from_universal(&resp).is_err());
+    }
+}
@@ -10,7 +10,9 @@ pub mod adapter;
 pub mod capabilities;
 pub mod convert;
 pub mod detect;
+pub mod embedding;
 pub mod generated;
+pub mod legacy_completions;
 pub mod params;
 pub mod responses_adapter;
 pub(crate) mod tool_discovery;
@@ -20,6 +22,16 @@ pub(crate) mod tool_parsing;
 pub use adapter::OpenAIAdapter;
 pub use responses_adapter::ResponsesAdapter;
 
+// Re-export embedding conversion functions and types
+pub use embedding::{
+    openai_embedding_request_to_universal, openai_embedding_response_to_universal,
+    universal_to_openai_embedding_request, universal_to_openai_embedding_response,
+    OpenAIEmbeddingRequest, OpenAIEmbeddingResponse,
+};
+
+// Re-export the legacy `/v1/completions` response shim
+pub use legacy_completions::legacy_completion_response_from_universal;
+
 #[cfg(test)]
 pub mod test_responses;
 
@@ -192,6 +192,11 @@ pub enum OpenAICompletionPrompt {
 pub struct OpenAIResponsesExtrasView {
     pub instructions: Option<String>,
     pub input: Option<Value>,
+    /// Whether the original request sent `input` as a bare string rather than
+    /// an item array. Used to re-derive the string form on the way back when
+    /// the conversation still round-trips as a single user text turn.
+    #[serde(default)]
+    pub input_is_string: bool,
     pub include: Option<Value>,
     pub temperature: Option<Value>,
     pub top_p: Option<Value>,
@@ -66,6 +66,26 @@ fn system_text(message: &Message) -> Option<&str> {
     }
 }
 
+/// Returns the plain text of `messages` if it is exactly one plain-text user
+/// turn with no participant `name`, i.e. the case OpenAI's Responses API lets
+/// you send as a bare `input` string instead of an item array.
+fn single_user_text_turn(messages: &[Message]) -> Option<&str> {
+    let [Message::User {
+        content,
+        name: None,
+    }] = messages
+    else {
+        return None;
+    };
+    match content {
+        UserContent::String(text) => Some(text.as_str()),
+        UserContent::Array(parts) => match &parts[..] {
+            [UserContentPart::Text(TextContentPart { text, .. })] => Some(text.as_str()),
+            _ => None,
+        },
+    }
+}
+
 /// Adapter for OpenAI Responses API (used by reasoning models like o1).
 pub struct ResponsesAdapter;
 
@@ -472,10 +492,12 @@ impl ProviderAdapter for ResponsesAdapter {
             .map_err(|e| TransformError::ToUniversalFailed(e.to_string()))?;
 
         // Extract input items from typed_params.input (partial move - other fields remain accessible)
+        let mut input_was_string = false;
         let input_items: Vec<InputItem> = match typed_params.input {
             Some(Instructions::InputItemArray(items)) => items,
             Some(Instructions::String(s)) => {
                 // Single string input - create a user message InputItem
+                input_was_string = true;
                 vec![InputItem {
                     input_item_type: Some(InputItemType::Message),
                     role: Some(InputItemRole::User),
@@ -563,7 +585,8 @@ impl ProviderAdapter for ResponsesAdapter {
                 .as_ref()
                 .and_then(|v| (ProviderFormat::Responses, v).try_into().ok()),
             response_format,
-            seed: None,             // Responses API uses different randomness control
+            modalities: None, // Responses API doesn't expose output modality selection
+            seed: None,       // Responses API uses different randomness control
             presence_penalty: None, // Responses API doesn't support penalties
             frequency_penalty: None,
             stream: typed_params.stream,
@@ -623,6 +646,9 @@ impl ProviderAdapter for ResponsesAdapter {
         if let Some(v) = typed_params.max_output_tokens {
             extras_map.insert("max_output_tokens".into(), Value::Number(v.into()));
         }
+        if input_was_string {
+            extras_map.insert("input_is_string".into(), Value::Bool(true));
+        }
         if let Some(moderation) = typed_params.moderation {
             extras_map.insert("moderation".into(), moderation);
         }
@@ -659,13 +685,26 @@ impl ProviderAdapter for ResponsesAdapter {
         let chat_extras_view =
             parse_openai_chat_extras(req.params.extras.get(&ProviderFormat::ChatCompletions))?;
         let mut messages_for_input = req.messages.clone();
-        if let Some(instructions) = responses_extras_view.instructions.as_deref() {
+        // The Responses API's top-level `instructions` string is the
+        // canonical System representation for this format. Prefer the raw
+        // value preserved in extras (same-format round trip); otherwise fall
+        // back to deriving it from a leading System message, which is what a
+        // System message converted from another format (e.g. Chat's `system`
+        // role, Anthropic's top-level `system`) looks like once canonicalized.
+        let instructions = if let Some(raw) = responses_extras_view.instructions.as_deref() {
             if let Some(first_text) = messages_for_input.first().and_then(system_text) {
-                if first_text == instructions {
+                if first_text == raw {
                     messages_for_input.remove(0);
                 }
             }
-        }
+            Some(raw.to_string())
+        } else if let Some(first_text) = messages_for_input.first().and_then(system_text) {
+            let instructions = first_text.to_string();
+            messages_for_input.remove(0);
+            Some(instructions)
+        } else {
+            None
+        };
 
         let mut input_items = req
             .params
@@ -695,8 +734,18 @@ impl ProviderAdapter for ResponsesAdapter {
 
         let mut obj = Map::new();
         obj.insert("model".into(), Value::String(model.clone()));
+        if let Some(instructions) = instructions {
+            obj.insert("instructions".into(), Value::String(instructions));
+        }
+        let single_user_text = (responses_extras_view.input_is_string
+            && req.params.conversation_reference.is_none())
+        .then(|| single_user_text_turn(&messages_for_input))
+        .flatten();
+
         if let Some(raw_input) = responses_extras_view.input.as_ref() {
             obj.insert("input".into(), raw_input.clone());
+        } else if let Some(text) = single_user_text {
+            obj.insert("input".into(), Value::String(text.to_string()));
         } else {
             obj.insert(
                 "input".into(),
@@ -1031,6 +1080,9 @@ impl ProviderAdapter for ResponsesAdapter {
             usage,
             finish_reason: finish_reason.clone(),
             finish_reasons: finish_reason.into_iter().collect(),
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         })
     }
 
@@ -1589,6 +1641,7 @@ mod tests {
             model: Some("gpt-5-nano".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 prompt_cache_key: Some("cache-key-1".to_string()),
@@ -1699,6 +1752,7 @@ mod tests {
             messages: vec![
                 Message::User {
                     content: UserContent::String("Check inventory.".to_string()),
+                    name: None,
                 },
                 Message::Assistant {
                     content: AssistantContent::Array(vec![
@@ -1721,6 +1775,7 @@ mod tests {
                         },
                     ]),
                     id: None,
+                    name: None,
                 },
                 Message::Tool {
                     content: vec![ToolContentPart::ToolResult(ToolResultContentPart {
@@ -1740,6 +1795,7 @@ mod tests {
                         id: Some("prog_out_123".to_string()),
                     }]),
                     id: None,
+                    name: None,
                 },
             ],
             params: UniversalParams::default(),
@@ -1958,6 +2014,9 @@ mod tests {
             usage: None,
             finish_reason: None,
             finish_reasons: Vec::new(),
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
         let exported = adapter.response_from_universal(&tool_result_resp).unwrap();
         let response: TheResponseObject = serde_json::from_value(exported)
@@ -2194,6 +2253,7 @@ mod tests {
                     provider_executed: None,
                 }]),
                 id: None,
+                name: None,
             }],
             params: UniversalParams::default(),
         };
@@ -2371,14 +2431,14 @@ mod tests {
             Message::Assistant {
                 id: Some(id),
                 content: AssistantContent::Array(parts),
-            } if id == "rs_123" && matches!(parts.as_slice(), [AssistantContentPart::Reasoning { .. }])
+             name: None} if id == "rs_123" && matches!(parts.as_slice(), [AssistantContentPart::Reasoning { .. }])
         ));
         assert!(matches!(
             &universal.messages[1],
             Message::Assistant {
                 id: Some(id),
                 content: AssistantContent::Array(parts),
-            } if id == "msg_123" && matches!(parts.as_slice(), [AssistantContentPart::Text(_)])
+             name: None} if id == "msg_123" && matches!(parts.as_slice(), [AssistantContentPart::Text(_)])
         ));
 
         #[derive(serde::Deserialize)]
@@ -2446,6 +2506,53 @@ mod tests {
         assert_eq!(round_tripped["tools"], json!([namespace, tool_search]));
     }
 
+    #[test]
+    fn test_responses_preserves_file_search_and_computer_use_tools() {
+        use crate::universal::tools::BuiltinToolProvider;
+
+        let adapter = ResponsesAdapter;
+        let file_search = json!({
+            "type": "file_search",
+            "vector_store_ids": ["vs_123"],
+            "max_num_results": 5
+        });
+        let computer_use = json!({
+            "type": "computer_use_preview",
+            "display_width": 1024,
+            "display_height": 768,
+            "environment": "browser"
+        });
+        let payload = json!({
+            "model": "gpt-5.4-2026-03-05",
+            "input": [{"role": "user", "content": "Find the invoice and click submit."}],
+            "tools": [file_search.clone(), computer_use.clone()]
+        });
+
+        let universal = adapter
+            .request_to_universal(payload)
+            .expect("file_search and computer_use_preview tools should parse");
+
+        let tools = universal
+            .params
+            .tools
+            .as_ref()
+            .expect("tools should be present");
+        assert_eq!(tools.len(), 2);
+        for tool in tools {
+            assert!(tool.is_builtin());
+            assert_eq!(
+                tool.builtin_provider(),
+                Some(BuiltinToolProvider::Responses)
+            );
+        }
+
+        let round_tripped = adapter
+            .request_from_universal(&universal)
+            .expect("file_search and computer_use_preview tools should serialize");
+
+        assert_eq!(round_tripped["tools"], json!([file_search, computer_use]));
+    }
+
     #[test]
     fn test_responses_item_reference_imports_to_conversation_reference() {
         let adapter = ResponsesAdapter;
@@ -2485,6 +2592,7 @@ mod tests {
             model: Some("gpt-4.1".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Continue.".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 conversation_reference: Some(vec![ConversationReference {
@@ -2721,10 +2829,14 @@ mod tests {
             messages: vec![Message::Assistant {
                 content: AssistantContent::String("Paris.".to_string()),
                 id: None,
+                name: None,
             }],
             usage: None,
             finish_reason: Some(FinishReason::Stop),
             finish_reasons: vec![FinishReason::Stop],
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
 
         let adapter = ResponsesAdapter;
@@ -2774,6 +2886,7 @@ mod tests {
             model: Some("gpt-5-nano".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("AI news".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 tools: Some(vec![UniversalTool::builtin(
@@ -2835,6 +2948,7 @@ mod tests {
             model: Some("gpt-5-nano".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("AI news".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 tools: Some(vec![UniversalTool::builtin(
@@ -2930,6 +3044,7 @@ mod tests {
             model: Some("gpt-5-mini".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 top_p: Some(0.9),
@@ -2952,6 +3067,7 @@ mod tests {
             model: Some("gpt-4.1".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 top_p: Some(0.9),
@@ -2972,6 +3088,7 @@ mod tests {
             model: Some("gpt-5.1".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 reasoning: Some(crate::universal::ReasoningConfig {
@@ -3000,6 +3117,7 @@ mod tests {
             model: Some("gpt-5.4".to_string()),
             messages: vec![Message::User {
                 content: UserContent::String("Hello".to_string()),
+                name: None,
             }],
             params: UniversalParams {
                 reasoning: Some(crate::universal::ReasoningConfig {
@@ -3099,6 +3217,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_responses_instructions_maps_to_system_message() {
+        let payload = json!({
+            "model": "gpt-5.6",
+            "input": [{"role": "user", "content": "Hello"}],
+            "instructions": "Be concise."
+        });
+        let adapter = ResponsesAdapter;
+
+        let universal = adapter.request_to_universal(payload).unwrap();
+
+        assert_eq!(universal.messages.len(), 2);
+        assert_eq!(system_text(&universal.messages[0]), Some("Be concise."));
+    }
+
+    #[test]
+    fn test_responses_roundtrip_preserves_instructions() {
+        let payload = json!({
+            "model": "gpt-5.6",
+            "input": [{"role": "user", "content": "Hello"}],
+            "instructions": "Be concise."
+        });
+        let adapter = ResponsesAdapter;
+
+        let universal = adapter.request_to_universal(payload).unwrap();
+        let roundtrip = adapter.request_from_universal(&universal).unwrap();
+
+        assert_eq!(roundtrip["instructions"], json!("Be concise."));
+        // The user message is still the only entry in `input`.
+        assert_eq!(roundtrip["input"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_responses_to_chat_to_responses_preserves_instructions() {
+        let responses_adapter = ResponsesAdapter;
+        let chat_adapter = crate::providers::openai::adapter::OpenAIAdapter;
+        let payload = json!({
+            "model": "gpt-5.6",
+            "input": [{"role": "user", "content": "Hello"}],
+            "instructions": "Be concise."
+        });
+
+        let universal = responses_adapter.request_to_universal(payload).unwrap();
+        let chat = chat_adapter.request_from_universal(&universal).unwrap();
+
+        assert_eq!(chat["messages"][0]["role"], json!("system"));
+        assert_eq!(chat["messages"][0]["content"], json!("Be concise."));
+
+        let universal_from_chat = chat_adapter.request_to_universal(chat).unwrap();
+        let roundtrip = responses_adapter
+            .request_from_universal(&universal_from_chat)
+            .unwrap();
+
+        assert_eq!(roundtrip["instructions"], json!("Be concise."));
+        let input = roundtrip["input"].as_array().unwrap();
+        assert_eq!(input.len(), 1);
+        assert_eq!(input[0]["role"], json!("user"));
+    }
+
+    #[test]
+    fn test_responses_string_input_parses_as_single_user_message() {
+        let payload = json!({
+            "model": "gpt-5.6",
+            "input": "Hello"
+        });
+        let adapter = ResponsesAdapter;
+
+        let universal = adapter.request_to_universal(payload).unwrap();
+
+        assert_eq!(universal.messages.len(), 1);
+        match &universal.messages[0] {
+            Message::User { content, name } => {
+                match content {
+                    UserContent::String(text) => assert_eq!(text, "Hello"),
+                    other => panic!("expected string content, got {other:?}"),
+                }
+                assert_eq!(name, &None);
+            }
+            other => panic!("expected a user message, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_responses_roundtrip_preserves_string_input_for_single_user_turn() {
+        let payload = json!({
+            "model": "gpt-5.6",
+            "input": "Hello"
+        });
+        let adapter = ResponsesAdapter;
+
+        let universal = adapter.request_to_universal(payload).unwrap();
+        let roundtrip = adapter.request_from_universal(&universal).unwrap();
+
+        assert_eq!(roundtrip.get("input"), Some(&json!("Hello")));
+    }
+
+    #[test]
+    fn test_responses_roundtrip_falls_back_to_array_when_string_input_conversation_grows() {
+        let payload = json!({
+            "model": "gpt-5.6",
+            "input": "Hello"
+        });
+        let adapter = ResponsesAdapter;
+
+        let mut universal = adapter.request_to_universal(payload).unwrap();
+        universal.messages.push(Message::Assistant {
+            content: AssistantContent::String("Hi there".to_string()),
+            id: None,
+            name: None,
+        });
+        let roundtrip = adapter.request_from_universal(&universal).unwrap();
+
+        assert!(roundtrip["input"].is_array());
+    }
+
     #[test]
     fn test_responses_roundtrip_preserves_prompt_cache_retention_before_gpt_5_6() {
         let payload = json!({
@@ -3232,6 +3465,7 @@ mod tests {
                 content: UserContent::String(
                     "Write a very long essay about the ocean.".to_string(),
                 ),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(1)),
@@ -3255,6 +3489,7 @@ mod tests {
                 content: UserContent::String(
                     "Write a very long essay about the ocean.".to_string(),
                 ),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(100)),
@@ -3285,6 +3520,7 @@ mod tests {
                 content: UserContent::String(
                     "Write a very long essay about the ocean.".to_string(),
                 ),
+                name: None,
             }],
             params: UniversalParams {
                 token_budget: Some(TokenBudget::OutputTokens(100)),
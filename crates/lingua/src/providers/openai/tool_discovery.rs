@@ -389,6 +389,7 @@ pub(super) fn message_from_input_call(input: openai::InputItem) -> Result<Messag
     Ok(Message::Assistant {
         content: AssistantContent::Array(vec![tool_call]),
         id,
+        name: None,
     })
 }
 
@@ -0,0 +1,48 @@
+/*!
+Universal embedding types for cross-provider transformation.
+
+Lingua's other universal types (see [`crate::universal::request`] and
+[`crate::universal::response`]) model chat/messages APIs. Embeddings are a
+much narrower surface — a list of strings in, a list of vectors out — so
+these types stay flat rather than following the params/extras pattern used
+for chat requests.
+*/
+
+use serde::{Deserialize, Serialize};
+
+/// Canonical embedding request, analogous to OpenAI's `POST /v1/embeddings` body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UniversalEmbeddingRequest {
+    /// Model identifier.
+    pub model: String,
+
+    /// Text(s) to embed. A single-input request is represented as a
+    /// one-element vector so callers don't need to branch on arity.
+    pub input: Vec<String>,
+
+    /// Requested output vector size, for models that support truncation
+    /// (e.g. OpenAI's `text-embedding-3-*` family, Gemini's
+    /// `outputDimensionality`).
+    pub dimensions: Option<u32>,
+}
+
+/// Canonical embedding response, analogous to OpenAI's `POST /v1/embeddings` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UniversalEmbeddingResponse {
+    /// Model that generated the embeddings, when the provider reports one.
+    pub model: Option<String>,
+
+    /// Embedding vectors, in the same order as the request's `input`.
+    pub embeddings: Vec<Vec<f32>>,
+
+    /// Token usage, when the provider reports it.
+    pub usage: Option<UniversalEmbeddingUsage>,
+}
+
+/// Token usage for an embedding request. Embeddings have no completion
+/// tokens, so this is narrower than [`crate::universal::UniversalUsage`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UniversalEmbeddingUsage {
+    pub prompt_tokens: Option<i64>,
+    pub total_tokens: Option<i64>,
+}
@@ -0,0 +1,29 @@
+/*!
+Universal error representation for cross-provider error translation.
+
+Upstream error bodies are shaped differently per provider (Anthropic wraps
+the error in `{"type": "error", "error": {...}}`, OpenAI wraps it in
+`{"error": {...}}`), so a proxy that changes a request's target format also
+needs to translate the error body it gets back. `UniversalError` is the
+canonical envelope; conversion lives on `ProviderAdapter::error_to_universal`
+and `ProviderAdapter::error_from_universal`.
+*/
+
+use serde::{Deserialize, Serialize};
+
+/// Canonical error envelope, modeled after OpenAI's `error` object.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UniversalError {
+    /// Human-readable error description.
+    pub message: String,
+
+    /// Provider-specific error category (e.g. `"invalid_request_error"`).
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+
+    /// Machine-readable error code, if the provider supplies one.
+    pub code: Option<String>,
+
+    /// The request parameter the error relates to, if any.
+    pub param: Option<String>,
+}
@@ -18,11 +18,21 @@ pub enum Message {
     },
     User {
         content: UserContent,
+        /// Optional participant name (OpenAI's per-message `name`), used by
+        /// multi-agent frameworks to disambiguate participants sharing a role.
+        #[serde(default)]
+        #[ts(optional)]
+        name: Option<String>,
     },
     Assistant {
         content: AssistantContent,
         #[ts(optional)]
         id: Option<String>,
+        /// Optional participant name (OpenAI's per-message `name`), used by
+        /// multi-agent frameworks to disambiguate participants sharing a role.
+        #[serde(default)]
+        #[ts(optional)]
+        name: Option<String>,
     },
     Tool {
         content: ToolContent,
@@ -85,6 +95,12 @@ pub enum AssistantContent {
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum AssistantContentPart {
     Text(TextContentPart),
+    /// The model declined to respond (e.g. OpenAI's `refusal` field). Kept
+    /// distinct from `Text` so a refusal round-trips as a refusal instead of
+    /// being mistaken for the model's actual answer.
+    Refusal {
+        text: String,
+    },
     File {
         #[ts(type = "string | Uint8Array | ArrayBuffer | Buffer | URL")]
         data: serde_json::Value,
@@ -189,6 +205,12 @@ pub enum ToolCallArguments {
 
 impl From<String> for ToolCallArguments {
     fn from(s: String) -> Self {
+        if s.trim().is_empty() {
+            // Some providers emit an empty string for a no-argument tool call
+            // where others would send "{}"; treat them the same rather than
+            // flagging the empty string as invalid JSON.
+            return ToolCallArguments::Valid(serde_json::Map::new());
+        }
         match serde_json::from_str(&s) {
             Ok(serde_json::Value::Object(map)) => ToolCallArguments::Valid(map),
             _ => ToolCallArguments::Invalid(s),
@@ -394,3 +416,37 @@ pub struct ToolErrorContentPart {
     pub error: String,
     pub provider_metadata: Option<ProviderMetadata>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_string_arguments_normalize_to_valid_empty_object() {
+        assert!(matches!(
+            ToolCallArguments::from(String::new()),
+            ToolCallArguments::Valid(map) if map.is_empty()
+        ));
+    }
+
+    #[test]
+    fn whitespace_only_arguments_normalize_to_valid_empty_object() {
+        assert!(matches!(
+            ToolCallArguments::from("   \n".to_string()),
+            ToolCallArguments::Valid(map) if map.is_empty()
+        ));
+    }
+
+    #[test]
+    fn non_empty_invalid_json_arguments_stay_invalid() {
+        assert!(matches!(
+            ToolCallArguments::from("not-json".to_string()),
+            ToolCallArguments::Invalid(s) if s == "not-json"
+        ));
+    }
+
+    #[test]
+    fn empty_object_arguments_display_as_empty_object_string() {
+        assert_eq!(ToolCallArguments::from(String::new()).to_string(), "{}");
+    }
+}
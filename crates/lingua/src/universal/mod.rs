@@ -11,6 +11,8 @@ This module provides a 1:1 Rust implementation of the AI SDK ModelMessage format
 
 pub mod convert;
 pub mod defaults;
+pub mod embedding;
+pub mod error;
 pub mod message;
 pub mod reasoning;
 pub mod request;
@@ -23,18 +25,23 @@ pub mod transform;
 
 // Re-export main types for convenience
 pub use defaults::*;
+pub use embedding::{
+    UniversalEmbeddingRequest, UniversalEmbeddingResponse, UniversalEmbeddingUsage,
+};
+pub use error::UniversalError;
 pub use message::*;
 pub use request::{
     parse_stop_sequences, ConversationReference, ConversationReferenceType, JsonSchemaConfig,
     ReasoningCanonical, ReasoningConfig, ReasoningEffort, ResponseFormatConfig, ResponseFormatType,
-    SummaryMode, TokenBudget, ToolChoiceConfig, ToolChoiceMode, UniversalParams, UniversalRequest,
+    ResponseModality, SummaryMode, TokenBudget, ToolChoiceConfig, ToolChoiceMode, UniversalParams,
+    UniversalRequest, UniversalRequestBuilder,
 };
 pub use response::{
     FinishReason, ParsableResponseInfo, ResponseRequirement, UniversalResponse, UniversalUsage,
 };
 pub use stream::{
-    UniversalReasoningDelta, UniversalStreamChoice, UniversalStreamChunk, UniversalStreamDelta,
-    UniversalToolCallDelta, UniversalToolFunctionDelta,
+    UniversalAudioDelta, UniversalReasoningDelta, UniversalStreamChoice, UniversalStreamChunk,
+    UniversalStreamDelta, UniversalToolCallDelta, UniversalToolFunctionDelta,
 };
 pub use tools::{
     tools_to_openai_chat_value, tools_to_responses_value, BuiltinToolProvider, UniversalTool,
@@ -61,6 +61,9 @@ use crate::universal::request::{ReasoningCanonical, ReasoningConfig, ReasoningEf
 // Heuristic Constants
 // =============================================================================
 
+/// Multiplier for "minimal" effort (10% of max_tokens)
+pub const EFFORT_MINIMAL_MULTIPLIER: f64 = 0.10;
+
 /// Multiplier for "low" effort (25% of max_tokens)
 pub const EFFORT_LOW_MULTIPLIER: f64 = 0.25;
 
@@ -95,6 +98,7 @@ pub const DEFAULT_REASONING_EFFORT: ReasoningEffort = ReasoningEffort::Medium;
 /// Convert effort level to token budget.
 ///
 /// Uses multipliers applied to max_tokens:
+/// - minimal: 10% of max_tokens
 /// - low: 25% of max_tokens
 /// - medium: 50% of max_tokens
 /// - high: 75% of max_tokens
@@ -115,7 +119,7 @@ pub fn effort_to_budget(effort: ReasoningEffort, max_tokens: Option<i64>) -> i64
 
     let multiplier = match effort {
         ReasoningEffort::None => return 0,
-        ReasoningEffort::Minimal => EFFORT_LOW_MULTIPLIER,
+        ReasoningEffort::Minimal => EFFORT_MINIMAL_MULTIPLIER,
         ReasoningEffort::Low => EFFORT_LOW_MULTIPLIER,
         ReasoningEffort::Medium => EFFORT_MEDIUM_MULTIPLIER,
         ReasoningEffort::High => EFFORT_HIGH_MULTIPLIER,
@@ -685,6 +689,44 @@ mod tests {
         assert_eq!(none.budget_tokens, Some(0));
     }
 
+    #[test]
+    fn test_minimal_effort_encodes_per_provider() {
+        let minimal = ReasoningConfig::from((OpenAIReasoningEffortParam::Minimal, Some(4096)));
+
+        // OpenAI Chat: passes the effort string through directly.
+        assert_eq!(
+            minimal
+                .to_provider(ProviderFormat::ChatCompletions, Some(4096))
+                .unwrap(),
+            Some(Value::String("minimal".to_string()))
+        );
+
+        // Anthropic: still enabled, with a budget no larger than "low" (at
+        // max_tokens 4096 both clamp to the 1024-token minimum).
+        let anthropic_budget = minimal
+            .to_provider(ProviderFormat::Anthropic, Some(4096))
+            .unwrap()
+            .unwrap();
+        assert_eq!(anthropic_budget["type"], "enabled");
+        let low = ReasoningConfig::from((OpenAIReasoningEffortParam::Low, Some(4096)));
+        let low_budget = low
+            .to_provider(ProviderFormat::Anthropic, Some(4096))
+            .unwrap()
+            .unwrap();
+        assert!(
+            anthropic_budget["budget_tokens"].as_i64().unwrap()
+                <= low_budget["budget_tokens"].as_i64().unwrap()
+        );
+
+        // Google: enabled with a very-low thinking budget.
+        let google_budget = minimal
+            .to_provider(ProviderFormat::Google, Some(4096))
+            .unwrap()
+            .unwrap();
+        assert_eq!(google_budget["includeThoughts"], true);
+        assert!(google_budget["thinkingBudget"].as_i64().unwrap() > 0);
+    }
+
     #[test]
     fn test_from_openai_responses_reasoning() {
         let reasoning = OpenAIReasoning {
@@ -40,7 +40,7 @@ use crate::capabilities::ProviderFormat;
 use crate::error::ConvertError;
 use crate::processing::transform::TransformError;
 use crate::serde_json::{Map, Value};
-use crate::universal::message::Message;
+use crate::universal::message::{AssistantContent, Message, UserContent};
 use crate::universal::tools::UniversalTool;
 
 /// Universal request envelope for LLM API calls.
@@ -66,6 +66,137 @@ impl UniversalRequest {
             .as_ref()
             .is_some_and(ResponseFormatConfig::requires_json_response)
     }
+
+    /// Start building a request fluently. See [`UniversalRequestBuilder`].
+    pub fn builder(model: impl Into<String>) -> UniversalRequestBuilder {
+        UniversalRequestBuilder::new(model)
+    }
+}
+
+// =============================================================================
+// UniversalRequest Builder
+// =============================================================================
+
+/// Fluent builder for [`UniversalRequest`].
+///
+/// Building a request by hand means constructing `Message` enum variants and filling
+/// in `UniversalParams`'s many `Option` fields directly. This builder covers the common
+/// cases - plain-text messages, tools, and the most frequently set sampling/output
+/// parameters - while [`UniversalRequestBuilder::message`] and
+/// [`UniversalRequestBuilder::params`] remain available for anything not exposed as a
+/// dedicated method.
+///
+/// # Example
+///
+/// ```
+/// use lingua::universal::UniversalRequestBuilder;
+///
+/// let request = UniversalRequestBuilder::new("gpt-4")
+///     .system("You are a helpful assistant.")
+///     .user_text("What's the weather in SF?")
+///     .temperature(0.7)
+///     .max_tokens(1024)
+///     .stream(true)
+///     .build();
+///
+/// assert_eq!(request.model.as_deref(), Some("gpt-4"));
+/// assert_eq!(request.messages.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UniversalRequestBuilder {
+    model: Option<String>,
+    messages: Vec<Message>,
+    params: UniversalParams,
+}
+
+impl UniversalRequestBuilder {
+    /// Start building a request for the given model.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: Some(model.into()),
+            messages: Vec::new(),
+            params: UniversalParams::default(),
+        }
+    }
+
+    /// Append a system message.
+    pub fn system(mut self, content: impl Into<String>) -> Self {
+        self.messages.push(Message::System {
+            content: UserContent::String(content.into()),
+        });
+        self
+    }
+
+    /// Append a user message with plain-text content.
+    pub fn user_text(mut self, content: impl Into<String>) -> Self {
+        self.messages.push(Message::User {
+            content: UserContent::String(content.into()),
+            name: None,
+        });
+        self
+    }
+
+    /// Append an assistant message with plain-text content.
+    pub fn assistant_text(mut self, content: impl Into<String>) -> Self {
+        self.messages.push(Message::Assistant {
+            content: AssistantContent::String(content.into()),
+            id: None,
+            name: None,
+        });
+        self
+    }
+
+    /// Append a pre-built message, for cases the dedicated helpers don't cover
+    /// (multi-part content, tool results, additional-tools messages, ...).
+    pub fn message(mut self, message: Message) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    /// Register a tool the model may call.
+    pub fn tool(mut self, tool: UniversalTool) -> Self {
+        self.params.tools.get_or_insert_with(Vec::new).push(tool);
+        self
+    }
+
+    /// Set the tool selection strategy.
+    pub fn tool_choice(mut self, tool_choice: ToolChoiceConfig) -> Self {
+        self.params.tool_choice = Some(tool_choice);
+        self
+    }
+
+    /// Set the sampling temperature.
+    pub fn temperature(mut self, temperature: f64) -> Self {
+        self.params.temperature = Some(temperature);
+        self
+    }
+
+    /// Set an output-token generation limit.
+    pub fn max_tokens(mut self, max_tokens: i64) -> Self {
+        self.params.token_budget = Some(TokenBudget::OutputTokens(max_tokens));
+        self
+    }
+
+    /// Enable or disable server-sent-event streaming.
+    pub fn stream(mut self, stream: bool) -> Self {
+        self.params.stream = Some(stream);
+        self
+    }
+
+    /// Adjust the full parameter set for anything not covered by a dedicated method.
+    pub fn params(mut self, edit: impl FnOnce(&mut UniversalParams)) -> Self {
+        edit(&mut self.params);
+        self
+    }
+
+    /// Finalize the builder into a [`UniversalRequest`].
+    pub fn build(self) -> UniversalRequest {
+        UniversalRequest {
+            model: self.model,
+            messages: self.messages,
+            params: self.params,
+        }
+    }
 }
 
 /// Canonical token budget for request generation limits.
@@ -117,12 +248,12 @@ pub struct UniversalParams {
 
     /// Penalize tokens based on whether they've appeared at all (-2.0 to 2.0).
     ///
-    /// **Providers:** OpenAI
+    /// **Providers:** OpenAI, Google (`generationConfig.presencePenalty`, clamped to Gemini's accepted range). No Anthropic equivalent.
     pub presence_penalty: Option<f64>,
 
     /// Penalize tokens based on how often they've appeared (-2.0 to 2.0).
     ///
-    /// **Providers:** OpenAI
+    /// **Providers:** OpenAI, Google (`generationConfig.frequencyPenalty`, clamped to Gemini's accepted range). No Anthropic equivalent.
     pub frequency_penalty: Option<f64>,
 
     // === Output control ===
@@ -170,6 +301,12 @@ pub struct UniversalParams {
     /// **Providers:** OpenAI, Anthropic (`output_format`)
     pub response_format: Option<ResponseFormatConfig>,
 
+    /// Output modalities the model should produce, beyond plain text.
+    ///
+    /// **Providers:** OpenAI (`modalities`), Google (`generationConfig.responseModalities`).
+    /// No Anthropic equivalent - dropped when converting to Anthropic.
+    pub modalities: Option<Vec<ResponseModality>>,
+
     // === Reasoning / Extended thinking ===
     /// Enable extended thinking / chain-of-thought reasoning.
     ///
@@ -665,6 +802,51 @@ impl AsRef<str> for ResponseFormatType {
     }
 }
 
+/// Output modality a provider may be asked to produce (portable across providers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[ts(export)]
+pub enum ResponseModality {
+    /// Plain text output
+    Text,
+    /// Inline image output (e.g. Google `inlineData`)
+    Image,
+    /// Inline audio output
+    Audio,
+}
+
+impl ResponseModality {
+    /// Returns the string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Image => "image",
+            Self::Audio => "audio",
+        }
+    }
+}
+
+impl FromStr for ResponseModality {
+    type Err = ConvertError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "image" => Ok(Self::Image),
+            "audio" => Ok(Self::Audio),
+            _ => Err(ConvertError::InvalidEnumValue {
+                type_name: "ResponseModality",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ResponseModality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// JSON schema configuration for structured output.
 #[derive(Debug, Clone, Serialize, TS)]
 #[ts(export)]
@@ -801,4 +983,85 @@ mod tests {
         );
         assert_eq!(tool_choice.disable_parallel_tool_use, Some(true));
     }
+
+    #[test]
+    fn test_builder_produces_expected_messages_and_params() {
+        let request = UniversalRequestBuilder::new("gpt-4")
+            .system("You are a helpful assistant.")
+            .user_text("What's the weather in SF?")
+            .temperature(0.7)
+            .max_tokens(1024)
+            .stream(true)
+            .build();
+
+        assert_eq!(request.model.as_deref(), Some("gpt-4"));
+        assert_eq!(request.messages.len(), 2);
+        assert!(matches!(request.messages[0], Message::System { .. }));
+        assert!(matches!(request.messages[1], Message::User { .. }));
+        assert_eq!(request.params.temperature, Some(0.7));
+        assert_eq!(
+            request.params.token_budget,
+            Some(TokenBudget::OutputTokens(1024))
+        );
+        assert_eq!(request.params.stream, Some(true));
+    }
+
+    #[test]
+    #[cfg(feature = "openai")]
+    fn test_builder_serializes_to_expected_openai_json() {
+        use crate::processing::adapters::ProviderAdapter;
+        use crate::providers::openai::OpenAIAdapter;
+
+        let request = UniversalRequestBuilder::new("gpt-4")
+            .system("You are a helpful assistant.")
+            .user_text("What's the weather in SF?")
+            .tool(UniversalTool::function(
+                "get_weather",
+                Some("Get the current weather".to_string()),
+                Some(json!({
+                    "type": "object",
+                    "properties": { "location": { "type": "string" } },
+                    "required": ["location"]
+                })),
+                None,
+            ))
+            .tool_choice(ToolChoiceConfig {
+                mode: Some(ToolChoiceMode::Auto),
+                tool_name: None,
+            })
+            .temperature(0.7)
+            .max_tokens(1024)
+            .stream(true)
+            .build();
+
+        let value = OpenAIAdapter.request_from_universal(&request).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "model": "gpt-4",
+                "messages": [
+                    { "role": "system", "content": "You are a helpful assistant." },
+                    { "role": "user", "content": "What's the weather in SF?" }
+                ],
+                "temperature": 0.7,
+                "max_completion_tokens": 1024,
+                "stream": true,
+                "stream_options": { "include_usage": true },
+                "tools": [{
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "description": "Get the current weather",
+                        "parameters": {
+                            "type": "object",
+                            "properties": { "location": { "type": "string" } },
+                            "required": ["location"]
+                        }
+                    }
+                }],
+                "tool_choice": "auto"
+            })
+        );
+    }
 }
@@ -8,7 +8,7 @@ converted to/from any provider format.
 use crate::capabilities::ProviderFormat;
 use crate::serde_json::{self, Value};
 use crate::universal::defaults::PLACEHOLDER_ID;
-use crate::universal::message::{AssistantContent, AssistantContentPart, Message};
+use crate::universal::message::{AssistantContent, AssistantContentPart, Message, ProviderOptions};
 use serde::{Deserialize, Serialize};
 
 /// Universal response envelope for LLM API responses.
@@ -39,6 +39,22 @@ pub struct UniversalResponse {
     /// Why each choice stopped generating.
     #[serde(skip_serializing)]
     pub finish_reasons: Vec<FinishReason>,
+
+    /// Backend configuration fingerprint, for detecting non-deterministic changes
+    /// alongside a request `seed`.
+    ///
+    /// **Providers:** OpenAI
+    pub system_fingerprint: Option<String>,
+
+    /// Provider-specific response fields with no cross-provider equivalent
+    /// (e.g. Anthropic's `stop_sequence`), preserved so a round trip back to
+    /// the same provider format doesn't lose them.
+    pub provider_options: Option<ProviderOptions>,
+
+    /// Priority tier the request actually ran at, echoed back by the provider.
+    ///
+    /// **Providers:** OpenAI, Anthropic
+    pub service_tier: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -828,6 +844,9 @@ mod tests {
             usage: None,
             finish_reason: Some(FinishReason::Stop),
             finish_reasons: vec![FinishReason::Length, FinishReason::Stop],
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
         assert!(!response.is_complete());
 
@@ -839,6 +858,9 @@ mod tests {
             usage: None,
             finish_reason: Some(FinishReason::Stop),
             finish_reasons: vec![FinishReason::Stop, FinishReason::ToolCalls],
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
         assert!(response.is_complete());
     }
@@ -853,15 +875,20 @@ mod tests {
                 Message::Assistant {
                     content: AssistantContent::String(r#"{"ok":true}"#.to_string()),
                     id: None,
+                    name: None,
                 },
                 Message::Assistant {
                     content: AssistantContent::String(r#"{"broken":"#.to_string()),
                     id: None,
+                    name: None,
                 },
             ],
             usage: None,
             finish_reason: Some(FinishReason::Stop),
             finish_reasons: vec![FinishReason::Stop, FinishReason::Stop],
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
         assert!(!response.content_is_json());
 
@@ -873,6 +900,9 @@ mod tests {
             usage: None,
             finish_reason: Some(FinishReason::Stop),
             finish_reasons: vec![FinishReason::Stop],
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
         assert!(!response.content_is_json());
 
@@ -892,10 +922,14 @@ mod tests {
                     ),
                 ]),
                 id: None,
+                name: None,
             }],
             usage: None,
             finish_reason: Some(FinishReason::Stop),
             finish_reasons: vec![FinishReason::Stop],
+            system_fingerprint: None,
+            provider_options: None,
+            service_tier: None,
         };
         assert!(response.content_is_json());
     }
@@ -1117,4 +1151,47 @@ mod tests {
         assert_eq!(responses["input_tokens_details"]["cached_tokens"], 40);
         assert_eq!(responses["input_tokens_details"]["cache_write_tokens"], 15);
     }
+
+    #[test]
+    fn test_openai_chat_completions_cached_tokens_roundtrip() {
+        let usage = crate::serde_json::json!({
+            "prompt_tokens": 100,
+            "completion_tokens": 25,
+            "total_tokens": 125,
+            "prompt_tokens_details": {
+                "cached_tokens": 40
+            }
+        });
+
+        let usage = UniversalUsage::from_provider_value(&usage, ProviderFormat::ChatCompletions);
+        assert_eq!(usage.prompt_tokens, Some(100));
+        assert_eq!(usage.prompt_cached_tokens, Some(40));
+        // OpenAI Chat Completions doesn't report cache write tokens.
+        assert_eq!(usage.prompt_cache_creation_tokens, None);
+
+        let chat = usage.to_provider_value(ProviderFormat::ChatCompletions);
+        assert_eq!(chat["prompt_tokens"], 100);
+        assert_eq!(chat["prompt_tokens_details"]["cached_tokens"], 40);
+    }
+
+    #[test]
+    fn test_anthropic_cache_tokens_roundtrip() {
+        let usage = crate::serde_json::json!({
+            "input_tokens": 10,
+            "output_tokens": 5,
+            "cache_creation_input_tokens": 30,
+            "cache_read_input_tokens": 20,
+        });
+
+        let usage = UniversalUsage::from_provider_value(&usage, ProviderFormat::Anthropic);
+        assert_eq!(usage.prompt_tokens, Some(10));
+        assert_eq!(usage.prompt_cached_tokens, Some(20));
+        assert_eq!(usage.prompt_cache_creation_tokens, Some(30));
+
+        let anthropic = usage.to_provider_value(ProviderFormat::Anthropic);
+        assert_eq!(anthropic["input_tokens"], 10);
+        assert_eq!(anthropic["output_tokens"], 5);
+        assert_eq!(anthropic["cache_read_input_tokens"], 20);
+        assert_eq!(anthropic["cache_creation_input_tokens"], 30);
+    }
 }
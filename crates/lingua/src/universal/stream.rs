@@ -41,6 +41,21 @@ pub struct UniversalReasoningDelta {
     pub content: Option<String>,
 }
 
+/// Incremental audio output, mirroring OpenAI Chat Completions'
+/// `choices[].delta.audio` (base64 `data`, incremental `transcript`, and a
+/// one-time `id`/`expires_at` on the chunk that opens the audio response).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UniversalAudioDelta {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transcript: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UniversalToolCallDelta {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -67,6 +82,8 @@ pub struct UniversalStreamDelta {
     pub reasoning: Vec<UniversalReasoningDelta>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reasoning_signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<UniversalAudioDelta>,
 }
 
 /// A normalized streaming chunk following OpenAI's format.
@@ -207,7 +224,8 @@ impl From<UniversalStreamDelta> for Value {
     fn from(delta: UniversalStreamDelta) -> Self {
         let has_structured_delta = !delta.tool_calls.is_empty()
             || !delta.reasoning.is_empty()
-            || delta.reasoning_signature.is_some();
+            || delta.reasoning_signature.is_some()
+            || delta.audio.is_some();
         let mut map = serde_json::Map::new();
         if let Some(role) = delta.role {
             map.insert("role".into(), Value::String(role));
@@ -230,6 +248,10 @@ impl From<UniversalStreamDelta> for Value {
         if let Some(signature) = delta.reasoning_signature {
             map.insert("reasoning_signature".into(), Value::String(signature));
         }
+        if let Some(audio) = delta.audio {
+            let value = serde_json::to_value(audio).unwrap_or(Value::Object(Default::default()));
+            map.insert("audio".into(), value);
+        }
         Value::Object(map)
     }
 }
@@ -406,4 +428,25 @@ mod tests {
         assert_eq!(parsed.reasoning[0].content.as_deref(), Some("thought"));
         assert_eq!(parsed.reasoning_signature.as_deref(), Some("sig_123"));
     }
+
+    #[test]
+    fn test_stream_delta_audio_from_into_value() {
+        let delta = UniversalStreamDelta {
+            audio: Some(UniversalAudioDelta {
+                id: Some("audio_1".to_string()),
+                data: Some("YmFzZTY0".to_string()),
+                transcript: Some("hel".to_string()),
+                expires_at: Some(1234567890),
+            }),
+            ..Default::default()
+        };
+
+        let value = Value::from(delta.clone());
+        let parsed: UniversalStreamDelta = serde_json::from_value(value).unwrap();
+        let audio = parsed.audio.expect("audio delta should round-trip");
+        assert_eq!(audio.id.as_deref(), Some("audio_1"));
+        assert_eq!(audio.data.as_deref(), Some("YmFzZTY0"));
+        assert_eq!(audio.transcript.as_deref(), Some("hel"));
+        assert_eq!(audio.expires_at, Some(1234567890));
+    }
 }
@@ -6,6 +6,7 @@ tool choice configurations:
 - OpenAI Chat: `"auto"` | `"none"` | `"required"` | `{ type: "function", function: { name } }`
 - OpenAI Responses: `"auto"` | `{ type: "function", name }`
 - Anthropic: `{ type: "auto" | "any" | "none" | "tool", name?, disable_parallel_tool_use? }`
+- Google: `{ functionCallingConfig: { mode: "AUTO" | "ANY" | "NONE", allowedFunctionNames? } }`
 
 ## Design
 
@@ -31,6 +32,7 @@ use std::convert::TryFrom;
 use crate::capabilities::ProviderFormat;
 use crate::processing::transform::TransformError;
 use crate::providers::anthropic::generated::ToolChoice;
+use crate::providers::google::generated::ToolConfig;
 use crate::serde_json::{self, json, Value};
 use crate::universal::request::{ToolChoiceConfig, ToolChoiceMode};
 
@@ -48,6 +50,9 @@ impl<'a> TryFrom<(ProviderFormat, &'a Value)> for ToolChoiceConfig {
             ProviderFormat::Anthropic => serde_json::from_value::<ToolChoice>(value.clone())
                 .map(|tc| ToolChoiceConfig::from(&tc))
                 .map_err(|e| TransformError::ToUniversalFailed(e.to_string())),
+            ProviderFormat::Google => serde_json::from_value::<ToolConfig>(value.clone())
+                .map(|tc| ToolChoiceConfig::from(&tc))
+                .map_err(|e| TransformError::ToUniversalFailed(e.to_string())),
             _ => Ok(Self::default()),
         }
     }
@@ -77,6 +82,9 @@ impl ToolChoiceConfig {
             ProviderFormat::ChatCompletions => Ok(to_openai_chat(self)),
             ProviderFormat::Responses => Ok(to_openai_responses(self)),
             ProviderFormat::Anthropic => Ok(to_anthropic(self, parallel_tool_calls)),
+            ProviderFormat::Google => Ok(ToolConfig::try_from(self)
+                .ok()
+                .and_then(|tc| serde_json::to_value(&tc).ok())),
             _ => Ok(None),
         }
     }
@@ -290,12 +298,24 @@ mod tests {
     }
 
     #[test]
-    fn test_from_anthropic_with_disable_parallel() {
+    fn test_from_anthropic_with_disable_parallel_and_no_other_choice() {
+        // `{"type": "auto", "disable_parallel_tool_use": true}` is exactly what
+        // `to_anthropic` synthesizes for a universal request that has no
+        // explicit tool choice but does disable parallel tool calls, so it
+        // must parse back to "no explicit tool choice" rather than picking up
+        // a spurious `mode: Auto` that wasn't in the original request.
         let value = json!({
             "type": "auto",
             "disable_parallel_tool_use": true
         });
         let config: ToolChoiceConfig = (ProviderFormat::Anthropic, &value).try_into().unwrap();
+        assert_eq!(config.mode, None);
+    }
+
+    #[test]
+    fn test_from_anthropic_explicit_auto_without_disable_parallel_keeps_mode() {
+        let value = json!({ "type": "auto" });
+        let config: ToolChoiceConfig = (ProviderFormat::Anthropic, &value).try_into().unwrap();
         assert_eq!(config.mode, Some(ToolChoiceMode::Auto));
     }
 
@@ -373,6 +393,22 @@ mod tests {
         assert_eq!(tool_choice.disable_parallel_tool_use, Some(true));
     }
 
+    #[test]
+    fn test_disabling_parallel_without_tool_choice_is_stable_through_anthropic() {
+        // No tool choice, parallel tool calls disabled: synthesizing Anthropic's
+        // `{"type": "auto", "disable_parallel_tool_use": true}` and reading it
+        // back must not introduce a `mode` that wasn't there originally.
+        let config = ToolChoiceConfig::default();
+        let anthropic_value = config
+            .to_provider(ProviderFormat::Anthropic, Some(false))
+            .unwrap()
+            .unwrap();
+        let round_tripped: ToolChoiceConfig = (ProviderFormat::Anthropic, &anthropic_value)
+            .try_into()
+            .unwrap();
+        assert_eq!(round_tripped.mode, config.mode);
+    }
+
     #[test]
     fn test_roundtrip_openai_chat() {
         let original = json!({
@@ -403,6 +439,120 @@ mod tests {
         assert_eq!(anthropic_value.get("type").unwrap(), "any");
     }
 
+    #[test]
+    fn test_from_google_auto() {
+        let value = json!({
+            "functionCallingConfig": { "mode": "AUTO" }
+        });
+        let config: ToolChoiceConfig = (ProviderFormat::Google, &value).try_into().unwrap();
+        assert_eq!(config.mode, Some(ToolChoiceMode::Auto));
+        assert_eq!(config.tool_name, None);
+    }
+
+    #[test]
+    fn test_from_google_none() {
+        let value = json!({
+            "functionCallingConfig": { "mode": "NONE" }
+        });
+        let config: ToolChoiceConfig = (ProviderFormat::Google, &value).try_into().unwrap();
+        assert_eq!(config.mode, Some(ToolChoiceMode::None));
+    }
+
+    #[test]
+    fn test_from_google_any_required() {
+        let value = json!({
+            "functionCallingConfig": { "mode": "ANY" }
+        });
+        let config: ToolChoiceConfig = (ProviderFormat::Google, &value).try_into().unwrap();
+        assert_eq!(config.mode, Some(ToolChoiceMode::Required));
+        assert_eq!(config.tool_name, None);
+    }
+
+    #[test]
+    fn test_from_google_any_with_single_allowed_name() {
+        let value = json!({
+            "functionCallingConfig": {
+                "mode": "ANY",
+                "allowedFunctionNames": ["get_weather"]
+            }
+        });
+        let config: ToolChoiceConfig = (ProviderFormat::Google, &value).try_into().unwrap();
+        assert_eq!(config.mode, Some(ToolChoiceMode::Tool));
+        assert_eq!(config.tool_name, Some("get_weather".into()));
+    }
+
+    #[test]
+    fn test_to_google_required() {
+        let config = ToolChoiceConfig {
+            mode: Some(ToolChoiceMode::Required),
+            ..Default::default()
+        };
+        let value = config
+            .to_provider(ProviderFormat::Google, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            value.get("functionCallingConfig").unwrap().get("mode"),
+            Some(&json!("ANY"))
+        );
+    }
+
+    #[test]
+    fn test_to_google_tool_with_name() {
+        let config = ToolChoiceConfig {
+            mode: Some(ToolChoiceMode::Tool),
+            tool_name: Some("get_weather".into()),
+        };
+        let value = config
+            .to_provider(ProviderFormat::Google, None)
+            .unwrap()
+            .unwrap();
+        let fcc = value.get("functionCallingConfig").unwrap();
+        assert_eq!(fcc.get("mode"), Some(&json!("ANY")));
+        assert_eq!(
+            fcc.get("allowedFunctionNames"),
+            Some(&json!(["get_weather"]))
+        );
+    }
+
+    #[test]
+    fn test_cross_provider_openai_to_google() {
+        // OpenAI required → Google ANY
+        let openai_value = json!("required");
+        let config: ToolChoiceConfig = (ProviderFormat::ChatCompletions, &openai_value)
+            .try_into()
+            .unwrap();
+        let google_value = config
+            .to_provider(ProviderFormat::Google, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            google_value
+                .get("functionCallingConfig")
+                .unwrap()
+                .get("mode"),
+            Some(&json!("ANY"))
+        );
+    }
+
+    #[test]
+    fn test_cross_provider_google_named_to_anthropic() {
+        // Google ANY + single allowed name → Anthropic named tool
+        let google_value = json!({
+            "functionCallingConfig": {
+                "mode": "ANY",
+                "allowedFunctionNames": ["get_weather"]
+            }
+        });
+        let config: ToolChoiceConfig = (ProviderFormat::Google, &google_value).try_into().unwrap();
+        let anthropic_value = config
+            .to_provider(ProviderFormat::Anthropic, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(anthropic_value.get("type").unwrap(), "tool");
+        assert_eq!(anthropic_value.get("name").unwrap(), "get_weather");
+    }
+
     #[test]
     fn test_invalid_string_mode_errors() {
         // Unrecognized string mode should error
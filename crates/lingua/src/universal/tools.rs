@@ -30,13 +30,15 @@ use ts_rs::TS;
 
 use crate::capabilities::ProviderFormat;
 use crate::error::ConvertError;
+use crate::processing::tool_schema::sanitize_tool_parameters;
 use crate::providers::anthropic::generated::{
-    UserLocation as AnthropicUserLocation, WebSearchTool20250305,
+    UserLocation as AnthropicUserLocation, UserLocationType as AnthropicUserLocationType,
+    WebSearchTool20250305,
 };
 use crate::providers::google::generated::GoogleSearch;
 use crate::providers::openai::generated::{
     ApproximateLocation, Tool as OpenAIResponsesTool, UserLocationType as OpenAIUserLocationType,
-    WebSearchTool,
+    WebSearchLocation, WebSearchTool,
 };
 use crate::serde_json::{self, json, Map, Value};
 
@@ -70,7 +72,13 @@ pub struct UniversalTool {
     #[ts(type = "Record<string, unknown> | null")]
     pub parameters: Option<Value>,
 
-    /// Whether to enforce strict schema validation (OpenAI Responses API)
+    /// Whether to enforce strict schema validation (OpenAI Responses API).
+    ///
+    /// `None` means the source provider has no notion of strict mode (e.g. Google);
+    /// it is left unset rather than defaulted to `false` so a later conversion back to
+    /// OpenAI can still tell "unset" apart from "explicitly disabled". Either way,
+    /// [`UniversalTool::to_openai_chat_value`] and [`UniversalTool::to_responses_value`]
+    /// only run the strict-mode schema adjustments when this is `Some(true)`.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[ts(optional)]
     pub strict: Option<bool>,
@@ -367,6 +375,85 @@ impl UniversalTool {
         })
     }
 
+    /// Parse an OpenAI web search tool's `filters.allowed_domains`/`user_location`
+    /// out of its raw builtin config, for translation to another provider's
+    /// native web search tool.
+    ///
+    /// The config shape differs by origin: Chat Completions' `web_search_options`
+    /// nests location fields under `user_location.approximate`, while the
+    /// Responses API's `web_search` tool lists them flat under `user_location`
+    /// (and is the only shape with `filters`). Both are handled here.
+    pub(crate) fn openai_web_search_config(
+        &self,
+    ) -> Result<Option<(Option<Vec<String>>, Option<AnthropicUserLocation>)>, ConvertError> {
+        let UniversalToolType::Builtin {
+            provider,
+            builtin_type,
+            config,
+        } = &self.tool_type
+        else {
+            return Ok(None);
+        };
+
+        if !matches!(provider, BuiltinToolProvider::Responses)
+            || (builtin_type != "web_search" && builtin_type != "web_search_preview")
+        {
+            return Ok(None);
+        }
+
+        let config = config.clone().unwrap_or_else(|| json!({}));
+
+        let allowed_domains = config
+            .get("filters")
+            .and_then(|filters| filters.get("allowed_domains"))
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(
+                |e: serde_json::Error| ConvertError::JsonSerializationFailed {
+                    field: format!("OpenAI web search tool allowed_domains for '{}'", self.name),
+                    error: e.to_string(),
+                },
+            )?;
+
+        let user_location = Self::anthropic_user_location_from_openai_web_search(
+            config.get("user_location").cloned(),
+            &self.name,
+        )?;
+
+        Ok(Some((allowed_domains, user_location)))
+    }
+
+    fn anthropic_user_location_from_openai_web_search(
+        user_location: Option<Value>,
+        tool_name: &str,
+    ) -> Result<Option<AnthropicUserLocation>, ConvertError> {
+        let Some(user_location) = user_location else {
+            return Ok(None);
+        };
+
+        // Chat Completions nests the location under `approximate`; the Responses
+        // API lists the same fields at the top level, matching Anthropic's shape.
+        let flat = user_location
+            .get("approximate")
+            .cloned()
+            .unwrap_or(user_location);
+
+        let location: WebSearchLocation =
+            serde_json::from_value(flat).map_err(|e| ConvertError::JsonSerializationFailed {
+                field: format!("OpenAI web search user_location for '{tool_name}'"),
+                error: e.to_string(),
+            })?;
+
+        Ok(Some(AnthropicUserLocation {
+            city: location.city,
+            country: location.country,
+            region: location.region,
+            timezone: location.timezone,
+            user_location_type: AnthropicUserLocationType::Approximate,
+        }))
+    }
+
     fn assert_chat_web_search_filters_supported(
         &self,
         allowed_domains: &Option<Vec<String>>,
@@ -405,7 +492,15 @@ impl UniversalTool {
                 }
 
                 if let Some(parameters) = &self.parameters {
-                    func.insert("parameters".into(), parameters.clone());
+                    let strict = self.strict.unwrap_or(false);
+                    func.insert(
+                        "parameters".into(),
+                        sanitize_tool_parameters(
+                            parameters,
+                            ProviderFormat::ChatCompletions,
+                            strict,
+                        ),
+                    );
                 }
 
                 if let Some(strict) = self.strict {
@@ -535,7 +630,11 @@ impl UniversalTool {
                 }
 
                 if let Some(parameters) = &self.parameters {
-                    obj.insert("parameters".into(), parameters.clone());
+                    let strict = self.strict.unwrap_or(false);
+                    obj.insert(
+                        "parameters".into(),
+                        sanitize_tool_parameters(parameters, ProviderFormat::Responses, strict),
+                    );
                 }
 
                 if let Some(strict) = self.strict {
@@ -942,6 +1041,55 @@ mod tests {
         assert_eq!(value["strict"], true);
     }
 
+    #[test]
+    fn test_strict_tool_round_trip_applies_schema_adjustments() {
+        // A schema that is valid but doesn't yet meet OpenAI's strict-mode
+        // requirements (no `additionalProperties`, no `required`).
+        let tool = UniversalTool::function(
+            "get_weather",
+            Some("Get weather".to_string()),
+            Some(json!({
+                "type": "object",
+                "properties": {
+                    "city": {"type": "string"},
+                    "unit": {"type": "string"}
+                }
+            })),
+            Some(true),
+        );
+
+        for value in [
+            tool.to_openai_chat_value().unwrap()["function"].clone(),
+            tool.to_responses_value().unwrap(),
+        ] {
+            assert_eq!(value["strict"], true);
+            assert_eq!(value["parameters"]["additionalProperties"], json!(false));
+            let required = value["parameters"]["required"].as_array().unwrap();
+            assert!(required.contains(&json!("city")));
+            assert!(required.contains(&json!("unit")));
+        }
+    }
+
+    #[test]
+    fn test_non_strict_tool_round_trip_leaves_schema_untouched() {
+        // Tools imported from a provider with no strict-mode concept (e.g. Google)
+        // carry `strict: None`, which must not trigger the strict-mode adjustments.
+        let schema = json!({
+            "type": "object",
+            "properties": {"city": {"type": "string"}}
+        });
+        let tool = UniversalTool::function(
+            "get_weather",
+            Some("Get weather".to_string()),
+            Some(schema.clone()),
+            None,
+        );
+
+        let chat_value = tool.to_openai_chat_value().unwrap();
+        assert_eq!(chat_value["function"]["parameters"], schema);
+        assert!(chat_value["function"].get("strict").is_none());
+    }
+
     #[test]
     fn test_universal_tool_to_responses_custom() {
         let tool = UniversalTool::custom(
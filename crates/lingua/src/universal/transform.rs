@@ -16,8 +16,8 @@ use lingua::universal::{Message, UserContent, extract_system_messages, flatten_c
 
 let mut messages = vec![
     Message::System { content: UserContent::String("You are helpful".into()) },
-    Message::User { content: UserContent::String("Hello".into()) },
-    Message::User { content: UserContent::String("World".into()) },
+    Message::User { content: UserContent::String("Hello".into()) , name: None},
+    Message::User { content: UserContent::String("World".into()) , name: None},
 ];
 
 // Extract system messages (for providers that need them separate)
@@ -48,7 +48,7 @@ use crate::universal::{
 ///
 /// let mut messages = vec![
 ///     Message::System { content: UserContent::String("System prompt".into()) },
-///     Message::User { content: UserContent::String("Hello".into()) },
+///     Message::User { content: UserContent::String("Hello".into()) , name: None},
 /// ];
 ///
 /// let system = extract_system_messages(&mut messages);
@@ -82,8 +82,8 @@ pub fn extract_system_messages(messages: &mut Vec<Message>) -> Vec<UserContent>
 /// use lingua::universal::{Message, UserContent, flatten_consecutive_messages};
 ///
 /// let mut messages = vec![
-///     Message::User { content: UserContent::String("Hello".into()) },
-///     Message::User { content: UserContent::String("World".into()) },
+///     Message::User { content: UserContent::String("Hello".into()) , name: None},
+///     Message::User { content: UserContent::String("World".into()) , name: None},
 /// ];
 ///
 /// flatten_consecutive_messages(&mut messages);
@@ -124,7 +124,14 @@ fn can_merge(a: &Message, b: &Message) -> bool {
 /// Merge message `b` into message `a`.
 fn merge_messages(a: &mut Message, b: Message) {
     match (a, b) {
-        (Message::User { content: a_content }, Message::User { content: b_content }) => {
+        (
+            Message::User {
+                content: a_content, ..
+            },
+            Message::User {
+                content: b_content, ..
+            },
+        ) => {
             merge_user_content(a_content, b_content);
         }
         (
@@ -215,6 +222,7 @@ mod tests {
             },
             Message::User {
                 content: UserContent::String("Hello".into()),
+                name: None,
             },
             Message::System {
                 content: UserContent::String("Another system".into()),
@@ -233,9 +241,11 @@ mod tests {
         let mut messages = vec![
             Message::User {
                 content: UserContent::String("Hello".into()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("World".into()),
+                name: None,
             },
         ];
 
@@ -244,6 +254,7 @@ mod tests {
         assert_eq!(messages.len(), 1);
         if let Message::User {
             content: UserContent::Array(parts),
+            ..
         } = &messages[0]
         {
             assert_eq!(parts.len(), 2);
@@ -258,10 +269,12 @@ mod tests {
             Message::Assistant {
                 content: AssistantContent::String("Hi".into()),
                 id: None,
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::String("there".into()),
                 id: Some("id2".into()),
+                name: None,
             },
         ];
 
@@ -284,9 +297,11 @@ mod tests {
         let messages = [
             Message::User {
                 content: UserContent::String("Hello".into()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("World".into()),
+                name: None,
             },
         ];
 
@@ -302,6 +317,7 @@ mod tests {
             },
             Message::User {
                 content: UserContent::String("Hello".into()),
+                name: None,
             },
         ];
 
@@ -315,16 +331,20 @@ mod tests {
         let mut messages = vec![
             Message::User {
                 content: UserContent::String("1".into()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("2".into()),
+                name: None,
             },
             Message::Assistant {
                 content: AssistantContent::String("A".into()),
                 id: None,
+                name: None,
             },
             Message::User {
                 content: UserContent::String("3".into()),
+                name: None,
             },
         ];
 
@@ -384,9 +404,11 @@ mod tests {
             },
             Message::User {
                 content: UserContent::String("Hello".into()),
+                name: None,
             },
             Message::User {
                 content: UserContent::String("World".into()),
+                name: None,
             },
         ];
 
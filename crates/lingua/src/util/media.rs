@@ -310,7 +310,7 @@ mod wasm_fetch {
 // Async URL Fetching - Native implementation
 // ============================================================================
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), feature = "media-fetch"))]
 mod native_fetch {
     use super::*;
     use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
@@ -706,7 +706,7 @@ mod native_fetch {
 #[cfg(target_arch = "wasm32")]
 pub use wasm_fetch::fetch_url_to_base64;
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), feature = "media-fetch"))]
 pub use native_fetch::fetch_url_to_base64;
 
 /// Convert media (URL or data URL) to a MediaBlock.
@@ -714,11 +714,15 @@ pub use native_fetch::fetch_url_to_base64;
 /// If the input is already a base64 data URL, it is parsed directly.
 /// Otherwise, the URL is fetched and the content is converted to base64.
 ///
+/// Fetching a remote URL requires the `media-fetch` feature (always on for
+/// wasm32 targets, since that path doesn't pull in reqwest).
+///
 /// # Arguments
 ///
 /// * `media` - A URL or data URL
 /// * `allowed_types` - Optional list of allowed MIME types for fetched URLs
 /// * `max_bytes` - Optional maximum size in bytes for fetched URLs
+#[cfg(any(target_arch = "wasm32", feature = "media-fetch"))]
 pub async fn convert_media_to_base64(
     media: &str,
     allowed_types: Option<&[&str]>,
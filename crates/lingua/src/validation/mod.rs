@@ -17,6 +17,8 @@ pub mod google;
 #[cfg(feature = "bedrock")]
 pub mod bedrock;
 
+pub mod stream;
+
 mod cross_provider_tests;
 
 use crate::serde_json;
@@ -0,0 +1,126 @@
+/*!
+Streaming transcript validation.
+
+Unlike [`crate::validation::openai`]/[`anthropic`]/[`google`]/[`bedrock`], which
+validate a single request or response body, [`validate_stream_transcript`]
+validates a full recorded SSE transcript against a provider format - useful for
+checking that a hand-written or captured streaming fixture is well-formed
+before it's used in tests.
+*/
+
+use bytes::Bytes;
+
+use crate::capabilities::ProviderFormat;
+use crate::processing::adapters::adapter_for_format;
+use crate::serde_json;
+use crate::validation::ValidationError;
+
+/// Validates that `events` form a well-formed streaming transcript for `format`.
+///
+/// Each entry in `events` is the JSON payload of a single SSE `data:` line,
+/// with framing and the terminal `[DONE]` marker already stripped. Checks:
+///
+/// - every event parses as a valid stream chunk for `format`
+/// - exactly one event reports a finish reason (the terminal event)
+/// - the terminal event is the last one in the transcript
+pub fn validate_stream_transcript(
+    events: &[Bytes],
+    format: ProviderFormat,
+) -> Result<(), ValidationError> {
+    let adapter = adapter_for_format(format).ok_or_else(|| {
+        ValidationError::DeserializationFailed(format!("unsupported streaming format: {format}"))
+    })?;
+
+    let mut terminal_index = None;
+    for (index, event) in events.iter().enumerate() {
+        let payload: serde_json::Value = serde_json::from_slice(event)
+            .map_err(|e| ValidationError::JsonParseFailed(format!("event {index}: {e}")))?;
+
+        let chunk = adapter
+            .stream_to_universal(payload)
+            .map_err(|e| ValidationError::DeserializationFailed(format!("event {index}: {e}")))?;
+
+        let is_terminal = chunk
+            .as_ref()
+            .is_some_and(|chunk| chunk.choices.iter().any(|c| c.finish_reason.is_some()));
+
+        if is_terminal {
+            if let Some(previous) = terminal_index {
+                return Err(ValidationError::DeserializationFailed(format!(
+                    "event {index} is a second terminal event (first at {previous})"
+                )));
+            }
+            terminal_index = Some(index);
+        } else if terminal_index.is_some() {
+            return Err(ValidationError::DeserializationFailed(format!(
+                "event {index} appears after the terminal event"
+            )));
+        }
+    }
+
+    if terminal_index.is_none() {
+        return Err(ValidationError::DeserializationFailed(
+            "transcript has no terminal event (no chunk reported a finish reason)".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "openai"))]
+mod tests {
+    use super::*;
+
+    fn events(payloads: &[serde_json::Value]) -> Vec<Bytes> {
+        payloads
+            .iter()
+            .map(|p| Bytes::from(serde_json::to_vec(p).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_openai_transcript() {
+        let events = events(&[
+            serde_json::json!({
+                "id": "chatcmpl-1",
+                "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"role": "assistant", "content": ""}, "finish_reason": null}]
+            }),
+            serde_json::json!({
+                "id": "chatcmpl-1",
+                "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"content": "hello"}, "finish_reason": null}]
+            }),
+            serde_json::json!({
+                "id": "chatcmpl-1",
+                "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {}, "finish_reason": "stop"}]
+            }),
+        ]);
+
+        let result = validate_stream_transcript(&events, ProviderFormat::ChatCompletions);
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn test_openai_transcript_missing_terminal_event() {
+        let events = events(&[
+            serde_json::json!({
+                "id": "chatcmpl-1",
+                "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"role": "assistant", "content": ""}, "finish_reason": null}]
+            }),
+            serde_json::json!({
+                "id": "chatcmpl-1",
+                "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"content": "hello"}, "finish_reason": null}]
+            }),
+        ]);
+
+        let result = validate_stream_transcript(&events, ProviderFormat::ChatCompletions);
+        assert!(matches!(
+            result,
+            Err(ValidationError::DeserializationFailed(_))
+        ));
+    }
+}
@@ -7,7 +7,7 @@
 //!   `make run`   - fail on first error with verbose output (for debugging)
 //!   `make stats` - run all cases and report aggregated summary (for triage)
 
-use coverage_report::runner::diff_json;
+use coverage_report::runner::{assert_roundtrip, diff_json};
 use lingua::processing::adapter_for_format;
 use lingua::serde_json::{self, Value};
 use lingua::ProviderFormat;
@@ -22,7 +22,9 @@ mod schema_strategy;
 const SNAPSHOT_SUITE_OPENAI: &str = "openai-roundtrip";
 const SNAPSHOT_SUITE_RESPONSES: &str = "responses-roundtrip";
 const SNAPSHOT_SUITE_ANTHROPIC: &str = "anthropic-roundtrip";
+const SNAPSHOT_SUITE_GOOGLE: &str = "google-roundtrip";
 const SNAPSHOT_SUITE_CHAT_ANTHROPIC_TWO_ARM: &str = "chat-anthropic-two-arm";
+const SNAPSHOT_SUITE_CHAT_GOOGLE_TWO_ARM: &str = "chat-google-two-arm";
 const SNAPSHOT_SUITE_CHAT_RESPONSES_ANTHROPIC_THREE_ARM: &str =
     "chat-responses-anthropic-three-arm";
 
@@ -271,34 +273,16 @@ fn prune_orphan_meta_files_for_suite(suite: &str) -> usize {
 /// Provider JSON -> Universal -> Provider JSON.
 /// Returns a list of diff descriptions, or empty if exact match.
 /// Returns None only when no adapter exists for the provider format.
+///
+/// Thin wrapper around `coverage_report::runner::assert_roundtrip`, which
+/// holds the actual round-trip and diffing logic shared with downstream
+/// consumers of this crate.
 fn assert_provider_roundtrip(format: ProviderFormat, payload: &Value) -> Option<Vec<String>> {
-    let adapter = adapter_for_format(format)?;
-
-    let universal = match adapter.request_to_universal(payload.clone()) {
-        Ok(u) => u,
-        Err(e) => return Some(vec![format!("request_to_universal error: {}", e)]),
-    };
-    let output = match adapter.request_from_universal(&universal) {
-        Ok(o) => o,
-        Err(e) => return Some(vec![format!("request_from_universal error: {}", e)]),
-    };
-
-    if *payload == output {
-        return Some(vec![]);
-    }
-
-    let diff = diff_json(payload, &output);
-    let mut issues = Vec::new();
-    for f in &diff.lost_fields {
-        issues.push(format!("lost: {}", f));
-    }
-    for f in &diff.added_fields {
-        issues.push(format!("added: {}", f));
+    adapter_for_format(format)?;
+    match assert_roundtrip(format, payload) {
+        Ok(()) => Some(vec![]),
+        Err(issues) => Some(issues),
     }
-    for (f, before, after) in &diff.changed_fields {
-        issues.push(format!("changed: {} ({} -> {})", f, before, after));
-    }
-    Some(issues)
 }
 
 /// Verbose roundtrip check for debugging. Returns full error string on failure.
@@ -489,6 +473,157 @@ fn assert_anthropic_roundtrip_verbose(payload: &Value) -> Result<bool, String> {
     assert_provider_roundtrip_verbose(ProviderFormat::Anthropic, payload)
 }
 
+fn assert_google_roundtrip(payload: &Value) -> Option<Vec<String>> {
+    assert_provider_roundtrip(ProviderFormat::Google, payload)
+}
+
+fn assert_google_roundtrip_verbose(payload: &Value) -> Result<bool, String> {
+    assert_provider_roundtrip_verbose(ProviderFormat::Google, payload)
+}
+
+fn assert_responses_roundtrip(payload: &Value) -> Option<Vec<String>> {
+    assert_provider_roundtrip(ProviderFormat::Responses, payload)
+}
+
+fn assert_responses_roundtrip_verbose(payload: &Value) -> Result<bool, String> {
+    assert_provider_roundtrip_verbose(ProviderFormat::Responses, payload)
+}
+
+fn assert_chat_google_two_arm(payload: &Value) -> Option<Vec<String>> {
+    let chat = adapter_for_format(ProviderFormat::ChatCompletions)?;
+    let google = adapter_for_format(ProviderFormat::Google)?;
+
+    let universal_1 = match chat.request_to_universal(payload.clone()) {
+        Ok(v) => v,
+        Err(e) => return Some(vec![format!("chat->universal error: {e}")]),
+    };
+    let google_1 = match google.request_from_universal(&universal_1) {
+        Ok(v) => v,
+        Err(e) => return Some(vec![format!("chat->google error: {e}")]),
+    };
+    let universal_2 = match google.request_to_universal(google_1.clone()) {
+        Ok(v) => v,
+        Err(e) => return Some(vec![format!("google->universal(1) error: {e}")]),
+    };
+
+    let google_2 = match google.request_from_universal(&universal_2) {
+        Ok(v) => v,
+        Err(e) => return Some(vec![format!("universal->google(2) error: {e}")]),
+    };
+    let universal_3 = match google.request_to_universal(google_2.clone()) {
+        Ok(v) => v,
+        Err(e) => return Some(vec![format!("google->universal(2) error: {e}")]),
+    };
+    let chat_out = match chat.request_from_universal(&universal_3) {
+        Ok(v) => v,
+        Err(e) => return Some(vec![format!("universal->chat error: {e}")]),
+    };
+
+    let mut issues = Vec::new();
+
+    let universal_1_json = serde_json::to_value(&universal_1).unwrap_or(Value::Null);
+    let universal_2_json = serde_json::to_value(&universal_2).unwrap_or(Value::Null);
+    append_diff_issues(
+        "universal(1->2):",
+        &universal_1_json,
+        &universal_2_json,
+        &mut issues,
+    );
+    append_diff_issues("google(1->2):", &google_1, &google_2, &mut issues);
+    append_diff_issues("chat(final):", payload, &chat_out, &mut issues);
+
+    Some(issues)
+}
+
+fn assert_chat_google_two_arm_verbose(payload: &Value) -> Result<bool, String> {
+    let chat = adapter_for_format(ProviderFormat::ChatCompletions)
+        .ok_or_else(|| "No chat-completions adapter".to_string())?;
+    let google = adapter_for_format(ProviderFormat::Google)
+        .ok_or_else(|| "No google adapter".to_string())?;
+
+    let universal_1 = chat
+        .request_to_universal(payload.clone())
+        .map_err(|e| format!("chat->universal error: {e}"))?;
+    let google_1 = google
+        .request_from_universal(&universal_1)
+        .map_err(|e| format!("universal->google(1) error: {e}"))?;
+    let universal_2 = google
+        .request_to_universal(google_1.clone())
+        .map_err(|e| format!("google->universal(1) error: {e}"))?;
+    let google_2 = google
+        .request_from_universal(&universal_2)
+        .map_err(|e| format!("universal->google(2) error: {e}"))?;
+    let universal_3 = google
+        .request_to_universal(google_2.clone())
+        .map_err(|e| format!("google->universal(2) error: {e}"))?;
+    let chat_out = chat
+        .request_from_universal(&universal_3)
+        .map_err(|e| format!("universal->chat error: {e}"))?;
+
+    let mut issues = Vec::new();
+    let universal_1_json = serde_json::to_value(&universal_1).unwrap_or(Value::Null);
+    let universal_2_json = serde_json::to_value(&universal_2).unwrap_or(Value::Null);
+    append_diff_issues(
+        "universal(1->2):",
+        &universal_1_json,
+        &universal_2_json,
+        &mut issues,
+    );
+    append_diff_issues("google(1->2):", &google_1, &google_2, &mut issues);
+    append_diff_issues("chat(final):", payload, &chat_out, &mut issues);
+
+    if issues.is_empty() {
+        return Ok(true);
+    }
+
+    Err(format!(
+        "chat->universal->google->universal->google->universal->chat mismatch:\n{}\n\n\
+         chat_input: {}\n\
+         universal_1: {}\n\
+         google_1: {}\n\
+         universal_2: {}\n\
+         google_2: {}\n\
+         universal_3: {}\n\
+         chat_output: {}",
+        issues
+            .iter()
+            .map(|i| format!("  {i}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        as_pretty_json(payload),
+        as_pretty_json(&universal_1),
+        as_pretty_json(&google_1),
+        as_pretty_json(&universal_2),
+        as_pretty_json(&google_2),
+        as_pretty_json(&universal_3),
+        as_pretty_json(&chat_out),
+    ))
+}
+
+fn assert_openai_roundtrip(payload: &Value) -> Option<Vec<String>> {
+    assert_provider_roundtrip(ProviderFormat::ChatCompletions, payload)
+}
+
+fn assert_openai_roundtrip_verbose(payload: &Value) -> Result<bool, String> {
+    assert_provider_roundtrip_verbose(ProviderFormat::ChatCompletions, payload)
+}
+
+fn assert_anthropic_roundtrip(payload: &Value) -> Option<Vec<String>> {
+    assert_provider_roundtrip(ProviderFormat::Google, payload)
+}
+
+fn assert_anthropic_roundtrip_verbose(payload: &Value) -> Result<bool, String> {
+    assert_provider_roundtrip_verbose(ProviderFormat::Google, payload)
+}
+
+fn assert_google_roundtrip(payload: &Value) -> Option<Vec<String>> {
+    assert_provider_roundtrip(ProviderFormat::Google, payload)
+}
+
+fn assert_google_roundtrip_verbose(payload: &Value) -> Result<bool, String> {
+    assert_provider_roundtrip_verbose(ProviderFormat::Google, payload)
+}
+
 fn assert_responses_roundtrip(payload: &Value) -> Option<Vec<String>> {
     assert_provider_roundtrip(ProviderFormat::Responses, payload)
 }
@@ -666,7 +801,9 @@ fn assert_chat_responses_anthropic_three_arm_verbose(payload: &Value) -> Result<
 // ============================================================================
 
 mod strategies {
-    use super::schema_strategy::{load_openapi_definitions, strategy_for_schema_name};
+    use super::schema_strategy::{
+        load_discovery_definitions, load_openapi_definitions, strategy_for_schema_name,
+    };
     use super::*;
 
     fn specs_dir() -> String {
@@ -701,6 +838,17 @@ mod strategies {
             )
             .boxed()
     }
+
+    pub fn arb_google_payload() -> BoxedStrategy<Value> {
+        let defs =
+            load_discovery_definitions(&format!("{}/specs/google/discovery.json", specs_dir()));
+        strategy_for_schema_name("GenerateContentRequest", &defs)
+            .prop_filter(
+                "payload must parse as a Google GenerateContentRequest",
+                |payload| lingua::providers::google::try_parse_google(payload).is_ok(),
+            )
+            .boxed()
+    }
 }
 
 // ============================================================================
@@ -962,6 +1110,11 @@ fn responses_roundtrip_saved_snapshots() {
     run_saved_snapshots_suite(SNAPSHOT_SUITE_RESPONSES, assert_responses_roundtrip);
 }
 
+#[test]
+fn google_roundtrip_saved_snapshots() {
+    run_saved_snapshots_suite(SNAPSHOT_SUITE_GOOGLE, assert_google_roundtrip);
+}
+
 #[test]
 fn chat_anthropic_two_arm_saved_snapshots() {
     run_saved_snapshots_suite(
@@ -970,6 +1123,35 @@ fn chat_anthropic_two_arm_saved_snapshots() {
     );
 }
 
+/// Regression case for a chat request that disables parallel tool calls but
+/// sets no explicit `tool_choice`. Anthropic has no field for "parallel tool
+/// calls disabled" outside of `tool_choice`, so the conversion has to
+/// synthesize one; reading that synthesized `tool_choice` back must not turn
+/// into a `tool_choice` that wasn't in the original chat request.
+#[test]
+fn chat_anthropic_two_arm_no_spurious_tool_choice_when_parallel_disabled() {
+    let payload = serde_json::json!({
+        "model": "gpt-5",
+        "messages": [{"role": "user", "content": "hi"}],
+        "parallel_tool_calls": false
+    });
+
+    let issues =
+        assert_chat_anthropic_two_arm(&payload).expect("chat and anthropic adapters should exist");
+    assert!(
+        issues.is_empty(),
+        "expected no roundtrip drift, got: {issues:?}"
+    );
+}
+
+#[test]
+fn chat_google_two_arm_saved_snapshots() {
+    run_saved_snapshots_suite(
+        SNAPSHOT_SUITE_CHAT_GOOGLE_TWO_ARM,
+        assert_chat_google_two_arm,
+    );
+}
+
 #[test]
 fn chat_responses_anthropic_three_arm_saved_snapshots() {
     run_saved_snapshots_suite(
@@ -1000,6 +1182,12 @@ fn responses_roundtrip_prune_snapshots() {
     run_prune_snapshots_suite(SNAPSHOT_SUITE_RESPONSES, assert_responses_roundtrip);
 }
 
+#[test]
+#[ignore]
+fn google_roundtrip_prune_snapshots() {
+    run_prune_snapshots_suite(SNAPSHOT_SUITE_GOOGLE, assert_google_roundtrip);
+}
+
 #[test]
 #[ignore]
 fn chat_anthropic_two_arm_prune_snapshots() {
@@ -1009,6 +1197,15 @@ fn chat_anthropic_two_arm_prune_snapshots() {
     );
 }
 
+#[test]
+#[ignore]
+fn chat_google_two_arm_prune_snapshots() {
+    run_prune_snapshots_suite(
+        SNAPSHOT_SUITE_CHAT_GOOGLE_TWO_ARM,
+        assert_chat_google_two_arm,
+    );
+}
+
 #[test]
 #[ignore]
 fn chat_responses_anthropic_three_arm_prune_snapshots() {
@@ -1059,6 +1256,19 @@ fn responses_roundtrip() {
     );
 }
 
+#[test]
+#[ignore]
+fn google_roundtrip() {
+    run_fail_fast_suite(
+        SNAPSHOT_SUITE_GOOGLE,
+        "google",
+        "request-roundtrip",
+        strategies::arb_google_payload(),
+        assert_google_roundtrip,
+        assert_google_roundtrip_verbose,
+    );
+}
+
 #[test]
 #[ignore]
 fn chat_anthropic_two_arm() {
@@ -1072,6 +1282,19 @@ fn chat_anthropic_two_arm() {
     );
 }
 
+#[test]
+#[ignore]
+fn chat_google_two_arm() {
+    run_fail_fast_suite(
+        SNAPSHOT_SUITE_CHAT_GOOGLE_TWO_ARM,
+        "chat-completions",
+        "chat-google-two-arm",
+        strategies::arb_openai_payload(),
+        assert_chat_google_two_arm,
+        assert_chat_google_two_arm_verbose,
+    );
+}
+
 #[test]
 #[ignore]
 fn chat_responses_anthropic_three_arm() {
@@ -1126,6 +1349,19 @@ fn responses_roundtrip_stats() {
     );
 }
 
+#[test]
+#[ignore]
+fn google_roundtrip_stats() {
+    run_stats_suite(
+        SNAPSHOT_SUITE_GOOGLE,
+        "google",
+        "request-roundtrip",
+        "Google roundtrip fuzz",
+        strategies::arb_google_payload(),
+        assert_google_roundtrip,
+    );
+}
+
 #[test]
 #[ignore]
 fn chat_anthropic_two_arm_stats() {
@@ -1139,6 +1375,19 @@ fn chat_anthropic_two_arm_stats() {
     );
 }
 
+#[test]
+#[ignore]
+fn chat_google_two_arm_stats() {
+    run_stats_suite(
+        SNAPSHOT_SUITE_CHAT_GOOGLE_TWO_ARM,
+        "chat-completions",
+        "chat-google-two-arm",
+        "Chat->Google two-arm fuzz",
+        strategies::arb_openai_payload(),
+        assert_chat_google_two_arm,
+    );
+}
+
 #[test]
 #[ignore]
 fn chat_responses_anthropic_three_arm_stats() {